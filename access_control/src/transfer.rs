@@ -3,10 +3,54 @@ use crate::constants::ADMIN_ACTIONS_DELAY;
 use crate::errors::AccessControlError;
 use crate::role::Role;
 use crate::storage::StorageTrait;
-use soroban_sdk::{panic_with_error, Address};
+use soroban_sdk::{panic_with_error, Address, Env, IntoVal, Val};
 use utils::bump::bump_instance;
 use utils::storage_errors::StorageError;
 
+// Delayed actions keep only a deadline under the key provided by the contract, 0 if none.
+// The value being changed is stored by the contract itself, as for role transfers.
+pub fn get_delayed_action_deadline<K: IntoVal<Env, Val>>(e: &Env, deadline_key: &K) -> u64 {
+    bump_instance(e);
+    e.storage().instance().get(deadline_key).unwrap_or(0)
+}
+
+fn put_delayed_action_deadline<K: IntoVal<Env, Val>>(e: &Env, deadline_key: &K, value: u64) {
+    bump_instance(e);
+    e.storage().instance().set(deadline_key, &value);
+}
+
+// Starts the delay, failing if another action under the same key is pending.
+pub fn commit_delayed_action<K: IntoVal<Env, Val>>(e: &Env, deadline_key: &K) {
+    if get_delayed_action_deadline(e, deadline_key) != 0 {
+        panic_with_error!(e, AccessControlError::AnotherActionActive);
+    }
+    put_delayed_action_deadline(
+        e,
+        deadline_key,
+        e.ledger().timestamp() + ADMIN_ACTIONS_DELAY,
+    );
+}
+
+// Completes the pending action once the delay has passed.
+pub fn apply_delayed_action<K: IntoVal<Env, Val>>(e: &Env, deadline_key: &K) {
+    let deadline = get_delayed_action_deadline(e, deadline_key);
+    if deadline == 0 {
+        panic_with_error!(e, AccessControlError::NoActionActive);
+    }
+    if e.ledger().timestamp() < deadline {
+        panic_with_error!(e, AccessControlError::ActionNotReadyYet);
+    }
+    put_delayed_action_deadline(e, deadline_key, 0);
+}
+
+// Discards the pending action.
+pub fn revert_delayed_action<K: IntoVal<Env, Val>>(e: &Env, deadline_key: &K) {
+    if get_delayed_action_deadline(e, deadline_key) == 0 {
+        panic_with_error!(e, AccessControlError::NoActionActive);
+    }
+    put_delayed_action_deadline(e, deadline_key, 0);
+}
+
 pub trait TransferOwnershipTrait {
     fn get_transfer_ownership_deadline(&self, role: &Role) -> u64;
     fn put_transfer_ownership_deadline(&self, role: &Role, value: u64);
@@ -18,15 +62,11 @@ pub trait TransferOwnershipTrait {
 
 impl TransferOwnershipTrait for AccessControl {
     fn get_transfer_ownership_deadline(&self, role: &Role) -> u64 {
-        let key = self.get_future_deadline_key(role);
-        bump_instance(&self.0);
-        self.0.storage().instance().get(&key).unwrap_or(0)
+        get_delayed_action_deadline(&self.0, &self.get_future_deadline_key(role))
     }
 
     fn put_transfer_ownership_deadline(&self, role: &Role, value: u64) {
-        let key = self.get_future_deadline_key(role);
-        bump_instance(&self.0);
-        self.0.storage().instance().set(&key, &value);
+        put_delayed_action_deadline(&self.0, &self.get_future_deadline_key(role), value);
     }
 
     fn get_future_address(&self, role: &Role) -> Address {
@@ -45,12 +85,7 @@ impl TransferOwnershipTrait for AccessControl {
             panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
         }
 
-        if self.get_transfer_ownership_deadline(role) != 0 {
-            panic_with_error!(&self.0, AccessControlError::AnotherActionActive);
-        }
-
-        let deadline = self.0.ledger().timestamp() + ADMIN_ACTIONS_DELAY;
-        self.put_transfer_ownership_deadline(role, deadline);
+        commit_delayed_action(&self.0, &self.get_future_deadline_key(role));
 
         bump_instance(&self.0);
         self.0
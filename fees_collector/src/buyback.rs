@@ -0,0 +1,23 @@
+use crate::errors::FeesCollectorError;
+use crate::storage::{get_last_buyback_time, BuybackConfig};
+use soroban_sdk::token::Client as SorobanTokenClient;
+use soroban_sdk::{panic_with_error, Env};
+
+// Checks that the configured interval has passed since the last buyback.
+pub(crate) fn assert_buyback_interval(e: &Env, config: &BuybackConfig) {
+    if let Some(last_time) = get_last_buyback_time(e) {
+        if e.ledger().timestamp() < last_time.saturating_add(config.interval) {
+            panic_with_error!(e, FeesCollectorError::BuybackTooEarly);
+        }
+    }
+}
+
+// Burns the amount of the reward token held by the current contract
+// or sends it to the configured sink.
+pub(crate) fn burn_reward_token(e: &Env, config: &BuybackConfig, amount: u128) {
+    let token_client = SorobanTokenClient::new(e, &config.reward_token);
+    match &config.sink {
+        Some(sink) => token_client.transfer(&e.current_contract_address(), sink, &(amount as i128)),
+        None => token_client.burn(&e.current_contract_address(), &(amount as i128)),
+    }
+}
@@ -1,6 +1,19 @@
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::token::Client as SorobanTokenClient;
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, BytesN, Env, Map, Symbol, Vec,
+};
 
-use crate::interface::AdminInterface;
+use crate::buyback::{assert_buyback_interval, burn_reward_token};
+use crate::errors::FeesCollectorError;
+use crate::events::{Events, FeesCollectorEvents};
+use crate::interface::{AdminInterface, BuybackInterface, FeesCollectorInterface};
+use crate::pipeline::{claim_pool_fees, estimate_swaps_chain, swap_chained};
+use crate::storage::{
+    get_burned_amount, get_buyback_budget, get_buyback_config, get_last_buyback_time,
+    get_max_slippage, get_recipients, get_router, get_target_token, set_burned_amount,
+    set_buyback_budget, set_buyback_config, set_last_buyback_time, set_max_slippage,
+    set_recipients, set_router, set_target_token, BuybackConfig, FEE_MULTIPLIER,
+};
 use access_control::access::{AccessControl, AccessControlTrait};
 use access_control::emergency::{get_emergency_mode, set_emergency_mode};
 use access_control::errors::AccessControlError;
@@ -9,6 +22,7 @@ use access_control::interface::TransferableContract;
 use access_control::management::SingleAddressManagementTrait;
 use access_control::role::{Role, SymbolRepresentation};
 use access_control::transfer::TransferOwnershipTrait;
+use access_control::utils::require_operations_admin_or_owner;
 use upgrade::events::Events as UpgradeEvents;
 use upgrade::interface::UpgradeableContract;
 use upgrade::{apply_upgrade, commit_upgrade, revert_upgrade};
@@ -30,6 +44,396 @@ impl AdminInterface for FeesCollector {
         }
         access_control.set_role_address(&Role::Admin, &account);
     }
+
+    // Sets the privileged addresses.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `operations_admin` - The address of the operations admin.
+    fn set_privileged_addrs(e: Env, admin: Address, operations_admin: Address) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        access_control.set_role_address(&Role::OperationsAdmin, &operations_admin);
+        // fill missing roles with admin address
+        AccessControlEvents::new(&e).set_privileged_addrs(
+            admin.clone(),
+            operations_admin,
+            admin.clone(),
+            Vec::new(&e),
+            admin,
+        );
+    }
+
+    // Returns a map of privileged roles.
+    //
+    // # Returns
+    //
+    // A map of privileged roles to their respective addresses.
+    fn get_privileged_addrs(e: Env) -> Map<Symbol, Vec<Address>> {
+        let access_control = AccessControl::new(&e);
+        let mut result: Map<Symbol, Vec<Address>> = Map::new(&e);
+        for role in [Role::Admin, Role::OperationsAdmin] {
+            result.set(
+                role.as_symbol(&e),
+                match access_control.get_role_safe(&role) {
+                    Some(v) => Vec::from_array(&e, [v]),
+                    None => Vec::new(&e),
+                },
+            );
+        }
+
+        result
+    }
+
+    // Sets the router used to convert collected fees.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `router` - The address of the router.
+    fn set_router(e: Env, admin: Address, router: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_router(&e, &router);
+        Events::new(&e).set_router(router);
+    }
+
+    // Returns the router used to convert collected fees.
+    fn get_router(e: Env) -> Address {
+        get_router(&e)
+    }
+
+    // Sets the token collected fees are converted to before distribution.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `token` - The address of the target token.
+    fn set_target_token(e: Env, admin: Address, token: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_target_token(&e, &token);
+        Events::new(&e).set_target_token(token);
+    }
+
+    // Returns the token collected fees are converted to.
+    fn get_target_token(e: Env) -> Address {
+        get_target_token(&e)
+    }
+
+    // Sets the recipients of converted fees, e.g. treasury, buyback-and-burn and LP rebates.
+    // Recipients should be unique. Shares are set in basis points and should sum up to 100%.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `recipients` - The list of recipient addresses with their shares.
+    fn set_recipients(e: Env, admin: Address, recipients: Vec<(Address, u32)>) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        let mut addresses: Vec<Address> = Vec::new(&e);
+        let mut total_share = 0;
+        for (recipient, share) in recipients.iter() {
+            if share == 0 || addresses.contains(&recipient) {
+                panic_with_error!(&e, FeesCollectorError::InvalidRecipients);
+            }
+            addresses.push_back(recipient);
+            total_share += share as u64;
+        }
+        if total_share != FEE_MULTIPLIER as u64 {
+            panic_with_error!(&e, FeesCollectorError::InvalidRecipients);
+        }
+
+        set_recipients(&e, &recipients);
+        Events::new(&e).set_recipients(recipients);
+    }
+
+    // Returns the recipients of converted fees with their shares in basis points.
+    fn get_recipients(e: Env) -> Vec<(Address, u32)> {
+        get_recipients(&e)
+    }
+
+    // Sets the max slippage allowed when converting the token to the target token.
+    // Conversion of the token is not possible until the limit is configured.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `token` - The address of the token.
+    // * `max_slippage` - The max slippage in basis points.
+    fn set_max_slippage(e: Env, admin: Address, token: Address, max_slippage: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        if max_slippage > FEE_MULTIPLIER {
+            panic_with_error!(&e, FeesCollectorError::InvalidSlippage);
+        }
+        set_max_slippage(&e, &token, &max_slippage);
+        Events::new(&e).set_max_slippage(token, max_slippage);
+    }
+
+    // Returns the max slippage in basis points configured for the token.
+    fn get_max_slippage(e: Env, token: Address) -> Option<u32> {
+        get_max_slippage(&e, &token)
+    }
+}
+
+#[contractimpl]
+impl FeesCollectorInterface for FeesCollector {
+    // Claims protocol fees from the pools to the collector.
+    // The collector should be the system fee admin of the pools.
+    //
+    // # Arguments
+    //
+    // * `operator` - The address of the operations admin or admin.
+    // * `pools` - The list of pool addresses to claim fees from.
+    //
+    // # Returns
+    //
+    // A map of claimed tokens to their total amounts.
+    fn collect_fees(e: Env, operator: Address, pools: Vec<Address>) -> Map<Address, u128> {
+        operator.require_auth();
+        require_operations_admin_or_owner(&e, &operator);
+
+        let mut result: Map<Address, u128> = Map::new(&e);
+        for pool in pools.iter() {
+            let (tokens, amounts) = match claim_pool_fees(&e, &pool) {
+                Some(v) => v,
+                None => continue,
+            };
+            for (token, amount) in tokens.iter().zip(amounts.iter()) {
+                result.set(token.clone(), result.get(token).unwrap_or(0) + amount);
+            }
+            Events::new(&e).collect_fees(pool, tokens, amounts);
+        }
+        result
+    }
+
+    // Converts the collector balance of the token to the target token through the router.
+    // `out_min` should not allow more slippage than configured for the token
+    // relative to the router estimation.
+    //
+    // # Arguments
+    //
+    // * `operator` - The address of the operations admin or admin.
+    // * `token` - The address of the token to convert.
+    // * `swaps_chain` - The series of swaps ending with the target token.
+    // * `out_min` - The minimum amount of the target token to receive.
+    //
+    // # Returns
+    //
+    // The amount of the target token received.
+    fn convert_fees(
+        e: Env,
+        operator: Address,
+        token: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        out_min: u128,
+    ) -> u128 {
+        operator.require_auth();
+        require_operations_admin_or_owner(&e, &operator);
+
+        let target_token = get_target_token(&e);
+        let (_, _, token_out) = match swaps_chain.last() {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::PathIsEmpty),
+        };
+        if token_out != target_token {
+            panic_with_error!(&e, FeesCollectorError::PathNotToTarget);
+        }
+        let max_slippage = match get_max_slippage(&e, &token) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::SlippageNotConfigured),
+        };
+
+        let amount = (SorobanTokenClient::new(&e, &token).balance(&e.current_contract_address())
+            as u128)
+            .saturating_sub(get_buyback_budget(&e, &token));
+        if amount == 0 {
+            return 0;
+        }
+
+        let router = get_router(&e);
+        let estimated = estimate_swaps_chain(&e, &router, &swaps_chain, &token, amount);
+        let min_allowed =
+            estimated * (FEE_MULTIPLIER - max_slippage) as u128 / FEE_MULTIPLIER as u128;
+        if out_min < min_allowed {
+            panic_with_error!(&e, FeesCollectorError::OutMinTooLow);
+        }
+
+        let out_amount = swap_chained(&e, &router, &swaps_chain, &token, amount, out_min);
+        Events::new(&e).convert_fees(token, amount, target_token, out_amount);
+        out_amount
+    }
+
+    // Splits the collector balance of the target token between the recipients by their shares.
+    // The buyback budget is excluded; the share of the collector itself is added to it.
+    // Rounding dust goes to the last recipient.
+    //
+    // # Arguments
+    //
+    // * `operator` - The address of the operations admin or admin.
+    //
+    // # Returns
+    //
+    // The amounts sent to the recipients in the order of configuration.
+    fn distribute_fees(e: Env, operator: Address) -> Vec<u128> {
+        operator.require_auth();
+        require_operations_admin_or_owner(&e, &operator);
+
+        let recipients = get_recipients(&e);
+        if recipients.is_empty() {
+            panic_with_error!(&e, FeesCollectorError::InvalidRecipients);
+        }
+
+        let target_token = get_target_token(&e);
+        let token_client = SorobanTokenClient::new(&e, &target_token);
+        let total = (token_client.balance(&e.current_contract_address()) as u128)
+            .saturating_sub(get_buyback_budget(&e, &target_token));
+
+        let mut result = Vec::new(&e);
+        let mut distributed = 0;
+        for (i, (recipient, share)) in recipients.iter().enumerate() {
+            let amount = if i as u32 == recipients.len() - 1 {
+                total - distributed
+            } else {
+                total * share as u128 / FEE_MULTIPLIER as u128
+            };
+            distributed += amount;
+            result.push_back(amount);
+            if amount == 0 {
+                continue;
+            }
+
+            if recipient == e.current_contract_address() {
+                let buyback_budget = get_buyback_budget(&e, &target_token);
+                set_buyback_budget(&e, &target_token, &(buyback_budget + amount));
+            } else {
+                token_client.transfer(&e.current_contract_address(), &recipient, &(amount as i128));
+            }
+            Events::new(&e).distribute_fees(recipient, target_token.clone(), amount);
+        }
+        result
+    }
+}
+
+#[contractimpl]
+impl BuybackInterface for FeesCollector {
+    // Sets the buyback-and-burn configuration.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `reward_token` - The token to buy back and burn.
+    // * `max_slippage` - The max slippage relative to the router estimation in basis points.
+    // * `interval` - The min time between buybacks in seconds.
+    // * `sink` - The irrecoverable address to send bought tokens to. Tokens are burned if not set.
+    fn set_buyback_config(
+        e: Env,
+        admin: Address,
+        reward_token: Address,
+        max_slippage: u32,
+        interval: u64,
+        sink: Option<Address>,
+    ) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        if max_slippage > FEE_MULTIPLIER {
+            panic_with_error!(&e, FeesCollectorError::InvalidSlippage);
+        }
+        set_buyback_config(
+            &e,
+            &BuybackConfig {
+                reward_token: reward_token.clone(),
+                max_slippage,
+                interval,
+                sink: sink.clone(),
+            },
+        );
+        Events::new(&e).set_buyback_config(reward_token, max_slippage, interval, sink);
+    }
+
+    // Returns the buyback-and-burn configuration.
+    fn get_buyback_config(e: Env) -> BuybackConfig {
+        get_buyback_config(&e)
+    }
+
+    // Swaps the buyback budget of the token to the reward token through the router and burns it.
+    // The budget is filled by distributing fees with the collector itself among the recipients.
+    // Can be executed at most once per configured interval.
+    // The reward token budget itself is burned without swaps if the chain is empty.
+    //
+    // # Arguments
+    //
+    // * `operator` - The address of the operations admin or admin.
+    // * `token` - The address of the token to buy back with.
+    // * `swaps_chain` - The series of swaps ending with the reward token.
+    // * `out_min` - The minimum amount of the reward token to receive.
+    //
+    // # Returns
+    //
+    // The amount of the reward token burned.
+    fn buyback(
+        e: Env,
+        operator: Address,
+        token: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        out_min: u128,
+    ) -> u128 {
+        operator.require_auth();
+        require_operations_admin_or_owner(&e, &operator);
+
+        let config = get_buyback_config(&e);
+        assert_buyback_interval(&e, &config);
+        if let Some((_, _, token_out)) = swaps_chain.last() {
+            if token_out != config.reward_token {
+                panic_with_error!(&e, FeesCollectorError::PathNotToTarget);
+            }
+        } else if token != config.reward_token {
+            panic_with_error!(&e, FeesCollectorError::PathIsEmpty);
+        }
+
+        let amount = get_buyback_budget(&e, &token);
+        set_buyback_budget(&e, &token, &0);
+        let bought = if swaps_chain.is_empty() || amount == 0 {
+            amount
+        } else {
+            let router = get_router(&e);
+            let estimated = estimate_swaps_chain(&e, &router, &swaps_chain, &token, amount);
+            let min_allowed =
+                estimated * (FEE_MULTIPLIER - config.max_slippage) as u128 / FEE_MULTIPLIER as u128;
+            if out_min < min_allowed {
+                panic_with_error!(&e, FeesCollectorError::OutMinTooLow);
+            }
+            swap_chained(&e, &router, &swaps_chain, &token, amount, out_min)
+        };
+
+        if bought > 0 {
+            burn_reward_token(&e, &config, bought);
+        }
+        set_burned_amount(&e, &(get_burned_amount(&e) + bought));
+        set_last_buyback_time(&e, &e.ledger().timestamp());
+        Events::new(&e).buyback(token, amount, config.reward_token, bought);
+        bought
+    }
+
+    // Returns the amount of the token reserved for buybacks.
+    fn get_buyback_budget(e: Env, token: Address) -> u128 {
+        get_buyback_budget(&e, &token)
+    }
+
+    // Returns the cumulative amount of the reward token burned by buybacks.
+    fn get_burned_amount(e: Env) -> u128 {
+        get_burned_amount(&e)
+    }
+
+    // Returns the timestamp of the last buyback.
+    fn get_last_buyback_time(e: Env) -> Option<u64> {
+        get_last_buyback_time(&e)
+    }
 }
 
 // The `UpgradeableContract` trait provides the interface for upgrading the contract.
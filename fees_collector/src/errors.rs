@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone)]
+#[repr(u32)]
+pub enum FeesCollectorError {
+    PathIsEmpty = 307,
+    InvalidRecipients = 3100,
+    InvalidSlippage = 3101,
+    SlippageNotConfigured = 3102,
+    OutMinTooLow = 3103,
+    PathNotToTarget = 3104,
+    BuybackTooEarly = 3105,
+}
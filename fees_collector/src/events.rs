@@ -0,0 +1,110 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+#[derive(Clone)]
+pub(crate) struct Events(Env);
+
+impl Events {
+    #[inline(always)]
+    pub(crate) fn env(&self) -> &Env {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub(crate) fn new(env: &Env) -> Events {
+        Events(env.clone())
+    }
+}
+
+pub(crate) trait FeesCollectorEvents {
+    fn set_router(&self, router: Address);
+
+    fn set_target_token(&self, token: Address);
+
+    fn set_recipients(&self, recipients: Vec<(Address, u32)>);
+
+    fn set_max_slippage(&self, token: Address, max_slippage: u32);
+
+    fn collect_fees(&self, pool: Address, tokens: Vec<Address>, amounts: Vec<u128>);
+
+    fn convert_fees(&self, token: Address, amount: u128, token_out: Address, out_amount: u128);
+
+    fn distribute_fees(&self, recipient: Address, token: Address, amount: u128);
+
+    fn set_buyback_config(
+        &self,
+        reward_token: Address,
+        max_slippage: u32,
+        interval: u64,
+        sink: Option<Address>,
+    );
+
+    fn buyback(&self, token: Address, amount: u128, reward_token: Address, burned_amount: u128);
+}
+
+impl FeesCollectorEvents for Events {
+    fn set_router(&self, router: Address) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_router"),), (router,));
+    }
+
+    fn set_target_token(&self, token: Address) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_target_token"),), (token,));
+    }
+
+    fn set_recipients(&self, recipients: Vec<(Address, u32)>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_recipients"),), (recipients,));
+    }
+
+    fn set_max_slippage(&self, token: Address, max_slippage: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_max_slippage"), token),
+            (max_slippage,),
+        );
+    }
+
+    fn collect_fees(&self, pool: Address, tokens: Vec<Address>, amounts: Vec<u128>) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "collect_fees"), pool),
+            (tokens, amounts),
+        );
+    }
+
+    fn convert_fees(&self, token: Address, amount: u128, token_out: Address, out_amount: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "convert_fees"), token, token_out),
+            (amount, out_amount),
+        );
+    }
+
+    fn distribute_fees(&self, recipient: Address, token: Address, amount: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "distribute_fees"), recipient),
+            (token, amount),
+        );
+    }
+
+    fn set_buyback_config(
+        &self,
+        reward_token: Address,
+        max_slippage: u32,
+        interval: u64,
+        sink: Option<Address>,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_buyback_config"),),
+            (reward_token, max_slippage, interval, sink),
+        );
+    }
+
+    fn buyback(&self, token: Address, amount: u128, reward_token: Address, burned_amount: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "buyback"), token, reward_token),
+            (amount, burned_amount),
+        );
+    }
+}
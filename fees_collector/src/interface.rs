@@ -1,6 +1,88 @@
-use soroban_sdk::{Address, Env};
+use crate::storage::BuybackConfig;
+use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Vec};
 
 pub trait AdminInterface {
     // Initializes the admin user.
     fn init_admin(e: Env, account: Address);
+
+    // Set privileged addresses
+    fn set_privileged_addrs(e: Env, admin: Address, operations_admin: Address);
+
+    // Get map of privileged roles
+    fn get_privileged_addrs(e: Env) -> Map<Symbol, Vec<Address>>;
+
+    // Set router used to convert collected fees
+    fn set_router(e: Env, admin: Address, router: Address);
+
+    // Get router used to convert collected fees
+    fn get_router(e: Env) -> Address;
+
+    // Set token collected fees are converted to
+    fn set_target_token(e: Env, admin: Address, token: Address);
+
+    // Get token collected fees are converted to
+    fn get_target_token(e: Env) -> Address;
+
+    // Set recipients of converted fees with their shares in basis points
+    fn set_recipients(e: Env, admin: Address, recipients: Vec<(Address, u32)>);
+
+    // Get recipients of converted fees with their shares in basis points
+    fn get_recipients(e: Env) -> Vec<(Address, u32)>;
+
+    // Set max slippage in basis points allowed when converting the token
+    fn set_max_slippage(e: Env, admin: Address, token: Address, max_slippage: u32);
+
+    // Get max slippage in basis points allowed when converting the token
+    fn get_max_slippage(e: Env, token: Address) -> Option<u32>;
+}
+
+pub trait FeesCollectorInterface {
+    // Claim protocol fees from the pools to the collector
+    fn collect_fees(e: Env, operator: Address, pools: Vec<Address>) -> Map<Address, u128>;
+
+    // Convert collected token balance, except the buyback budget, to the target token through the router
+    fn convert_fees(
+        e: Env,
+        operator: Address,
+        token: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        out_min: u128,
+    ) -> u128;
+
+    // Split target token balance, except the buyback budget, between recipients.
+    // Share of the collector itself is added to the buyback budget.
+    fn distribute_fees(e: Env, operator: Address) -> Vec<u128>;
+}
+
+pub trait BuybackInterface {
+    // Set buyback-and-burn configuration
+    fn set_buyback_config(
+        e: Env,
+        admin: Address,
+        reward_token: Address,
+        max_slippage: u32,
+        interval: u64,
+        sink: Option<Address>,
+    );
+
+    // Get buyback-and-burn configuration
+    fn get_buyback_config(e: Env) -> BuybackConfig;
+
+    // Swap the buyback budget of the token to the reward token and burn it
+    fn buyback(
+        e: Env,
+        operator: Address,
+        token: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        out_min: u128,
+    ) -> u128;
+
+    // Get amount of the token reserved for buybacks
+    fn get_buyback_budget(e: Env, token: Address) -> u128;
+
+    // Get cumulative amount of the reward token burned by buybacks
+    fn get_burned_amount(e: Env) -> u128;
+
+    // Get timestamp of the last buyback
+    fn get_last_buyback_time(e: Env) -> Option<u64>;
 }
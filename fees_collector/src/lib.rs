@@ -1,7 +1,11 @@
 #![no_std]
 
+mod buyback;
 mod contract;
+mod errors;
+mod events;
 mod interface;
+mod pipeline;
 mod storage;
 mod test;
 mod test_permissions;
@@ -0,0 +1,105 @@
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
+
+// Claims protocol fees of the pool to the current contract.
+// Pools without accumulated fees are skipped to save a claim call.
+//
+// # Returns
+//
+// Pool tokens with claimed amounts or None if there was nothing to claim.
+pub(crate) fn claim_pool_fees(e: &Env, pool: &Address) -> Option<(Vec<Address>, Vec<u128>)> {
+    let fees: Vec<u128> =
+        e.invoke_contract(pool, &Symbol::new(e, "get_protocol_fees"), Vec::new(e));
+    if fees.iter().all(|fee| fee == 0) {
+        return None;
+    }
+
+    let tokens: Vec<Address> = e.invoke_contract(pool, &Symbol::new(e, "get_tokens"), Vec::new(e));
+    let claimed: Vec<u128> = e.invoke_contract(
+        pool,
+        &Symbol::new(e, "claim_protocol_fees"),
+        Vec::from_array(
+            e,
+            [
+                e.current_contract_address().to_val(),
+                e.current_contract_address().to_val(),
+            ],
+        ),
+    );
+    Some((tokens, claimed))
+}
+
+// Estimates the output of the swaps chain hop by hop with the router `estimate_swap`.
+pub(crate) fn estimate_swaps_chain(
+    e: &Env,
+    router: &Address,
+    swaps_chain: &Vec<(Vec<Address>, BytesN<32>, Address)>,
+    token_in: &Address,
+    in_amount: u128,
+) -> u128 {
+    let mut amount = in_amount;
+    let mut token = token_in.clone();
+    for (tokens, pool_index, token_out) in swaps_chain.iter() {
+        amount = e.invoke_contract(
+            router,
+            &Symbol::new(e, "estimate_swap"),
+            Vec::from_array(
+                e,
+                [
+                    tokens.to_val(),
+                    token.to_val(),
+                    token_out.to_val(),
+                    pool_index.to_val(),
+                    amount.into_val(e),
+                ],
+            ),
+        );
+        token = token_out;
+    }
+    amount
+}
+
+// Swaps the amount of the current contract tokens through the router `swap_chained`.
+//
+// # Returns
+//
+// The amount of the last token in chain received.
+pub(crate) fn swap_chained(
+    e: &Env,
+    router: &Address,
+    swaps_chain: &Vec<(Vec<Address>, BytesN<32>, Address)>,
+    token_in: &Address,
+    in_amount: u128,
+    out_min: u128,
+) -> u128 {
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token_in.clone(),
+                fn_name: Symbol::new(e, "transfer"),
+                args: (
+                    e.current_contract_address(),
+                    router.clone(),
+                    in_amount as i128,
+                )
+                    .into_val(e),
+            },
+            sub_invocations: vec![e],
+        }),
+    ]);
+    e.invoke_contract(
+        router,
+        &Symbol::new(e, "swap_chained"),
+        Vec::from_array(
+            e,
+            [
+                e.current_contract_address().to_val(),
+                swaps_chain.to_val(),
+                token_in.to_val(),
+                in_amount.into_val(e),
+                out_min.into_val(e),
+            ],
+        ),
+    )
+}
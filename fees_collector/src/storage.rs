@@ -1 +1,97 @@
+use paste::paste;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+use utils::bump::{bump_instance, bump_persistent};
+use utils::storage_errors::StorageError;
+use utils::{
+    generate_instance_storage_getter, generate_instance_storage_getter_and_setter,
+    generate_instance_storage_getter_and_setter_with_default,
+    generate_instance_storage_getter_with_default, generate_instance_storage_setter,
+};
 
+// Denominator for recipient shares and slippage limits (basis points)
+pub(crate) const FEE_MULTIPLIER: u32 = 10_000;
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Router,                 // Address of the AMM router used for conversions.
+    TargetToken,            // Token the collected fees are converted to.
+    Recipients,             // Recipients of converted fees with their shares in basis points.
+    MaxSlippage(Address),   // Max conversion slippage for the token in basis points.
+    BuybackConfig,          // Buyback-and-burn configuration.
+    LastBuybackTime,        // Timestamp of the last buyback.
+    BurnedAmount,           // Cumulative amount of the reward token burned by buybacks.
+    BuybackBudget(Address), // Token amount reserved for buybacks from the collector's own share.
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct BuybackConfig {
+    // Token bought back and burned.
+    pub reward_token: Address,
+    // Max slippage relative to the router estimation in basis points.
+    pub max_slippage: u32,
+    // Min time between buybacks in seconds.
+    pub interval: u64,
+    // Irrecoverable address to send bought tokens to instead of burning them.
+    pub sink: Option<Address>,
+}
+
+generate_instance_storage_getter_and_setter!(router, DataKey::Router, Address);
+generate_instance_storage_getter_and_setter!(target_token, DataKey::TargetToken, Address);
+generate_instance_storage_getter_and_setter!(buyback_config, DataKey::BuybackConfig, BuybackConfig);
+generate_instance_storage_getter_and_setter_with_default!(
+    burned_amount,
+    DataKey::BurnedAmount,
+    u128,
+    0
+);
+generate_instance_storage_setter!(recipients, DataKey::Recipients, Vec<(Address, u32)>);
+
+pub fn get_recipients(e: &Env) -> Vec<(Address, u32)> {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::Recipients)
+        .unwrap_or(Vec::new(e))
+}
+
+pub fn get_max_slippage(e: &Env, token: &Address) -> Option<u32> {
+    let key = DataKey::MaxSlippage(token.clone());
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_max_slippage(e: &Env, token: &Address, value: &u32) {
+    let key = DataKey::MaxSlippage(token.clone());
+    e.storage().persistent().set(&key, value);
+    bump_persistent(e, &key);
+}
+
+pub fn get_buyback_budget(e: &Env, token: &Address) -> u128 {
+    let key = DataKey::BuybackBudget(token.clone());
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value.unwrap_or(0)
+}
+
+pub fn set_buyback_budget(e: &Env, token: &Address, value: &u128) {
+    let key = DataKey::BuybackBudget(token.clone());
+    e.storage().persistent().set(&key, value);
+    bump_persistent(e, &key);
+}
+
+pub fn get_last_buyback_time(e: &Env) -> Option<u64> {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::LastBuybackTime)
+}
+
+pub fn set_last_buyback_time(e: &Env, value: &u64) {
+    bump_instance(e);
+    e.storage().instance().set(&DataKey::LastBuybackTime, value)
+}
@@ -1,10 +1,10 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::testutils::{create_contract, install_dummy_wasm, jump, Setup};
+use crate::testutils::{create_contract, install_dummy_wasm, jump, PipelineSetup, Setup};
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use soroban_sdk::testutils::{Address as _, Events};
-use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, Symbol};
+use soroban_sdk::{map, symbol_short, vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
 
 #[test]
 fn test() {
@@ -195,3 +195,572 @@ fn test_regular_upgrade() {
 
     assert_eq!(contract.version(), 130)
 }
+
+fn generate_protocol_fees(setup: &PipelineSetup) {
+    let user = Address::generate(&setup.env);
+    let tokens = vec![
+        &setup.env,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    setup.token_a_admin_client.mint(&user, &1000_0000000);
+    setup.token_b_admin_client.mint(&user, &1000_0000000);
+    setup.router.swap(
+        &user,
+        &tokens,
+        &setup.token_a.address,
+        &setup.token_b.address,
+        &setup.pool_index,
+        &1000_0000000,
+        &0,
+    );
+    setup.router.swap(
+        &user,
+        &tokens,
+        &setup.token_b.address,
+        &setup.token_a.address,
+        &setup.pool_index,
+        &1000_0000000,
+        &0,
+    );
+}
+
+fn swaps_chain_to_b(setup: &PipelineSetup) -> Vec<(Vec<Address>, BytesN<32>, Address)> {
+    vec![
+        &setup.env,
+        (
+            vec![
+                &setup.env,
+                setup.token_a.address.clone(),
+                setup.token_b.address.clone(),
+            ],
+            setup.pool_index.clone(),
+            setup.token_b.address.clone(),
+        ),
+    ]
+}
+
+#[test]
+fn test_fees_pipeline() {
+    let setup = PipelineSetup::default();
+    let e = &setup.env;
+    let collector = &setup.collector;
+    generate_protocol_fees(&setup);
+
+    // 0.3% fee, half of it goes to protocol
+    let fees = setup.pool.get_protocol_fees();
+    assert_eq!(fees, vec![e, 1_5000000, 1_5000000]);
+
+    let collected = collector.collect_fees(
+        &setup.operations_admin,
+        &vec![e, setup.pool.address.clone()],
+    );
+    assert_eq!(
+        collected,
+        map![
+            e,
+            (setup.token_a.address.clone(), 1_5000000),
+            (setup.token_b.address.clone(), 1_5000000)
+        ]
+    );
+    assert_eq!(
+        e.events().all().filter_by_contract(&collector.address),
+        vec![
+            e,
+            (
+                collector.address.clone(),
+                (Symbol::new(e, "collect_fees"), setup.pool.address.clone()).into_val(e),
+                (
+                    vec![
+                        e,
+                        setup.token_a.address.clone(),
+                        setup.token_b.address.clone()
+                    ],
+                    fees
+                )
+                    .into_val(e),
+            ),
+        ]
+    );
+    assert_eq!(setup.pool.get_protocol_fees(), vec![e, 0, 0]);
+    assert_eq!(setup.token_a.balance(&collector.address), 1_5000000);
+
+    // pools without fees are skipped
+    assert_eq!(
+        collector.collect_fees(
+            &setup.operations_admin,
+            &vec![e, setup.pool.address.clone()]
+        ),
+        map![e]
+    );
+
+    collector.set_max_slippage(&setup.admin, &setup.token_a.address, &100);
+    let estimated = setup.router.estimate_swap(
+        &vec![
+            e,
+            setup.token_a.address.clone(),
+            setup.token_b.address.clone(),
+        ],
+        &setup.token_a.address,
+        &setup.token_b.address,
+        &setup.pool_index,
+        &1_5000000,
+    );
+    let converted = collector.convert_fees(
+        &setup.operations_admin,
+        &setup.token_a.address,
+        &swaps_chain_to_b(&setup),
+        &(estimated * 99 / 100),
+    );
+    assert_eq!(converted, estimated);
+    assert_eq!(
+        e.events().all().filter_by_contract(&collector.address),
+        vec![
+            e,
+            (
+                collector.address.clone(),
+                (
+                    Symbol::new(e, "convert_fees"),
+                    setup.token_a.address.clone(),
+                    setup.token_b.address.clone()
+                )
+                    .into_val(e),
+                (1_5000000_u128, converted).into_val(e),
+            ),
+        ]
+    );
+    assert_eq!(setup.token_a.balance(&collector.address), 0);
+
+    let treasury = Address::generate(e);
+    let buyback = Address::generate(e);
+    let rebates = Address::generate(e);
+    collector.set_recipients(
+        &setup.admin,
+        &vec![
+            e,
+            (treasury.clone(), 5000),
+            (buyback.clone(), 3000),
+            (rebates.clone(), 2000),
+        ],
+    );
+    let total = 1_5000000 + converted;
+    assert_eq!(setup.token_b.balance(&collector.address) as u128, total);
+    let distributed = collector.distribute_fees(&setup.operations_admin);
+    assert_eq!(
+        e.events().all().filter_by_contract(&collector.address),
+        vec![
+            e,
+            (
+                collector.address.clone(),
+                (Symbol::new(e, "distribute_fees"), treasury.clone()).into_val(e),
+                (setup.token_b.address.clone(), distributed.get(0).unwrap()).into_val(e),
+            ),
+            (
+                collector.address.clone(),
+                (Symbol::new(e, "distribute_fees"), buyback.clone()).into_val(e),
+                (setup.token_b.address.clone(), distributed.get(1).unwrap()).into_val(e),
+            ),
+            (
+                collector.address.clone(),
+                (Symbol::new(e, "distribute_fees"), rebates.clone()).into_val(e),
+                (setup.token_b.address.clone(), distributed.get(2).unwrap()).into_val(e),
+            ),
+        ]
+    );
+    assert_eq!(
+        distributed,
+        vec![
+            e,
+            total * 5000 / 10000,
+            total * 3000 / 10000,
+            total - total * 5000 / 10000 - total * 3000 / 10000
+        ]
+    );
+    assert_eq!(
+        setup.token_b.balance(&treasury) as u128,
+        distributed.get(0).unwrap()
+    );
+    assert_eq!(
+        setup.token_b.balance(&buyback) as u128,
+        distributed.get(1).unwrap()
+    );
+    assert_eq!(
+        setup.token_b.balance(&rebates) as u128,
+        distributed.get(2).unwrap()
+    );
+    assert_eq!(setup.token_b.balance(&collector.address), 0);
+}
+
+#[should_panic(expected = "Error(Contract, #3103)")]
+#[test]
+fn test_convert_fees_slippage_over_limit() {
+    let setup = PipelineSetup::default();
+    generate_protocol_fees(&setup);
+    setup.collector.collect_fees(
+        &setup.operations_admin,
+        &vec![&setup.env, setup.pool.address.clone()],
+    );
+    setup
+        .collector
+        .set_max_slippage(&setup.admin, &setup.token_a.address, &100);
+    setup.collector.convert_fees(
+        &setup.operations_admin,
+        &setup.token_a.address,
+        &swaps_chain_to_b(&setup),
+        &(1_5000000 * 97 / 100),
+    );
+}
+
+#[should_panic(expected = "Error(Contract, #3102)")]
+#[test]
+fn test_convert_fees_slippage_not_configured() {
+    let setup = PipelineSetup::default();
+    generate_protocol_fees(&setup);
+    setup.collector.collect_fees(
+        &setup.operations_admin,
+        &vec![&setup.env, setup.pool.address.clone()],
+    );
+    setup.collector.convert_fees(
+        &setup.operations_admin,
+        &setup.token_a.address,
+        &swaps_chain_to_b(&setup),
+        &0,
+    );
+}
+
+#[should_panic(expected = "Error(Contract, #3104)")]
+#[test]
+fn test_convert_fees_path_not_to_target() {
+    let setup = PipelineSetup::default();
+    setup
+        .collector
+        .set_target_token(&setup.admin, &setup.token_a.address);
+    setup
+        .collector
+        .set_max_slippage(&setup.admin, &setup.token_a.address, &100);
+    setup.collector.convert_fees(
+        &setup.operations_admin,
+        &setup.token_a.address,
+        &swaps_chain_to_b(&setup),
+        &0,
+    );
+}
+
+#[test]
+fn test_set_recipients_invalid_shares() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let treasury = Address::generate(e);
+    let rebates = Address::generate(e);
+
+    for recipients in [
+        vec![e],
+        vec![e, (treasury.clone(), 5000), (rebates.clone(), 4000)],
+        vec![e, (treasury.clone(), 10000), (rebates.clone(), 0)],
+        vec![e, (treasury.clone(), 6000), (rebates.clone(), 6000)],
+        vec![e, (treasury.clone(), 5000), (treasury.clone(), 5000)],
+    ] {
+        assert!(setup
+            .collector
+            .try_set_recipients(&setup.admin, &recipients)
+            .is_err());
+    }
+    setup.collector.set_recipients(
+        &setup.admin,
+        &vec![e, (treasury.clone(), 6000), (rebates.clone(), 4000)],
+    );
+    assert_eq!(
+        setup.collector.get_recipients(),
+        vec![e, (treasury, 6000), (rebates, 4000)]
+    );
+}
+
+#[should_panic(expected = "Error(Contract, #3101)")]
+#[test]
+fn test_set_max_slippage_over_max() {
+    let setup = Setup::default();
+    setup
+        .collector
+        .set_max_slippage(&setup.admin, &Address::generate(&setup.env), &10001);
+}
+
+#[test]
+fn test_buyback_and_burn() {
+    let setup = PipelineSetup::default();
+    let e = &setup.env;
+    let collector = &setup.collector;
+    let treasury = Address::generate(e);
+    setup
+        .token_b_admin_client
+        .mint(&collector.address, &10_0000000);
+
+    // collector keeps its own share as the buyback budget
+    collector.set_recipients(
+        &setup.admin,
+        &vec![
+            e,
+            (treasury.clone(), 7000),
+            (collector.address.clone(), 3000),
+        ],
+    );
+    assert_eq!(
+        collector.distribute_fees(&setup.operations_admin),
+        vec![e, 7_0000000, 3_0000000]
+    );
+    assert_eq!(
+        e.events().all().filter_by_contract(&collector.address),
+        vec![
+            e,
+            (
+                collector.address.clone(),
+                (Symbol::new(e, "distribute_fees"), treasury.clone()).into_val(e),
+                (setup.token_b.address.clone(), 7_0000000_u128).into_val(e),
+            ),
+            (
+                collector.address.clone(),
+                (Symbol::new(e, "distribute_fees"), collector.address.clone()).into_val(e),
+                (setup.token_b.address.clone(), 3_0000000_u128).into_val(e),
+            ),
+        ]
+    );
+    assert_eq!(setup.token_b.balance(&treasury), 7_0000000);
+    assert_eq!(setup.token_b.balance(&collector.address), 3_0000000);
+    assert_eq!(
+        collector.get_buyback_budget(&setup.token_b.address),
+        3_0000000
+    );
+
+    // fees collected after the distribution are not part of the budget
+    setup
+        .token_b_admin_client
+        .mint(&collector.address, &1_0000000);
+    collector.set_buyback_config(&setup.admin, &setup.token_b.address, &100, &86400, &None);
+    assert_eq!(
+        collector.buyback(
+            &setup.operations_admin,
+            &setup.token_b.address,
+            &Vec::new(e),
+            &0
+        ),
+        3_0000000
+    );
+    assert_eq!(
+        e.events().all().filter_by_contract(&collector.address),
+        vec![
+            e,
+            (
+                collector.address.clone(),
+                (
+                    Symbol::new(e, "buyback"),
+                    setup.token_b.address.clone(),
+                    setup.token_b.address.clone()
+                )
+                    .into_val(e),
+                (3_0000000_u128, 3_0000000_u128).into_val(e),
+            ),
+        ]
+    );
+    assert_eq!(setup.token_b.balance(&collector.address), 1_0000000);
+    assert_eq!(setup.token_b.balance(&treasury), 7_0000000);
+    assert_eq!(collector.get_buyback_budget(&setup.token_b.address), 0);
+    assert_eq!(collector.get_burned_amount(), 3_0000000);
+    assert_eq!(collector.get_last_buyback_time(), Some(0));
+
+    // once per interval
+    assert!(collector
+        .try_buyback(
+            &setup.operations_admin,
+            &setup.token_b.address,
+            &Vec::new(e),
+            &0
+        )
+        .is_err());
+    jump(e, 86400);
+    assert_eq!(
+        collector.buyback(
+            &setup.operations_admin,
+            &setup.token_b.address,
+            &Vec::new(e),
+            &0
+        ),
+        0
+    );
+    assert_eq!(setup.token_b.balance(&collector.address), 1_0000000);
+    assert_eq!(collector.get_burned_amount(), 3_0000000);
+    assert_eq!(collector.get_last_buyback_time(), Some(86400));
+
+    // remaining balance is distributed again, the budget excluded
+    assert_eq!(
+        collector.distribute_fees(&setup.operations_admin),
+        vec![e, 7000000, 3000000]
+    );
+    assert_eq!(setup.token_b.balance(&treasury), 7_7000000);
+    assert_eq!(setup.token_b.balance(&collector.address), 3000000);
+    assert_eq!(
+        collector.get_buyback_budget(&setup.token_b.address),
+        3000000
+    );
+}
+
+fn swaps_chain_to_a(setup: &PipelineSetup) -> Vec<(Vec<Address>, BytesN<32>, Address)> {
+    vec![
+        &setup.env,
+        (
+            vec![
+                &setup.env,
+                setup.token_a.address.clone(),
+                setup.token_b.address.clone(),
+            ],
+            setup.pool_index.clone(),
+            setup.token_a.address.clone(),
+        ),
+    ]
+}
+
+#[test]
+fn test_buyback_swapped() {
+    let setup = PipelineSetup::default();
+    let e = &setup.env;
+    let collector = &setup.collector;
+    let treasury = Address::generate(e);
+    generate_protocol_fees(&setup);
+    collector.collect_fees(
+        &setup.operations_admin,
+        &vec![e, setup.pool.address.clone()],
+    );
+    collector.set_recipients(
+        &setup.admin,
+        &vec![
+            e,
+            (treasury.clone(), 5000),
+            (collector.address.clone(), 5000),
+        ],
+    );
+    collector.distribute_fees(&setup.operations_admin);
+    assert_eq!(
+        collector.get_buyback_budget(&setup.token_b.address),
+        7500000
+    );
+
+    collector.set_buyback_config(&setup.admin, &setup.token_a.address, &100, &86400, &None);
+    let estimated = setup.router.estimate_swap(
+        &vec![
+            e,
+            setup.token_a.address.clone(),
+            setup.token_b.address.clone(),
+        ],
+        &setup.token_b.address,
+        &setup.token_a.address,
+        &setup.pool_index,
+        &7500000,
+    );
+    let burned = collector.buyback(
+        &setup.operations_admin,
+        &setup.token_b.address,
+        &swaps_chain_to_a(&setup),
+        &(estimated * 99 / 100),
+    );
+    assert_eq!(burned, estimated);
+    assert_eq!(
+        e.events().all().filter_by_contract(&collector.address),
+        vec![
+            e,
+            (
+                collector.address.clone(),
+                (
+                    Symbol::new(e, "buyback"),
+                    setup.token_b.address.clone(),
+                    setup.token_a.address.clone()
+                )
+                    .into_val(e),
+                (7500000_u128, estimated).into_val(e),
+            ),
+        ]
+    );
+    // collected reward token is untouched, bought tokens are burned
+    assert_eq!(setup.token_a.balance(&collector.address), 1_5000000);
+    assert_eq!(setup.token_b.balance(&collector.address), 0);
+    assert_eq!(setup.token_b.balance(&treasury), 7500000);
+    assert_eq!(collector.get_buyback_budget(&setup.token_b.address), 0);
+    assert_eq!(collector.get_burned_amount(), estimated);
+}
+
+#[test]
+fn test_buyback_to_sink() {
+    let setup = PipelineSetup::default();
+    let e = &setup.env;
+    let sink = Address::generate(e);
+    setup
+        .token_b_admin_client
+        .mint(&setup.collector.address, &1_0000000);
+    setup.collector.set_recipients(
+        &setup.admin,
+        &vec![e, (setup.collector.address.clone(), 10000)],
+    );
+    setup.collector.distribute_fees(&setup.operations_admin);
+
+    setup.collector.set_buyback_config(
+        &setup.admin,
+        &setup.token_b.address,
+        &100,
+        &86400,
+        &Some(sink.clone()),
+    );
+    setup.collector.buyback(
+        &setup.operations_admin,
+        &setup.token_b.address,
+        &Vec::new(e),
+        &0,
+    );
+    assert_eq!(setup.token_b.balance(&sink), 1_0000000);
+    assert_eq!(setup.collector.get_burned_amount(), 1_0000000);
+}
+
+#[should_panic(expected = "Error(Contract, #3105)")]
+#[test]
+fn test_buyback_too_early() {
+    let setup = PipelineSetup::default();
+    let e = &setup.env;
+    setup
+        .collector
+        .set_buyback_config(&setup.admin, &setup.token_b.address, &100, &86400, &None);
+    setup.collector.buyback(
+        &setup.operations_admin,
+        &setup.token_b.address,
+        &Vec::new(e),
+        &0,
+    );
+    jump(e, 86399);
+    setup.collector.buyback(
+        &setup.operations_admin,
+        &setup.token_b.address,
+        &Vec::new(e),
+        &0,
+    );
+}
+
+#[should_panic(expected = "Error(Contract, #3103)")]
+#[test]
+fn test_buyback_slippage_over_limit() {
+    let setup = PipelineSetup::default();
+    let e = &setup.env;
+    generate_protocol_fees(&setup);
+    setup.collector.collect_fees(
+        &setup.operations_admin,
+        &vec![e, setup.pool.address.clone()],
+    );
+    setup.collector.set_recipients(
+        &setup.admin,
+        &vec![e, (setup.collector.address.clone(), 10000)],
+    );
+    setup.collector.distribute_fees(&setup.operations_admin);
+    setup
+        .collector
+        .set_buyback_config(&setup.admin, &setup.token_a.address, &100, &86400, &None);
+    setup.collector.buyback(
+        &setup.operations_admin,
+        &setup.token_b.address,
+        &swaps_chain_to_a(&setup),
+        &(1_5000000 * 97 / 100),
+    );
+}
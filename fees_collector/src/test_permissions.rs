@@ -1,9 +1,9 @@
 #![cfg(test)]
 
-use crate::testutils::{create_contract, install_dummy_wasm, jump, Setup};
+use crate::testutils::{create_contract, install_dummy_wasm, jump, PipelineSetup, Setup};
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use soroban_sdk::{symbol_short, vec, Address, Env, Symbol, Vec};
 
 // test admin transfer ownership
 #[test]
@@ -375,3 +375,139 @@ fn test_set_emergency_mode_emergency_admin() {
         .try_set_emergency_mode(&setup.emergency_admin, &false)
         .is_ok());
 }
+
+// fees pipeline configuration
+#[test]
+fn test_set_privileged_addrs() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let operations_admin = Address::generate(&setup.env);
+    for (address, is_ok) in [
+        (Address::generate(&setup.env), false),
+        (setup.emergency_admin.clone(), false),
+        (setup.admin.clone(), true),
+    ] {
+        assert_eq!(
+            collector
+                .try_set_privileged_addrs(&address, &operations_admin)
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_set_fees_pipeline_config() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let operations_admin = Address::generate(&setup.env);
+    collector.set_privileged_addrs(&setup.admin, &operations_admin);
+
+    let token = Address::generate(&setup.env);
+    let recipients = vec![&setup.env, (Address::generate(&setup.env), 10000)];
+    for (address, is_ok) in [
+        (Address::generate(&setup.env), false),
+        (setup.emergency_admin.clone(), false),
+        (operations_admin.clone(), false),
+        (setup.admin.clone(), true),
+    ] {
+        assert_eq!(collector.try_set_router(&address, &token).is_ok(), is_ok);
+        assert_eq!(
+            collector.try_set_target_token(&address, &token).is_ok(),
+            is_ok
+        );
+        assert_eq!(
+            collector.try_set_recipients(&address, &recipients).is_ok(),
+            is_ok
+        );
+        assert_eq!(
+            collector
+                .try_set_max_slippage(&address, &token, &100)
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_collect_fees() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let operations_admin = Address::generate(&setup.env);
+    collector.set_privileged_addrs(&setup.admin, &operations_admin);
+
+    for (address, is_ok) in [
+        (Address::generate(&setup.env), false),
+        (setup.emergency_admin.clone(), false),
+        (operations_admin.clone(), true),
+        (setup.admin.clone(), true),
+    ] {
+        assert_eq!(
+            collector
+                .try_collect_fees(&address, &Vec::new(&setup.env))
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_distribute_fees() {
+    let setup = PipelineSetup::default();
+    let collector = setup.collector;
+    collector.set_recipients(
+        &setup.admin,
+        &vec![&setup.env, (Address::generate(&setup.env), 10000)],
+    );
+
+    for (address, is_ok) in [
+        (Address::generate(&setup.env), false),
+        (setup.operations_admin.clone(), true),
+        (setup.admin.clone(), true),
+    ] {
+        assert_eq!(collector.try_distribute_fees(&address).is_ok(), is_ok);
+    }
+}
+
+#[test]
+fn test_set_buyback_config() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let operations_admin = Address::generate(&setup.env);
+    collector.set_privileged_addrs(&setup.admin, &operations_admin);
+
+    let token = Address::generate(&setup.env);
+    for (address, is_ok) in [
+        (Address::generate(&setup.env), false),
+        (setup.emergency_admin.clone(), false),
+        (operations_admin.clone(), false),
+        (setup.admin.clone(), true),
+    ] {
+        assert_eq!(
+            collector
+                .try_set_buyback_config(&address, &token, &100, &86400, &None)
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_buyback() {
+    let setup = PipelineSetup::default();
+    let collector = setup.collector;
+    collector.set_buyback_config(&setup.admin, &setup.token_b.address, &100, &0, &None);
+
+    for (address, is_ok) in [
+        (Address::generate(&setup.env), false),
+        (setup.operations_admin.clone(), true),
+        (setup.admin.clone(), true),
+    ] {
+        assert_eq!(
+            collector
+                .try_buyback(&address, &setup.token_b.address, &Vec::new(&setup.env), &0)
+                .is_ok(),
+            is_ok
+        );
+    }
+}
@@ -1,9 +1,14 @@
 #![allow(dead_code)]
 #![cfg(test)]
+extern crate std;
 
 use crate::FeesCollectorClient;
+use liquidity_pool_config_storage::testutils::deploy_config_storage;
 use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
-use soroban_sdk::{Address, BytesN, Env, Symbol};
+use soroban_sdk::token::{
+    StellarAssetClient as SorobanTokenAdminClient, TokenClient as SorobanTokenClient,
+};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
 
 pub fn install_dummy_wasm<'a>(e: &Env) -> BytesN<32> {
     soroban_sdk::contractimport!(file = "../contracts/dummy_contract.wasm");
@@ -63,3 +68,152 @@ impl Default for Setup<'_> {
         }
     }
 }
+
+pub(crate) fn create_token_contract<'a>(e: &Env, admin: &Address) -> SorobanTokenClient<'a> {
+    SorobanTokenClient::new(
+        e,
+        &e.register_stellar_asset_contract_v2(admin.clone())
+            .address(),
+    )
+}
+
+pub(crate) fn get_token_admin_client<'a>(
+    e: &Env,
+    address: &Address,
+) -> SorobanTokenAdminClient<'a> {
+    SorobanTokenAdminClient::new(e, address)
+}
+
+pub mod liquidity_pool {
+    soroban_sdk::contractimport!(file = "../contracts/soroban_liquidity_pool_contract.wasm");
+}
+
+pub mod swap_router {
+    soroban_sdk::contractimport!(file = "../contracts/soroban_liquidity_pool_router_contract.wasm");
+}
+
+mod reward_boost_feed {
+    soroban_sdk::contractimport!(file = "../contracts/soroban_locker_feed_contract.wasm");
+}
+
+pub(crate) fn install_token_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(file = "../contracts/soroban_token_contract.wasm");
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+pub(crate) fn install_liq_pool_hash(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(file = "../contracts/soroban_liquidity_pool_contract.wasm");
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+pub(crate) fn deploy_plane_contract(e: &Env) -> Address {
+    soroban_sdk::contractimport!(file = "../contracts/soroban_liquidity_pool_plane_contract.wasm");
+    Client::new(e, &e.register(WASM, ())).address
+}
+
+pub(crate) fn create_reward_boost_feed_contract<'a>(
+    e: &Env,
+    admin: &Address,
+) -> reward_boost_feed::Client<'a> {
+    reward_boost_feed::Client::new(
+        e,
+        &e.register(
+            reward_boost_feed::WASM,
+            reward_boost_feed::Args::__constructor(admin, admin, admin),
+        ),
+    )
+}
+
+// Setup with the router and a standard pool accumulating protocol fees for the collector
+pub(crate) struct PipelineSetup<'a> {
+    pub(crate) env: Env,
+
+    pub(crate) admin: Address,
+    pub(crate) operations_admin: Address,
+    pub(crate) collector: FeesCollectorClient<'a>,
+    pub(crate) router: swap_router::Client<'a>,
+    pub(crate) pool: liquidity_pool::Client<'a>,
+    pub(crate) pool_index: BytesN<32>,
+    pub(crate) token_a: SorobanTokenClient<'a>,
+    pub(crate) token_a_admin_client: SorobanTokenAdminClient<'a>,
+    pub(crate) token_b: SorobanTokenClient<'a>,
+    pub(crate) token_b_admin_client: SorobanTokenAdminClient<'a>,
+}
+
+impl Default for PipelineSetup<'_> {
+    fn default() -> Self {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let admin = Address::generate(&e);
+        let operations_admin = Address::generate(&e);
+        let collector = create_contract(&e);
+        collector.init_admin(&admin);
+        collector.set_privileged_addrs(&admin, &operations_admin);
+
+        let mut token_a = create_token_contract(&e, &admin);
+        let mut token_b = create_token_contract(&e, &admin);
+        if token_b.address < token_a.address {
+            std::mem::swap(&mut token_a, &mut token_b);
+        }
+        let token_a_admin_client = get_token_admin_client(&e, &token_a.address);
+        let token_b_admin_client = get_token_admin_client(&e, &token_b.address);
+
+        let router = swap_router::Client::new(&e, &e.register(swap_router::WASM, ()));
+        router.init_admin(&admin);
+        router.init_config_storage(&admin, &deploy_config_storage(&e, &admin, &admin).address);
+        router.set_privileged_addrs(
+            &admin,
+            &admin,
+            &admin,
+            &admin,
+            &Vec::from_array(&e, [admin.clone()]),
+            &collector.address,
+        );
+        router.set_pool_hash(&admin, &install_liq_pool_hash(&e));
+        router.set_token_hash(&admin, &install_token_wasm(&e));
+        router.set_reward_token(&admin, &token_a.address);
+        router.set_pools_plane(&admin, &deploy_plane_contract(&e));
+        router.configure_init_pool_payment(
+            &admin,
+            &token_a.address,
+            &10_0000000,
+            &1_0000000,
+            &1_0000000,
+            &router.address,
+        );
+        let boost_feed = create_reward_boost_feed_contract(&e, &admin);
+        router.set_reward_boost_config(&admin, &token_a.address, &boost_feed.address);
+        router.set_protocol_fee_fraction(&admin, &5000);
+
+        token_a_admin_client.mint(&admin, &10_0000000);
+        let tokens = Vec::from_array(&e, [token_a.address.clone(), token_b.address.clone()]);
+        let (pool_index, pool_address) = router.init_standard_pool(&admin, &tokens, &30);
+        let pool = liquidity_pool::Client::new(&e, &pool_address);
+        token_a_admin_client.mint(&admin, &1_000_000_0000000);
+        token_b_admin_client.mint(&admin, &1_000_000_0000000);
+        pool.deposit(
+            &admin,
+            &Vec::from_array(&e, [1_000_000_0000000, 1_000_000_0000000]),
+            &1,
+        );
+
+        collector.set_router(&admin, &router.address);
+        collector.set_target_token(&admin, &token_b.address);
+
+        PipelineSetup {
+            env: e,
+            admin,
+            operations_admin,
+            collector,
+            router,
+            pool,
+            pool_index,
+            token_a,
+            token_a_admin_client,
+            token_b,
+            token_b_admin_client,
+        }
+    }
+}
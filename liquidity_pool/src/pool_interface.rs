@@ -1,3 +1,6 @@
+use rewards::storage::{
+    BoostConfig, BoostDelegation, PoolRewardConfig, RewardClaimRecord, RewardEpochSnapshot,
+};
 use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Val, Vec};
 
 pub trait LiquidityPoolCrunch {
@@ -188,6 +191,43 @@ pub trait RewardsTrait {
         reward_boost_feed: Address,
     );
 
+    // Commit new boost parameters: working balance = min(b + (max_boost - 1) * S * l / L,
+    // max_boost * b), max_boost up to 3x. Applicable after the admin actions delay.
+    fn commit_boost_config(e: Env, admin: Address, max_boost_bps: u32);
+
+    // Apply the committed boost parameters
+    fn apply_boost_config(e: Env, admin: Address);
+
+    // Discard the committed boost parameters
+    fn revert_boost_config(e: Env, admin: Address);
+
+    // Get the boost parameters in use
+    fn get_boost_config(e: Env) -> BoostConfig;
+
+    // Get the committed boost parameters waiting for the timelock, if any
+    fn get_future_boost_config(e: Env) -> Option<BoostConfig>;
+
+    // Use the boost feed lock records for boost balances. Enable once locks are backfilled.
+    fn set_boost_lock_records(e: Env, admin: Address, enabled: bool);
+
+    // Whether boost balances come from the boost feed lock records
+    fn get_boost_lock_records(e: Env) -> bool;
+
+    // Delegate `amount_bps` of the owner's boost to the delegatee until `expires_at`.
+    // amount_bps = 0 revokes it. Working balances of both accounts are checkpointed.
+    fn delegate_boost(e: Env, owner: Address, delegatee: Address, amount_bps: u32, expires_at: u64);
+
+    // Get active delegations of the owner's boost keyed by delegatee
+    fn get_boost_delegations(e: Env, owner: Address) -> Map<Address, BoostDelegation>;
+
+    // Get owners with an active boost delegation to the delegatee
+    fn get_boost_delegators(e: Env, delegatee: Address) -> Vec<Address>;
+
+    // Recalculate the user's working balance with the current boost, checkpointing rewards
+    // and gauges. Boost delegators and delegatees of the user are checkpointed too.
+    // Permissionless. Returns the new working balance of the user.
+    fn update_boost(e: Env, user: Address) -> u128;
+
     // Configure rewards for pool. Every second tps of coins
     // being distributed across all liquidity providers
     // after expired_at timestamp distribution ends
@@ -240,6 +280,75 @@ pub trait RewardsTrait {
     // returns amount of tokens rewarded to the user
     fn claim(e: Env, user: Address) -> u128;
 
+    // Claim rewards of the user to the recipient. The caller must be the user
+    // or the delegated claimer and has to authorize the call. The caller is an explicit
+    // argument since a claim routed through the router has the router as invoker.
+    // returns amount of tokens rewarded
+    fn claim_to(e: Env, caller: Address, user: Address, recipient: Address) -> u128;
+
+    // Delegate claim_to and gauges_claim_to of the user's rewards to the claimer. None revokes it.
+    // The user keeps the ability to claim on its own.
+    fn set_reward_claimer(e: Env, user: Address, claimer: Option<Address>);
+
+    // Get the delegated claimer of the user's rewards
+    fn get_reward_claimer(e: Env, user: Address) -> Option<Address>;
+
+    // Configure vesting of claimed rewards: nothing is releasable for `cliff` seconds after a claim,
+    // the amount unlocks linearly over `duration`. Exiting early forfeits `early_exit_penalty_bps`
+    // of the locked amount to the other LPs. duration = 0 pays rewards out on claim again.
+    fn set_rewards_vesting(
+        e: Env,
+        admin: Address,
+        cliff: u64,
+        duration: u64,
+        early_exit_penalty_bps: u32,
+    );
+
+    // Transfer the vested part of the user's claimed rewards.
+    // returns amount of tokens transferred
+    fn claim_vested(e: Env, user: Address) -> u128;
+
+    // Transfer the whole vesting balance, minus the early exit penalty on the locked part.
+    // returns amount of tokens transferred and the penalty
+    fn exit_vesting(e: Env, user: Address) -> (u128, u128);
+
+    // Turn recording of reward history on or off: a pool reward snapshot per reward config
+    // change and a record per user claim. Already recorded history is kept.
+    fn set_rewards_history(e: Env, admin: Address, enabled: bool);
+
+    // Get pool reward snapshots recorded on reward config changes, oldest first.
+    // At most 50 records are returned per call.
+    fn get_reward_snapshots(e: Env, offset: u32, limit: u32) -> Vec<RewardEpochSnapshot>;
+
+    // Get reward claims of the user, oldest first. At most 50 records are returned per call.
+    fn get_user_reward_claims(
+        e: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<RewardClaimRecord>;
+
+    // Add a reward token accounted separately from the primary one, up to 3 extra tokens.
+    // It yields nothing until configured with set_token_rewards_config.
+    fn add_reward_token(e: Env, admin: Address, token: Address);
+
+    // Get reward tokens of the pool, the primary one first.
+    fn get_reward_tokens(e: Env) -> Vec<Address>;
+
+    // Configure rewards of any of the pool reward tokens. The pool should hold enough tokens
+    // to pay the configured rewards.
+    fn set_token_rewards_config(e: Env, admin: Address, token: Address, expired_at: u64, tps: u128);
+
+    // Get rewards config of any of the pool reward tokens.
+    fn get_token_rewards_config(e: Env, token: Address) -> PoolRewardConfig;
+
+    // Get amounts of every reward token available for the user to claim.
+    fn get_user_rewards(e: Env, user: Address) -> Map<Address, u128>;
+
+    // Claim rewards in every reward token as a user.
+    // returns amounts of tokens rewarded to the user
+    fn claim_rewards(e: Env, user: Address) -> Map<Address, u128>;
+
     // Get rewards state
     fn get_rewards_state(e: Env, user: Address) -> bool;
 
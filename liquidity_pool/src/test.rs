@@ -10,7 +10,10 @@ use crate::testutils::{
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use core::cmp::min;
 use liquidity_pool_config_storage::testutils::deploy_config_storage;
-use rewards::storage::{PoolRewardsStorageTrait, UserRewardsStorageTrait};
+use rewards::storage::{
+    BoostConfig, BoostDelegation, PoolRewardsStorageTrait, RewardClaimRecord, RewardEpochSnapshot,
+    UserRewardsStorageTrait, VestingTranche,
+};
 use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
 use soroban_sdk::token::{
     StellarAssetClient as SorobanTokenAdminClient, TokenClient as SorobanTokenClient,
@@ -19,7 +22,9 @@ use soroban_sdk::{
     symbol_short, testutils::Address as _, vec, Address, Env, Error, IntoVal, Map, Symbol, Val, Vec,
 };
 use token_share::Client as ShareTokenClient;
-use utils::test_utils::{assert_approx_eq_abs, install_dummy_wasm, jump, jump_sequence};
+use utils::test_utils::{
+    assert_approx_eq_abs, count_events, install_dummy_wasm, jump, jump_sequence,
+};
 
 #[test]
 fn test() {
@@ -1053,6 +1058,65 @@ fn test_gauge_checkpoint_recalculated_working_balance_on_claim() {
     assert_eq!(user1_gauge_reward, 300);
 }
 
+#[test]
+fn test_update_boost() {
+    let setup = Setup::new_with_config(&TestConfig {
+        users_count: 2,
+        ..TestConfig::default()
+    });
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+    let token_reward = setup.token_reward;
+    let users = setup.users;
+
+    let gauge = deploy_rewards_gauge(&env, &liq_pool.address, &token_reward.address);
+    liq_pool.gauge_add(&setup.admin, &gauge.address);
+
+    for user in users.iter() {
+        liq_pool.deposit(user, &Vec::from_array(&env, [100, 100]), &0);
+    }
+
+    let gauge_distributor = Address::generate(&env);
+    get_token_admin_client(&env, &token_reward.address).mint(&gauge_distributor, &3000);
+    liq_pool.gauge_schedule_reward(
+        &setup.router,
+        &gauge_distributor,
+        &gauge.address,
+        &None,
+        &100,
+        &30,
+    );
+
+    jump(&env, 10);
+
+    // user locks tokens, anyone syncs the boost
+    get_token_admin_client(&env, &setup.reward_boost_token.address).mint(&users[0], &1);
+    setup
+        .reward_boost_feed
+        .set_total_supply(&setup.operations_admin, &3);
+    let working_balance = liq_pool.update_boost(&users[0]);
+    assert_eq!(working_balance, 199);
+    assert!(env.auths().is_empty());
+
+    jump(&env, 10);
+
+    // first 10 seconds shared equally, next 10 seconds - by working balance 199 vs 100
+    assert_eq!(
+        liq_pool
+            .gauges_claim(&users[0])
+            .get(token_reward.address.clone())
+            .unwrap(),
+        150 + 300 * 199 / 299
+    );
+    assert_eq!(
+        liq_pool
+            .gauges_claim(&users[1])
+            .get(token_reward.address.clone())
+            .unwrap(),
+        150 + 300 * 100 / 299
+    );
+}
+
 #[test]
 fn test_lazy_user_rewards() {
     let setup = Setup::default();
@@ -3353,6 +3417,40 @@ fn test_remove_gauge_twice() {
         .gauge_remove(&setup.admin, &gauge_reward_token.address);
 }
 
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_gauge_cancel_reward_not_router() {
+    let setup = Setup::setup(&TestConfig::default());
+    let user = Address::generate(&setup.env);
+    let gauge_reward_token = create_token_contract(&setup.env, &setup.admin);
+    let gauge = deploy_rewards_gauge(
+        &setup.env,
+        &setup.liq_pool.address,
+        &gauge_reward_token.address,
+    );
+    setup.liq_pool.gauge_add(&setup.admin, &gauge.address);
+    setup
+        .liq_pool
+        .gauge_cancel_reward(&setup.admin, &user, &gauge.address, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_gauge_extend_reward_not_router() {
+    let setup = Setup::setup(&TestConfig::default());
+    let user = Address::generate(&setup.env);
+    let gauge_reward_token = create_token_contract(&setup.env, &setup.admin);
+    let gauge = deploy_rewards_gauge(
+        &setup.env,
+        &setup.liq_pool.address,
+        &gauge_reward_token.address,
+    );
+    setup.liq_pool.gauge_add(&setup.admin, &gauge.address);
+    setup
+        .liq_pool
+        .gauge_extend_reward(&setup.admin, &user, &gauge.address, &0, &100, &0);
+}
+
 #[test]
 fn test_gauges_kill_claim() {
     let setup = Setup::setup(&TestConfig::default());
@@ -3413,6 +3511,79 @@ fn test_gauges_kill_claim_third_user() {
         .kill_gauges_claim(&Address::generate(&setup.env));
 }
 
+#[test]
+fn test_pause_gauge() {
+    let setup = Setup::new_with_config(&TestConfig {
+        users_count: 1,
+        ..TestConfig::default()
+    });
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[0].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&env, [100, 100]), &0);
+
+    let gauge_distributor = Address::generate(&env);
+    let gauge_reward_tokens = [
+        create_token_contract(&env, &setup.admin),
+        create_token_contract(&env, &setup.admin),
+    ];
+    for token in gauge_reward_tokens.iter() {
+        let gauge = deploy_rewards_gauge(&env, &liq_pool.address, &token.address);
+        liq_pool.gauge_add(&setup.admin, &gauge.address);
+        get_token_admin_client(&env, &token.address).mint(&gauge_distributor, &1000);
+        liq_pool.gauge_schedule_reward(
+            &setup.router,
+            &gauge_distributor,
+            &gauge.address,
+            &None,
+            &100,
+            &10,
+        );
+    }
+    let [paused_token, active_token] = gauge_reward_tokens;
+
+    jump(&env, 10);
+    liq_pool.pause_gauge(&setup.pause_admin, &paused_token.address);
+    assert_eq!(
+        liq_pool.get_paused_gauges(),
+        Vec::from_array(&env, [paused_token.address.clone()])
+    );
+
+    // paused gauge is skipped, the other one is claimed
+    assert_eq!(
+        liq_pool.gauges_claim(&user),
+        Map::from_array(&env, [(active_token.address.clone(), 100)])
+    );
+    assert_eq!(
+        count_events(&env, &liq_pool.address, "rewards_gauge_claim_skipped"),
+        1
+    );
+    assert_eq!(paused_token.balance(&user), 0);
+
+    // rewards keep accruing while paused
+    jump(&env, 10);
+    liq_pool.unpause_gauge(&setup.pause_admin, &paused_token.address);
+    assert_eq!(liq_pool.get_paused_gauges(), Vec::new(&env));
+    assert_eq!(
+        liq_pool.gauges_claim(&user),
+        Map::from_array(
+            &env,
+            [
+                (paused_token.address.clone(), 200),
+                (active_token.address.clone(), 100),
+            ]
+        )
+    );
+
+    // not paused gauge can't be unpaused
+    assert_eq!(
+        liq_pool
+            .try_unpause_gauge(&setup.pause_admin, &paused_token.address)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(405))
+    );
+}
+
 #[test]
 fn test_gauges_unkill_claim() {
     let setup = Setup::setup(&TestConfig::default());
@@ -4136,3 +4307,783 @@ fn test_estimate_working_balance_lower_share_value_with_boost() {
             .unwrap() as u128,
     );
 }
+
+#[test]
+fn test_boost_config() {
+    let setup = Setup::new_with_config(&TestConfig {
+        users_count: 2,
+        ..TestConfig::default()
+    });
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+    let [user1, user2] = [setup.users[0].clone(), setup.users[1].clone()];
+
+    liq_pool.deposit(&user1, &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(&user2, &Vec::from_array(&env, [20, 20]), &0);
+
+    // user2 holds half of the locked supply, so its working balance is capped by the max boost
+    let boost_admin = get_token_admin_client(&env, &setup.reward_boost_token.address);
+    boost_admin.mint(&user2, &10_000_0000000);
+    setup
+        .reward_boost_feed
+        .set_total_supply(&setup.operations_admin, &20_000_0000000);
+    let user1_shares = liq_pool.get_user_shares(&user1);
+    let user2_shares = liq_pool.get_user_shares(&user2);
+    let total_shares = liq_pool.get_total_shares();
+
+    assert_eq!(
+        liq_pool.get_boost_config(),
+        BoostConfig {
+            max_boost_bps: 25_000
+        }
+    );
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user2, &user2_shares).0,
+        user2_shares * 5 / 2
+    );
+    // shares without a lock are not boosted
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user1, &user1_shares).0,
+        user1_shares
+    );
+
+    assert_eq!(
+        liq_pool
+            .try_commit_boost_config(&setup.admin, &9_999)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(704))
+    );
+    assert_eq!(
+        liq_pool
+            .try_commit_boost_config(&setup.admin, &30_001)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(704))
+    );
+
+    liq_pool.commit_boost_config(&setup.admin, &30_000);
+    assert_eq!(
+        liq_pool
+            .try_commit_boost_config(&setup.admin, &15_000)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(2906))
+    );
+    assert_eq!(
+        liq_pool.try_apply_boost_config(&setup.admin).unwrap_err(),
+        Ok(Error::from_contract_error(2908))
+    );
+    assert_eq!(
+        liq_pool.get_future_boost_config(),
+        Some(BoostConfig {
+            max_boost_bps: 30_000
+        })
+    );
+    jump(&env, ADMIN_ACTIONS_DELAY + 1);
+    liq_pool.apply_boost_config(&setup.admin);
+    assert_eq!(liq_pool.get_future_boost_config(), None);
+    assert_eq!(liq_pool.get_boost_config().max_boost_bps, 30_000);
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user2, &user2_shares).0,
+        user2_shares * 3
+    );
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user1, &user1_shares).0,
+        user1_shares
+    );
+
+    // user1 holds 5% of the locked supply, each locked share adds up to (max_boost - 1)
+    boost_admin.mint(&user1, &1_000_0000000);
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user1, &user1_shares).0,
+        user1_shares + total_shares / 20 * 2
+    );
+
+    // working balance is updated on the next checkpoint
+    liq_pool.deposit(&user2, &Vec::from_array(&env, [1, 1]), &0);
+    let user2_shares = liq_pool.get_user_shares(&user2);
+    let total_shares = liq_pool.get_total_shares();
+    assert_eq!(
+        liq_pool
+            .get_rewards_info(&user2)
+            .get(Symbol::new(&env, "working_balance"))
+            .unwrap() as u128,
+        user2_shares * 3
+    );
+
+    // reverted parameters are never applied
+    liq_pool.commit_boost_config(&setup.admin, &15_000);
+    liq_pool.revert_boost_config(&setup.admin);
+    assert_eq!(liq_pool.get_future_boost_config(), None);
+    assert_eq!(
+        liq_pool.try_apply_boost_config(&setup.admin).unwrap_err(),
+        Ok(Error::from_contract_error(2907))
+    );
+
+    liq_pool.commit_boost_config(&setup.admin, &15_000);
+    jump(&env, ADMIN_ACTIONS_DELAY + 1);
+    liq_pool.apply_boost_config(&setup.admin);
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user2, &user2_shares).0,
+        user2_shares * 3 / 2
+    );
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user1, &user1_shares).0,
+        user1_shares + total_shares / 20 / 2
+    );
+}
+
+#[test]
+fn test_boost_lock_records_flag() {
+    let setup = Setup::new_with_config(&TestConfig {
+        users_count: 2,
+        ..TestConfig::default()
+    });
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+    let [user1, user2] = [setup.users[0].clone(), setup.users[1].clone()];
+
+    liq_pool.deposit(&user1, &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(&user2, &Vec::from_array(&env, [20, 20]), &0);
+
+    let boost_admin = get_token_admin_client(&env, &setup.reward_boost_token.address);
+    boost_admin.mint(&user2, &10_000_0000000);
+    setup
+        .reward_boost_feed
+        .set_total_supply(&setup.operations_admin, &20_000_0000000);
+    let user2_shares = liq_pool.get_user_shares(&user2);
+    assert!(!liq_pool.get_boost_lock_records());
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user2, &user2_shares).0,
+        user2_shares * 5 / 2
+    );
+
+    // once enabled, the feed is queried for lock records directly
+    liq_pool.set_boost_lock_records(&setup.admin, &true);
+    assert!(liq_pool.get_boost_lock_records());
+    assert!(liq_pool
+        .try_estimate_working_balance(&user2, &user2_shares)
+        .is_err());
+
+    liq_pool.set_boost_lock_records(&setup.admin, &false);
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user2, &user2_shares).0,
+        user2_shares * 5 / 2
+    );
+}
+
+#[test]
+fn test_boost_delegation() {
+    let setup = Setup::new_with_config(&TestConfig {
+        users_count: 2,
+        ..TestConfig::default()
+    });
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+    let [user1, user2] = [setup.users[0].clone(), setup.users[1].clone()];
+    // cold wallet with locked tokens and no liquidity
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    liq_pool.deposit(&user1, &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(&user2, &Vec::from_array(&env, [20, 20]), &0);
+    let user2_shares = liq_pool.get_user_shares(&user2);
+
+    let boost_admin = get_token_admin_client(&env, &setup.reward_boost_token.address);
+    boost_admin.mint(&owner, &10_000_0000000);
+    setup
+        .reward_boost_feed
+        .set_total_supply(&setup.operations_admin, &20_000_0000000);
+
+    let get_info = |user: &Address, key: &str| -> i128 {
+        liq_pool
+            .get_rewards_info(user)
+            .get(Symbol::new(&env, key))
+            .unwrap()
+    };
+    assert_eq!(get_info(&user2, "working_balance"), user2_shares as i128);
+
+    let expires_at = env.ledger().timestamp() + 100;
+    liq_pool.delegate_boost(&owner, &user2, &5000, &expires_at);
+    assert_eq!(env.auths()[0].0, owner);
+    assert_eq!(
+        liq_pool.get_boost_delegations(&owner),
+        Map::from_array(
+            &env,
+            [(
+                user2.clone(),
+                BoostDelegation {
+                    amount_bps: 5000,
+                    expires_at,
+                }
+            )]
+        )
+    );
+    assert_eq!(
+        liq_pool.get_boost_delegators(&user2),
+        Vec::from_array(&env, [owner.clone()])
+    );
+    assert_eq!(get_info(&owner, "boost_balance"), 5_000_0000000);
+    assert_eq!(get_info(&user2, "boost_balance"), 5_000_0000000);
+    // working balance is checkpointed by the delegation itself
+    assert_eq!(
+        get_info(&user2, "working_balance"),
+        (user2_shares * 5 / 2) as i128
+    );
+
+    // delegated part can't exceed the owner's boost
+    assert_eq!(
+        liq_pool
+            .try_delegate_boost(&owner, &other, &5001, &expires_at)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(705))
+    );
+    assert_eq!(
+        liq_pool
+            .try_delegate_boost(&owner, &owner, &1000, &expires_at)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(705))
+    );
+    assert_eq!(
+        liq_pool
+            .try_delegate_boost(&owner, &other, &1000, &env.ledger().timestamp())
+            .unwrap_err(),
+        Ok(Error::from_contract_error(705))
+    );
+    // the delegation to the same account is replaced
+    liq_pool.delegate_boost(&owner, &user2, &10000, &expires_at);
+    assert_eq!(get_info(&owner, "boost_balance"), 0);
+    assert_eq!(get_info(&user2, "boost_balance"), 10_000_0000000);
+
+    // expired delegation is ignored
+    jump(&env, 100);
+    assert_eq!(liq_pool.get_boost_delegations(&owner).len(), 0);
+    assert_eq!(liq_pool.get_boost_delegators(&user2).len(), 0);
+    assert_eq!(get_info(&owner, "boost_balance"), 10_000_0000000);
+    assert_eq!(get_info(&user2, "boost_balance"), 0);
+
+    // revoke
+    let expires_at = env.ledger().timestamp() + 100;
+    liq_pool.delegate_boost(&owner, &user2, &2500, &expires_at);
+    assert_eq!(get_info(&user2, "boost_balance"), 2_500_0000000);
+    liq_pool.delegate_boost(&owner, &user2, &0, &0);
+    assert_eq!(get_info(&user2, "boost_balance"), 0);
+    assert_eq!(get_info(&user2, "working_balance"), user2_shares as i128);
+}
+
+#[test]
+fn test_update_boost_after_delegation_expires() {
+    let setup = Setup::new_with_config(&TestConfig {
+        users_count: 2,
+        ..TestConfig::default()
+    });
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+    let [user1, user2] = [setup.users[0].clone(), setup.users[1].clone()];
+    let owner = Address::generate(&env);
+
+    liq_pool.deposit(&user1, &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(&user2, &Vec::from_array(&env, [20, 20]), &0);
+    let user2_shares = liq_pool.get_user_shares(&user2);
+
+    let boost_admin = get_token_admin_client(&env, &setup.reward_boost_token.address);
+    boost_admin.mint(&owner, &10_000_0000000);
+    setup
+        .reward_boost_feed
+        .set_total_supply(&setup.operations_admin, &20_000_0000000);
+
+    let get_working_balance = |user: &Address| -> u128 {
+        liq_pool
+            .get_rewards_info(user)
+            .get(Symbol::new(&env, "working_balance"))
+            .unwrap() as u128
+    };
+
+    liq_pool.delegate_boost(&owner, &user2, &5000, &(env.ledger().timestamp() + 100));
+    assert_eq!(get_working_balance(&user2), user2_shares * 5 / 2);
+
+    // expired delegation is still part of the checkpointed working balance
+    jump(&env, 100);
+    assert_eq!(get_working_balance(&user2), user2_shares * 5 / 2);
+
+    // updating the owner's boost checkpoints the delegatee as well
+    liq_pool.update_boost(&owner);
+    assert_eq!(get_working_balance(&user2), user2_shares);
+}
+
+#[test]
+fn test_rewards_vesting() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let [user1, user2] = [setup.users[0].clone(), setup.users[1].clone()];
+    let token_reward = setup.token_reward;
+
+    liq_pool.set_rewards_vesting(&setup.admin, &10, &100, &5000);
+    let amounts = Vec::from_array(&e, [100_0000000, 100_0000000]);
+    liq_pool.deposit(&user1, &amounts, &0);
+    liq_pool.deposit(&user2, &amounts, &0);
+    jump(&e, 60);
+
+    // claimed reward goes to vesting instead of the user
+    let reward1 = liq_pool.claim(&user1);
+    assert!(reward1 > 0);
+    assert_eq!(token_reward.balance(&user1), 0);
+    let info = liq_pool.get_rewards_info(&user1);
+    assert_eq!(
+        info.get(Symbol::new(&e, "vesting_locked")).unwrap(),
+        reward1 as i128
+    );
+    assert_eq!(info.get(Symbol::new(&e, "vesting_claimable")).unwrap(), 0);
+    assert_eq!(info.get(Symbol::new(&e, "vesting_duration")).unwrap(), 100);
+
+    // nothing before the cliff
+    jump(&e, 5);
+    assert_eq!(liq_pool.claim_vested(&user1), 0);
+
+    // linear unlock after the cliff
+    jump(&e, 45);
+    assert_eq!(liq_pool.claim_vested(&user1), reward1 / 2);
+    assert_eq!(token_reward.balance(&user1) as u128, reward1 / 2);
+
+    // early exit: half of the locked amount is distributed to the other LP
+    let unused_before = liq_pool.get_unused_reward();
+    let reward2_before = liq_pool.get_user_reward(&user2);
+    let locked = reward1 - reward1 / 2;
+    assert_eq!(
+        liq_pool.exit_vesting(&user1),
+        (locked - locked / 2, locked / 2)
+    );
+    assert_eq!(
+        token_reward.balance(&user1) as u128,
+        reward1 / 2 + locked - locked / 2
+    );
+    assert_approx_eq_abs(
+        liq_pool.get_user_reward(&user2),
+        reward2_before + locked / 2,
+        1,
+    );
+    assert_eq!(liq_pool.get_user_reward(&user1), 0);
+    assert_eq!(liq_pool.get_unused_reward(), unused_before);
+    let info = liq_pool.get_rewards_info(&user1);
+    assert_eq!(info.get(Symbol::new(&e, "vesting_locked")).unwrap(), 0);
+
+    // disabled vesting pays out on claim again
+    liq_pool.set_rewards_vesting(&setup.admin, &0, &0, &0);
+    let reward2 = liq_pool.claim(&user2);
+    assert_eq!(token_reward.balance(&user2) as u128, reward2);
+}
+
+#[test]
+fn test_rewards_vesting_claims_vest_separately() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user1 = setup.users[0].clone();
+    let token_reward = setup.token_reward;
+
+    liq_pool.set_rewards_vesting(&setup.admin, &10, &100, &5000);
+    let amounts = Vec::from_array(&e, [100_0000000, 100_0000000]);
+    liq_pool.deposit(&user1, &amounts, &0);
+    jump(&e, 30);
+    let start1 = e.ledger().timestamp();
+    let reward1 = liq_pool.claim(&user1);
+
+    // second claim does not restart the first one
+    jump(&e, 30);
+    let start2 = e.ledger().timestamp();
+    let reward2 = liq_pool.claim(&user1);
+    assert!(reward2 > 0);
+    let info = liq_pool.get_rewards_info(&user1);
+    assert_eq!(
+        info.get(Symbol::new(&e, "vesting_claimable")).unwrap(),
+        (reward1 * 30 / 100) as i128
+    );
+    assert_eq!(
+        info.get(Symbol::new(&e, "vesting_locked")).unwrap(),
+        (reward1 - reward1 * 30 / 100 + reward2) as i128
+    );
+    assert_eq!(
+        info.get(Symbol::new(&e, "vesting_end")).unwrap(),
+        (start2 + 100) as i128
+    );
+
+    // first claim is fully vested on its own schedule, the second one is not yet
+    jump(&e, start1 + 100 - start2);
+    assert_eq!(liq_pool.claim_vested(&user1), reward1 + reward2 * 70 / 100);
+    assert_eq!(
+        token_reward.balance(&user1) as u128,
+        reward1 + reward2 * 70 / 100
+    );
+
+    // early exit penalizes only the still locked part of the second claim
+    let locked = reward2 - reward2 * 70 / 100;
+    assert_eq!(
+        liq_pool.exit_vesting(&user1),
+        (locked - locked / 2, locked / 2)
+    );
+}
+
+#[test]
+fn test_rewards_vesting_tranches_bounded() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user1 = setup.users[0].clone();
+
+    liq_pool.set_rewards_vesting(&setup.admin, &10, &1000, &5000);
+    let amounts = Vec::from_array(&e, [100_0000000, 100_0000000]);
+    liq_pool.deposit(&user1, &amounts, &0);
+
+    // a claim every ledger doesn't grow the schedule beyond the limit
+    let mut claimed = 0;
+    for _ in 0..40 {
+        jump(&e, 1);
+        claimed += liq_pool.claim(&user1);
+    }
+    let schedule = e
+        .as_contract(&liq_pool.address, || {
+            get_rewards_manager(&e).manager().get_user_vesting(&user1)
+        })
+        .unwrap();
+    assert_eq!(schedule.tranches.len(), 16);
+    assert_eq!(schedule.total + schedule.unlocked, claimed);
+
+    // folded claims vest with the last tranche
+    jump(&e, 1000);
+    assert_eq!(liq_pool.claim_vested(&user1), claimed);
+}
+
+#[test]
+fn test_rewards_vesting_third_party_claims() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user1 = setup.users[0].clone();
+    let get_schedule = || {
+        e.as_contract(&liq_pool.address, || {
+            get_rewards_manager(&e).manager().get_user_vesting(&user1)
+        })
+        .unwrap()
+    };
+    let tranche_end = |tranche: VestingTranche| tranche.start + tranche.config.duration;
+
+    liq_pool.set_rewards_vesting(&setup.admin, &10, &1000, &5000);
+    let amounts = Vec::from_array(&e, [100_0000000, 100_0000000]);
+    liq_pool.deposit(&user1, &amounts, &0);
+
+    // claims need no user auth, so anyone may claim for the user every ledger
+    jump(&e, 1);
+    liq_pool.claim(&user1);
+    let first_end = tranche_end(get_schedule().tranches.first().unwrap());
+    for _ in 0..16 {
+        jump(&e, 1);
+        liq_pool.claim(&user1);
+    }
+    let last_end = tranche_end(get_schedule().tranches.last().unwrap());
+    for _ in 0..20 {
+        jump(&e, 1);
+        liq_pool.claim(&user1);
+    }
+
+    // the first tranche unlocks on time and the last one isn't restarted by the folded claims
+    let schedule = get_schedule();
+    assert_eq!(schedule.tranches.len(), 16);
+    assert_eq!(tranche_end(schedule.tranches.first().unwrap()), first_end);
+    let folded_end = tranche_end(schedule.tranches.last().unwrap());
+    assert!(folded_end >= last_end);
+    assert!(folded_end < e.ledger().timestamp() + 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #703)")]
+fn test_rewards_vesting_cliff_after_duration() {
+    let setup = Setup::default();
+    setup
+        .liq_pool
+        .set_rewards_vesting(&setup.admin, &200, &100, &0);
+}
+
+#[test]
+fn test_rewards_history() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let [user1, user2] = [setup.users[0].clone(), setup.users[1].clone()];
+    let recipient = Address::generate(&e);
+
+    liq_pool.set_rewards_history(&setup.admin, &true);
+    let amounts = Vec::from_array(&e, [100_0000000, 100_0000000]);
+    liq_pool.deposit(&user1, &amounts, &0);
+    liq_pool.deposit(&user2, &amounts, &0);
+    jump(&e, 60);
+    let now = e.ledger().timestamp();
+
+    let reward1 = liq_pool.claim(&user1);
+    let reward2 = liq_pool.claim_to(&user2, &user2, &recipient);
+    // empty claims are not recorded
+    assert_eq!(liq_pool.claim(&user1), 0);
+
+    assert_eq!(
+        liq_pool.get_user_reward_claims(&user1, &0, &10),
+        Vec::from_array(
+            &e,
+            [RewardClaimRecord {
+                timestamp: now,
+                amount: reward1,
+                recipient: user1.clone(),
+            }]
+        )
+    );
+    assert_eq!(
+        liq_pool.get_user_reward_claims(&user2, &0, &10),
+        Vec::from_array(
+            &e,
+            [RewardClaimRecord {
+                timestamp: now,
+                amount: reward2,
+                recipient: recipient.clone(),
+            }]
+        )
+    );
+    assert_eq!(liq_pool.get_reward_snapshots(&0, &10), Vec::new(&e));
+
+    // reward config changes are snapshotted
+    liq_pool.set_rewards_config(&user1, &(now + 100), &1_0000000);
+    jump(&e, 100);
+    liq_pool.set_rewards_config(&user1, &(now + 200), &2_0000000);
+    let snapshots = liq_pool.get_reward_snapshots(&0, &10);
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(
+        snapshots.get_unchecked(0),
+        RewardEpochSnapshot {
+            timestamp: now,
+            accumulated: 630_0000000,
+            claimed: reward1 + reward2,
+            tps: 1_0000000,
+            expired_at: now + 100,
+        }
+    );
+    assert_eq!(
+        snapshots.get_unchecked(1),
+        RewardEpochSnapshot {
+            timestamp: now + 100,
+            accumulated: 730_0000000,
+            claimed: reward1 + reward2,
+            tps: 2_0000000,
+            expired_at: now + 200,
+        }
+    );
+
+    // pagination
+    assert_eq!(
+        liq_pool.get_reward_snapshots(&1, &10),
+        Vec::from_array(&e, [snapshots.get_unchecked(1)])
+    );
+    assert_eq!(liq_pool.get_reward_snapshots(&0, &1).len(), 1);
+    assert_eq!(liq_pool.get_reward_snapshots(&2, &10), Vec::new(&e));
+
+    // recording stops once disabled, the history is kept
+    liq_pool.set_rewards_history(&setup.admin, &false);
+    assert!(liq_pool.claim(&user1) > 0);
+    assert_eq!(liq_pool.get_user_reward_claims(&user1, &0, &10).len(), 1);
+    assert_eq!(liq_pool.get_reward_snapshots(&0, &10).len(), 2);
+}
+
+#[test]
+fn test_multiple_reward_tokens() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let [user1, user2] = [setup.users[0].clone(), setup.users[1].clone()];
+    let token_reward = setup.token_reward;
+    let token_extra = create_token_contract(&e, &setup.admin);
+
+    // both users hold shares before the token is added
+    let amounts = Vec::from_array(&e, [100_0000000, 100_0000000]);
+    liq_pool.deposit(&user1, &amounts, &0);
+    liq_pool.deposit(&user2, &amounts, &0);
+
+    liq_pool.add_reward_token(&setup.admin, &token_extra.address);
+    assert_eq!(
+        liq_pool.get_reward_tokens(),
+        Vec::from_array(
+            &e,
+            [token_reward.address.clone(), token_extra.address.clone()]
+        )
+    );
+    get_token_admin_client(&e, &token_extra.address).mint(&liq_pool.address, &100_0000000);
+    let now = e.ledger().timestamp();
+    liq_pool.set_token_rewards_config(&setup.admin, &token_extra.address, &(now + 100), &1_0000000);
+    let config = liq_pool.get_token_rewards_config(&token_extra.address);
+    assert_eq!((config.expired_at, config.tps), (now + 100, 1_0000000));
+    jump(&e, 50);
+
+    // every token is claimed at once
+    let primary_reward = liq_pool.get_user_reward(&user1);
+    let rewards = liq_pool.get_user_rewards(&user1);
+    assert_eq!(
+        rewards.get(token_reward.address.clone()),
+        Some(primary_reward)
+    );
+    assert_eq!(rewards.get(token_extra.address.clone()), Some(25_0000000));
+    assert_eq!(liq_pool.claim_rewards(&user1), rewards);
+    assert_eq!(token_reward.balance(&user1) as u128, primary_reward);
+    assert_eq!(token_extra.balance(&user1), 25_0000000);
+
+    // the primary token claim leaves extra rewards in place
+    let primary_reward = liq_pool.claim(&user2);
+    assert!(primary_reward > 0);
+    assert_eq!(token_extra.balance(&user2), 0);
+    jump(&e, 100);
+    assert_eq!(
+        liq_pool
+            .get_user_rewards(&user2)
+            .get(token_extra.address.clone()),
+        Some(50_0000000)
+    );
+    liq_pool.claim_rewards(&user2);
+    assert_eq!(token_extra.balance(&user2), 50_0000000);
+    assert_eq!(
+        liq_pool
+            .claim_rewards(&user1)
+            .get(token_extra.address.clone()),
+        Some(25_0000000)
+    );
+    assert_eq!(token_extra.balance(&liq_pool.address), 0);
+}
+
+#[test]
+fn test_add_reward_token_limits() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let token_extra = Address::generate(&e);
+
+    liq_pool.add_reward_token(&setup.admin, &token_extra);
+    for token in [token_extra.clone(), setup.token_reward.address.clone()] {
+        assert_eq!(
+            liq_pool.try_add_reward_token(&setup.admin, &token),
+            Err(Ok(Error::from_contract_error(707)))
+        );
+    }
+    liq_pool.add_reward_token(&setup.admin, &Address::generate(&e));
+    liq_pool.add_reward_token(&setup.admin, &Address::generate(&e));
+    assert_eq!(
+        liq_pool.try_add_reward_token(&setup.admin, &Address::generate(&e)),
+        Err(Ok(Error::from_contract_error(708)))
+    );
+    assert_eq!(
+        liq_pool.try_set_token_rewards_config(
+            &setup.admin,
+            &Address::generate(&e),
+            &(e.ledger().timestamp() + 100),
+            &1_0000000,
+        ),
+        Err(Ok(Error::from_contract_error(709)))
+    );
+}
+
+#[test]
+fn test_claim_to_with_delegated_claimer() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let [user1, user2] = [setup.users[0].clone(), setup.users[1].clone()];
+    let token_reward = setup.token_reward;
+    let claimer = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let amounts = Vec::from_array(&e, [100_0000000, 100_0000000]);
+    liq_pool.deposit(&user1, &amounts, &0);
+    liq_pool.deposit(&user2, &amounts, &0);
+    jump(&e, 60);
+
+    // without delegation the user authorizes
+    let reward1 = liq_pool.get_user_reward(&user1);
+    assert_eq!(liq_pool.claim_to(&user1, &user1, &recipient), reward1);
+    assert_eq!(e.auths()[0].0, user1);
+    assert_eq!(token_reward.balance(&recipient) as u128, reward1);
+    assert_eq!(token_reward.balance(&user1), 0);
+
+    liq_pool.set_reward_claimer(&user2, &Some(claimer.clone()));
+    assert_eq!(liq_pool.get_reward_claimer(&user2), Some(claimer.clone()));
+
+    let reward2 = liq_pool.get_user_reward(&user2);
+    assert_eq!(liq_pool.claim_to(&claimer, &user2, &recipient), reward2);
+    assert_eq!(e.auths()[0].0, claimer);
+    assert_eq!(token_reward.balance(&recipient) as u128, reward1 + reward2);
+    assert_eq!(liq_pool.get_user_reward(&user2), 0);
+
+    assert_eq!(
+        liq_pool.gauges_claim_to(&claimer, &user2, &recipient),
+        Map::new(&e)
+    );
+    assert_eq!(e.auths()[0].0, claimer);
+
+    // neither the user nor the claimer
+    assert_eq!(
+        liq_pool.try_claim_to(&Address::generate(&e), &user2, &recipient),
+        Err(Ok(Error::from_contract_error(102)))
+    );
+
+    liq_pool.set_reward_claimer(&user2, &None);
+    assert_eq!(liq_pool.get_reward_claimer(&user2), None);
+}
+
+#[test]
+fn test_owner_claims_with_delegated_claimer() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[0].clone();
+    let token_reward = setup.token_reward;
+    let claimer = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    liq_pool.set_reward_claimer(&user, &Some(claimer.clone()));
+    jump(&e, 60);
+
+    // delegation doesn't take claims away from the owner
+    let reward = liq_pool.get_user_reward(&user);
+    assert!(reward > 0);
+    assert_eq!(liq_pool.claim_to(&user, &user, &recipient), reward);
+    assert_eq!(e.auths()[0].0, user);
+    assert_eq!(token_reward.balance(&recipient) as u128, reward);
+
+    assert_eq!(
+        liq_pool.gauges_claim_to(&user, &user, &recipient),
+        Map::new(&e)
+    );
+    assert_eq!(e.auths()[0].0, user);
+
+    // and the owner can revoke it
+    liq_pool.set_reward_claimer(&user, &None);
+    assert_eq!(
+        liq_pool.try_claim_to(&claimer, &user, &recipient),
+        Err(Ok(Error::from_contract_error(102)))
+    );
+}
@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use crate::testutils::Setup;
+use crate::testutils::{create_token_contract, deploy_rewards_gauge, Setup};
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{symbol_short, Address, Symbol, Vec};
@@ -547,6 +547,106 @@ fn test_set_rewards_config() {
     }
 }
 
+#[test]
+fn test_set_rewards_vesting() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.rewards_admin, true),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(
+            pool.try_set_rewards_vesting(&addr, &10, &100, &5000)
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_set_rewards_history() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.rewards_admin, true),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(pool.try_set_rewards_history(&addr, &true).is_ok(), is_ok);
+    }
+}
+
+#[test]
+fn test_reward_tokens_permissions() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.rewards_admin, true),
+        (setup.operations_admin.clone(), false),
+        (setup.pause_admin.clone(), false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        let token = Address::generate(&setup.env);
+        assert_eq!(pool.try_add_reward_token(&addr, &token).is_ok(), is_ok);
+        if is_ok {
+            assert!(pool
+                .try_set_token_rewards_config(
+                    &addr,
+                    &token,
+                    &setup.env.ledger().timestamp().saturating_add(10),
+                    &1
+                )
+                .is_ok());
+        }
+    }
+    let token = pool.get_reward_tokens().get_unchecked(1);
+    for addr in [setup.operations_admin, setup.pause_admin] {
+        assert!(pool
+            .try_set_token_rewards_config(
+                &addr,
+                &token,
+                &setup.env.ledger().timestamp().saturating_add(20),
+                &1
+            )
+            .is_err());
+    }
+}
+
+#[test]
+fn test_boost_config_permissions() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+        (setup.admin, true),
+    ] {
+        assert_eq!(pool.try_commit_boost_config(&addr, &30_000).is_ok(), is_ok);
+        assert_eq!(pool.try_revert_boost_config(&addr).is_ok(), is_ok);
+        assert_eq!(pool.try_set_boost_lock_records(&addr, &true).is_ok(), is_ok);
+    }
+}
+
 #[test]
 fn test_set_protocol_fee() {
     let setup = Setup::default();
@@ -615,3 +715,38 @@ fn test_admin_set_rewards_state_permissions() {
         );
     }
 }
+
+#[test]
+fn test_pause_gauge_permissions() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+    let gauge_reward_token = create_token_contract(&setup.env, &setup.admin);
+    let gauge = deploy_rewards_gauge(&setup.env, &pool.address, &gauge_reward_token.address);
+    pool.gauge_add(&setup.admin, &gauge.address);
+
+    for (addr, can_pause, can_unpause) in [
+        (user, false, false),
+        (setup.rewards_admin, false, false),
+        (setup.operations_admin, false, false),
+        (setup.emergency_admin, false, false),
+        (setup.pause_admin, true, true),
+        (setup.emergency_pause_admin, true, false),
+        (setup.admin.clone(), true, true),
+    ] {
+        assert_eq!(
+            pool.try_pause_gauge(&addr, &gauge_reward_token.address)
+                .is_ok(),
+            can_pause
+        );
+        pool.pause_gauge(&setup.admin, &gauge_reward_token.address);
+        assert_eq!(
+            pool.try_unpause_gauge(&addr, &gauge_reward_token.address)
+                .is_ok(),
+            can_unpause
+        );
+        if !can_unpause {
+            pool.unpause_gauge(&setup.admin, &gauge_reward_token.address);
+        }
+    }
+}
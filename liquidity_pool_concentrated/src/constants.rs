@@ -6,6 +6,17 @@ pub const MAX_TICK: i32 = 887_272;
 pub const FEE_DENOMINATOR: u128 = 10_000;
 // Max positions per user account (prevents storage bloat from griefing).
 pub const MAX_USER_POSITIONS: u32 = 20;
+// Keeper bounty for compounding someone else's position fees, per FEE_DENOMINATOR.
+// Default 0.5%, capped at 5% so an opted-in owner can't be drained by a fee change.
+pub const DEFAULT_COMPOUND_BOUNTY_FRACTION: u32 = 50;
+pub const MAX_COMPOUND_BOUNTY_FRACTION: u32 = 500;
+// Max shortfall of the compounding swap output from its quote on top of the pool fee, per
+// FEE_DENOMINATOR; default 1%, max 10%. For keepers it also bounds how far the spot price may
+// drift from the ledger start price, which a keeper can't move within its own transaction.
+pub const DEFAULT_COMPOUND_MAX_SLIPPAGE: u32 = 100;
+pub const MAX_COMPOUND_MAX_SLIPPAGE: u32 = 1_000;
+// Max buckets returned by get_liquidity_distribution (bounds the view's read footprint).
+pub const MAX_DISTRIBUTION_POINTS: u32 = 200;
 // Number of ticks per chunk. Each chunk is stored as one Vec<TickData> entry.
 // Chunk addressing: chunk_pos = compressed_tick.div_euclid(TICKS_PER_CHUNK),
 //                   slot      = compressed_tick.rem_euclid(TICKS_PER_CHUNK).
@@ -125,6 +125,33 @@ impl AdminInterfaceTrait for ConcentratedLiquidityPool {
         PoolEvents::new(&e).set_protocol_fee_fraction(new_fraction);
     }
 
+    // Set the keeper bounty for compound_position_for, in parts per FEE_DENOMINATOR.
+    // Capped at MAX_COMPOUND_BOUNTY_FRACTION. Operations admin or owner only.
+    fn set_compound_bounty_fraction(e: Env, admin: Address, new_fraction: u32) {
+        admin.require_auth();
+        require_operations_admin_or_owner(&e, &admin);
+        if new_fraction > MAX_COMPOUND_BOUNTY_FRACTION {
+            panic_with_error!(&e, LiquidityPoolValidationError::FeeOutOfBounds);
+        }
+        set_compound_bounty_fraction(&e, &new_fraction);
+        SetCompoundBountyFraction {
+            fraction: new_fraction,
+        }
+        .publish(&e);
+    }
+
+    // Set the slippage bound of the swap made when compounding fees, in parts per FEE_DENOMINATOR
+    // on top of the pool fee. Capped at MAX_COMPOUND_MAX_SLIPPAGE. Operations admin or owner only.
+    fn set_compound_max_slippage(e: Env, admin: Address, max_slippage: u32) {
+        admin.require_auth();
+        require_operations_admin_or_owner(&e, &admin);
+        if max_slippage > MAX_COMPOUND_MAX_SLIPPAGE {
+            panic_with_error!(&e, Error::InvalidSlippage);
+        }
+        set_compound_max_slippage(&e, &max_slippage);
+        SetCompoundMaxSlippage { max_slippage }.publish(&e);
+    }
+
     // Returns [token0_fees, token1_fees] accumulated for the protocol.
     fn get_protocol_fees(e: Env) -> Vec<u128> {
         let fees = get_protocol_fees(&e);
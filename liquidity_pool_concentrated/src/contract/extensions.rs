@@ -5,6 +5,31 @@ use super::*;
 // For router-compatible full-range operations, see LiquidityPoolInterfaceTrait.
 #[contractimpl]
 impl ConcentratedPoolExtensionsTrait for ConcentratedLiquidityPool {
+    // Set the starting price of an empty pool, once. Lets the first deposits be one-sided
+    // ranges (e.g. a token launch seeding only the new asset above market price) instead of
+    // deriving the price from the first deposit's amount ratio.
+    // Router (on behalf of the pool creator) or admin only.
+    fn initialize_price(e: Env, admin: Address, sqrt_price_x96: U256) {
+        admin.require_auth();
+        if admin != get_router(&e) {
+            AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        }
+        if get_price_initialized(&e) || get_total_raw_liquidity(&e) > 0 {
+            panic_with_error!(&e, Error::PriceAlreadyInitialized);
+        }
+        if sqrt_price_x96 < min_sqrt_ratio(&e) || sqrt_price_x96 >= max_sqrt_ratio(&e) {
+            panic_with_error!(&e, Error::InvalidSqrtPrice);
+        }
+
+        let slot = Slot0 {
+            tick: tick_at_sqrt_ratio(&e, &sqrt_price_x96),
+            sqrt_price_x96,
+        };
+        set_slot0(&e, &slot);
+        set_price_initialized(&e, &true);
+        Self::emit_pool_state(&e, &slot, get_liquidity(&e));
+    }
+
     // Read-only preview for custom-range deposit.
     // Returns (actual_amounts, liquidity) exactly as deposit_position would produce.
     fn estimate_deposit_position(
@@ -25,9 +50,10 @@ impl ConcentratedPoolExtensionsTrait for ConcentratedLiquidityPool {
 
         Self::check_ticks_internal(&e, tick_lower, tick_upper);
 
-        // Match deposit_position behavior on empty pool: first deposit must provide both tokens.
+        // Match deposit_position behavior on empty pool: first deposit must provide both tokens
+        // unless the price was set with initialize_price.
         let mut slot = get_slot0(&e);
-        if get_total_raw_liquidity(&e) == 0 {
+        if get_total_raw_liquidity(&e) == 0 && !get_price_initialized(&e) {
             if desired_amount0 == 0 || desired_amount1 == 0 {
                 panic_with_error!(&e, LiquidityPoolValidationError::AllCoinsRequired);
             }
@@ -82,6 +108,7 @@ impl ConcentratedPoolExtensionsTrait for ConcentratedLiquidityPool {
     // Computes max liquidity, transfers desired amounts, refunds excess.
     // On an empty pool, auto-initializes price from sqrt(amount1/amount0);
     // both tokens required (AllCoinsRequired), derived tick must be in range.
+    // Skipped once the price was set with initialize_price: one-sided ranges are accepted.
     fn deposit_position(
         e: Env,
         sender: Address,
@@ -104,14 +131,10 @@ impl ConcentratedPoolExtensionsTrait for ConcentratedLiquidityPool {
         }
 
         Self::check_ticks_internal(&e, tick_lower, tick_upper);
-        let is_full_range = {
-            let (full_range_lower, full_range_upper) = Self::full_range_ticks(&e);
-            tick_lower == full_range_lower && tick_upper == full_range_upper
-        };
 
         // Auto-initialize price on empty pool from token amount ratio.
         // First deposit MUST provide both tokens to establish the initial price.
-        if get_total_raw_liquidity(&e) == 0 {
+        if get_total_raw_liquidity(&e) == 0 && !get_price_initialized(&e) {
             if desired_amount0 > 0 && desired_amount1 > 0 {
                 let (sqrt_price_x96, tick) = Self::init_sqrt_price_for_range(
                     &e,
@@ -202,26 +225,7 @@ impl ConcentratedPoolExtensionsTrait for ConcentratedLiquidityPool {
         set_reserve0(&e, &(get_reserve0(&e) + amount0));
         set_reserve1(&e, &(get_reserve1(&e) + amount1));
 
-        Self::update_tick_liquidity(&e, tick_lower, liquidity as i128, false);
-        Self::update_tick_liquidity(&e, tick_upper, liquidity as i128, true);
-
-        let mut position = Self::get_or_create_position(&e, &sender, tick_lower, tick_upper);
-        Self::accrue_position_fees(&e, &mut position, tick_lower, tick_upper, slot.tick);
-        position.liquidity = position.liquidity.saturating_add(liquidity);
-        set_position(&e, &sender, tick_lower, tick_upper, &position);
-        Self::ensure_user_range_exists(&e, &sender, tick_lower, tick_upper);
-
-        if slot.tick >= tick_lower && slot.tick < tick_upper {
-            set_liquidity(&e, &get_liquidity(&e).saturating_add(liquidity));
-        }
-        if is_full_range {
-            let full_range_liquidity = get_full_range_liquidity(&e);
-            let next_full_range_liquidity = match full_range_liquidity.checked_add(liquidity) {
-                Some(value) => value,
-                None => panic_with_error!(&e, Error::LiquidityOverflow),
-            };
-            set_full_range_liquidity(&e, &next_full_range_liquidity);
-        }
+        Self::mint_position_liquidity(&e, &sender, tick_lower, tick_upper, liquidity);
 
         Self::update_user_raw_liquidity(&e, &sender, liquidity as i128);
         Self::recompute_user_weighted_liquidity(&e, &sender);
@@ -313,10 +317,6 @@ impl ConcentratedPoolExtensionsTrait for ConcentratedLiquidityPool {
         }
 
         Self::check_ticks_internal(&e, tick_lower, tick_upper);
-        let is_full_range = {
-            let (full_range_lower, full_range_upper) = Self::full_range_ticks(&e);
-            tick_lower == full_range_lower && tick_upper == full_range_upper
-        };
 
         Self::recompute_user_weighted_liquidity(&e, &owner);
         Self::rewards_checkpoint_user(&e, &owner);
@@ -378,34 +378,9 @@ impl ConcentratedPoolExtensionsTrait for ConcentratedLiquidityPool {
             panic_with_error!(&e, LiquidityPoolValidationError::InsufficientBalance);
         }
 
-        position.liquidity -= amount;
         position.tokens_owed_0 = 0;
         position.tokens_owed_1 = 0;
-
-        if position.liquidity == 0 {
-            remove_position(&e, &owner, tick_lower, tick_upper);
-            Self::remove_user_range_if_empty(&e, &owner, tick_lower, tick_upper);
-        } else {
-            set_position(&e, &owner, tick_lower, tick_upper, &position);
-        }
-
-        Self::update_tick_liquidity(&e, tick_lower, -(amount as i128), false);
-        Self::update_tick_liquidity(&e, tick_upper, -(amount as i128), true);
-
-        if slot.tick >= tick_lower && slot.tick < tick_upper {
-            let active = get_liquidity(&e);
-            if active < amount {
-                panic_with_error!(&e, Error::LiquidityUnderflow);
-            }
-            set_liquidity(&e, &(active - amount));
-        }
-        if is_full_range {
-            let full_range_liquidity = get_full_range_liquidity(&e);
-            if full_range_liquidity < amount {
-                panic_with_error!(&e, Error::LiquidityUnderflow);
-            }
-            set_full_range_liquidity(&e, &(full_range_liquidity - amount));
-        }
+        Self::burn_position_liquidity(&e, &owner, tick_lower, tick_upper, &mut position, amount);
 
         Self::update_user_raw_liquidity(&e, &owner, -(amount as i128));
         Self::recompute_user_weighted_liquidity(&e, &owner);
@@ -457,6 +432,268 @@ impl ConcentratedPoolExtensionsTrait for ConcentratedLiquidityPool {
         Vec::from_array(&e, [total_amount0, total_amount1])
     }
 
+    // Move a position to a new range in one call: withdraws all liquidity of
+    // [old_tick_lower, old_tick_upper) together with accrued fees (compounded, not paid out),
+    // swaps inside the pool to the token ratio the new range needs at the current price and
+    // deposits the result into [new_tick_lower, new_tick_upper). Tokens that don't fit the new
+    // range are refunded. `max_slippage` (bps, swap fee included) bounds the internal swap
+    // output against the spot-price quote. Rewards are checkpointed once for the whole move.
+    // Returns (deposited amounts, new liquidity).
+    fn rebalance_position(
+        e: Env,
+        owner: Address,
+        old_tick_lower: i32,
+        old_tick_upper: i32,
+        new_tick_lower: i32,
+        new_tick_upper: i32,
+        max_slippage: u32,
+    ) -> (Vec<u128>, u128) {
+        owner.require_auth();
+        if get_is_killed_deposit(&e) {
+            panic_with_error!(&e, Error::DepositKilled);
+        }
+        if max_slippage as u128 > FEE_DENOMINATOR {
+            panic_with_error!(&e, Error::InvalidSlippage);
+        }
+        Self::check_ticks_internal(&e, old_tick_lower, old_tick_upper);
+        Self::check_ticks_internal(&e, new_tick_lower, new_tick_upper);
+        if old_tick_lower == new_tick_lower && old_tick_upper == new_tick_upper {
+            panic_with_error!(&e, Error::InvalidTickRange);
+        }
+
+        Self::recompute_user_weighted_liquidity(&e, &owner);
+        Self::rewards_checkpoint_user(&e, &owner);
+
+        let mut position = match get_position(&e, &owner, old_tick_lower, old_tick_upper) {
+            Some(pos) => pos,
+            None => panic_with_error!(&e, Error::PositionNotFound),
+        };
+        let slot = get_slot0(&e);
+        Self::accrue_position_fees(&e, &mut position, old_tick_lower, old_tick_upper, slot.tick);
+
+        let old_liquidity = position.liquidity;
+        if old_liquidity == 0 {
+            panic_with_error!(&e, Error::InsufficientLiquidity);
+        }
+        let (principal0, principal1) = Self::amounts_for_liquidity(
+            &e,
+            &slot,
+            old_tick_lower,
+            old_tick_upper,
+            old_liquidity,
+            false,
+        );
+        let fees0 = position.tokens_owed_0;
+        let fees1 = position.tokens_owed_1;
+        let withdrawn0 = match principal0.checked_add(fees0) {
+            Some(v) => v,
+            None => panic_with_error!(&e, Error::InvalidAmount),
+        };
+        let withdrawn1 = match principal1.checked_add(fees1) {
+            Some(v) => v,
+            None => panic_with_error!(&e, Error::InvalidAmount),
+        };
+
+        position.tokens_owed_0 = 0;
+        position.tokens_owed_1 = 0;
+        Self::burn_position_liquidity(
+            &e,
+            &owner,
+            old_tick_lower,
+            old_tick_upper,
+            &mut position,
+            old_liquidity,
+        );
+
+        // Withdrawn tokens leave LP reserves; the deposit below adds back what is re-invested.
+        let reserve0 = get_reserve0(&e);
+        let reserve1 = get_reserve1(&e);
+        if reserve0 < withdrawn0 || reserve1 < withdrawn1 {
+            panic_with_error!(&e, LiquidityPoolValidationError::InsufficientBalance);
+        }
+        set_reserve0(&e, &(reserve0 - withdrawn0));
+        set_reserve1(&e, &(reserve1 - withdrawn1));
+
+        let mut amount0 = withdrawn0;
+        let mut amount1 = withdrawn1;
+        let (zero_for_one, swap_amount, expected_out) = Self::ratio_swap_for_range(
+            &e,
+            &get_slot0(&e),
+            new_tick_lower,
+            new_tick_upper,
+            amount0,
+            amount1,
+        );
+        if swap_amount > 0 && expected_out > 0 {
+            if get_is_killed_swap(&e) {
+                panic_with_error!(&e, Error::SwapKilled);
+            }
+            let (swapped_in, swapped_out) =
+                Self::swap_in_place(&e, &owner, zero_for_one, swap_amount);
+            let min_out = mul_div_u128(
+                &e,
+                expected_out,
+                FEE_DENOMINATOR - max_slippage as u128,
+                FEE_DENOMINATOR,
+                false,
+            );
+            if swapped_out < min_out {
+                panic_with_error!(&e, LiquidityPoolValidationError::OutMinNotSatisfied);
+            }
+            if zero_for_one {
+                amount0 -= swapped_in;
+                amount1 += swapped_out;
+            } else {
+                amount1 -= swapped_in;
+                amount0 += swapped_out;
+            }
+        }
+
+        let liquidity =
+            Self::max_liquidity_for_amounts(&e, new_tick_lower, new_tick_upper, amount0, amount1);
+        if liquidity > i128::MAX as u128 {
+            panic_with_error!(&e, Error::LiquidityAmountTooLarge);
+        }
+        if liquidity == 0 {
+            panic_with_error!(&e, LiquidityPoolValidationError::OutMinNotSatisfied);
+        }
+        let slot = get_slot0(&e);
+        let (deposit0, deposit1) =
+            Self::amounts_for_liquidity(&e, &slot, new_tick_lower, new_tick_upper, liquidity, true);
+        let deposit0 = deposit0.min(amount0);
+        let deposit1 = deposit1.min(amount1);
+
+        Self::mint_position_liquidity(&e, &owner, new_tick_lower, new_tick_upper, liquidity);
+        let reserve0 = get_reserve0(&e) + deposit0;
+        let reserve1 = get_reserve1(&e) + deposit1;
+        set_reserve0(&e, &reserve0);
+        set_reserve1(&e, &reserve1);
+
+        Self::update_user_raw_liquidity(&e, &owner, liquidity as i128 - old_liquidity as i128);
+        Self::recompute_user_weighted_liquidity(&e, &owner);
+        Self::rewards_refresh_working_balance(&e, &owner);
+
+        let token0 = get_token0(&e);
+        let token1 = get_token1(&e);
+        let contract = e.current_contract_address();
+        let refund0 = amount0 - deposit0;
+        let refund1 = amount1 - deposit1;
+        if refund0 > 0 {
+            SorobanTokenClient::new(&e, &token0).transfer(&contract, &owner, &(refund0 as i128));
+        }
+        if refund1 > 0 {
+            SorobanTokenClient::new(&e, &token1).transfer(&contract, &owner, &(refund1 as i128));
+        }
+        update_plane(&e);
+
+        if fees0 > 0 || fees1 > 0 {
+            ClaimFees {
+                owner: owner.clone(),
+                token0: token0.clone(),
+                token1: token1.clone(),
+                amount0: fees0 as i128,
+                amount1: fees1 as i128,
+            }
+            .publish(&e);
+        }
+        let tokens = Vec::from_array(&e, [token0, token1]);
+        let events = PoolEvents::new(&e);
+        events.withdraw_liquidity(
+            tokens.clone(),
+            Vec::from_array(&e, [withdrawn0, withdrawn1]),
+            old_liquidity,
+        );
+        events.deposit_liquidity(tokens, Vec::from_array(&e, [deposit0, deposit1]), liquidity);
+        events.update_reserves(Vec::from_array(&e, [reserve0, reserve1]));
+        Self::emit_position_update(
+            &e,
+            &owner,
+            old_tick_lower,
+            old_tick_upper,
+            -(old_liquidity as i128),
+        );
+        Self::emit_position_update(
+            &e,
+            &owner,
+            new_tick_lower,
+            new_tick_upper,
+            liquidity as i128,
+        );
+        Self::emit_pool_state(&e, &slot, get_liquidity(&e));
+
+        (Vec::from_array(&e, [deposit0, deposit1]), liquidity)
+    }
+
+    // Re-invest the position's accrued fees into the same range: swaps the excess side inside
+    // the pool and mints the result as liquidity. Leftovers that don't fit stay owed on the
+    // position. Panics with OutMinNotSatisfied below `min_liquidity` or when the swap output
+    // falls short of the spot-price quote by more than the pool fee and get_compound_max_slippage.
+    // Returns (deposited amounts, liquidity added).
+    fn compound_position(
+        e: Env,
+        owner: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        min_liquidity: u128,
+    ) -> (Vec<u128>, u128) {
+        owner.require_auth();
+        Self::compound_position_internal(
+            &e,
+            &owner,
+            &owner,
+            tick_lower,
+            tick_upper,
+            min_liquidity,
+            0,
+        )
+    }
+
+    // Keeper variant of compound_position for owners that enabled auto-compounding.
+    // The keeper receives get_compound_bounty_fraction of the fees as a bounty. The keeper
+    // picks no minimum: the swap is quoted at the price the pool had when the ledger started,
+    // and the call panics with CompoundPriceMoved once the spot price has drifted from it by
+    // more than get_compound_max_slippage.
+    fn compound_position_for(
+        e: Env,
+        keeper: Address,
+        owner: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+    ) -> (Vec<u128>, u128) {
+        keeper.require_auth();
+        if !get_auto_compound(&e, &owner) {
+            panic_with_error!(&e, Error::AutoCompoundDisabled);
+        }
+        Self::compound_position_internal(
+            &e,
+            &keeper,
+            &owner,
+            tick_lower,
+            tick_upper,
+            0,
+            get_compound_bounty_fraction(&e),
+        )
+    }
+
+    // Opt in or out of permissionless compounding by keepers.
+    fn set_auto_compound(e: Env, owner: Address, enabled: bool) {
+        owner.require_auth();
+        set_auto_compound(&e, &owner, enabled);
+        SetAutoCompound { owner, enabled }.publish(&e);
+    }
+
+    fn get_auto_compound(e: Env, owner: Address) -> bool {
+        get_auto_compound(&e, &owner)
+    }
+
+    fn get_compound_bounty_fraction(e: Env) -> u32 {
+        get_compound_bounty_fraction(&e)
+    }
+
+    fn get_compound_max_slippage(e: Env) -> u32 {
+        get_compound_max_slippage(&e)
+    }
+
     // Read-only preview for currently claimable swap fees on a single position.
     // Returns current tokens_owed values after fee accrual at current tick.
     fn get_position_fees(e: Env, owner: Address, tick_lower: i32, tick_upper: i32) -> Vec<u128> {
@@ -695,4 +932,118 @@ impl ConcentratedPoolExtensionsTrait for ConcentratedLiquidityPool {
     fn get_tick_bounds(e: Env) -> (i32, i32) {
         (get_min_init_tick(&e), get_max_init_tick(&e))
     }
+
+    // Liquidity histogram for frontends. Splits [tick_from, tick_to) into at most `max_points`
+    // equal-width buckets (width rounded up to tick spacing) and walks the 3-level bitmap once.
+    // Each bucket is (start_tick, active_liquidity at start_tick, amount0, amount1), where the
+    // amounts are the tokens locked inside the bucket at the current price.
+    fn get_liquidity_distribution(
+        e: Env,
+        tick_from: i32,
+        tick_to: i32,
+        max_points: u32,
+    ) -> Vec<(i32, u128, u128, u128)> {
+        Self::check_ticks_internal(&e, tick_from, tick_to);
+        if max_points == 0 {
+            panic_with_error!(&e, LiquidityPoolValidationError::ZeroAmount);
+        }
+
+        let spacing = get_tick_spacing(&e);
+        let steps = ((tick_to - tick_from) / spacing) as u32;
+        let points = max_points.min(MAX_DISTRIBUTION_POINTS).min(steps);
+        let width = steps.div_ceil(points) as i32 * spacing;
+
+        let slot = get_slot0(&e);
+        let mut cc = ChunkCache::new(&e);
+        let mut liquidity = Self::liquidity_at_tick(&e, tick_from, &mut cc);
+
+        let mut result = Vec::new(&e);
+        let mut bucket_start = tick_from;
+        let mut bucket_end = (tick_from + width).min(tick_to);
+        let mut bucket_liquidity = liquidity;
+        let mut bucket_amount0 = 0u128;
+        let mut bucket_amount1 = 0u128;
+        let mut segment_start = tick_from;
+
+        loop {
+            let (next_tick, initialized) =
+                Self::find_initialized_tick_in_word(&e, segment_start, spacing, false, &mut cc);
+            let segment_end = next_tick.min(tick_to);
+
+            // Liquidity is constant over [segment_start, segment_end); spread it over buckets.
+            while segment_start < segment_end {
+                let piece_end = segment_end.min(bucket_end);
+                if liquidity > 0 {
+                    let (amount0, amount1) = Self::amounts_for_liquidity(
+                        &e,
+                        &slot,
+                        segment_start,
+                        piece_end,
+                        liquidity,
+                        false,
+                    );
+                    bucket_amount0 = bucket_amount0.saturating_add(amount0);
+                    bucket_amount1 = bucket_amount1.saturating_add(amount1);
+                }
+                segment_start = piece_end;
+
+                if segment_start == bucket_end {
+                    result.push_back((
+                        bucket_start,
+                        bucket_liquidity,
+                        bucket_amount0,
+                        bucket_amount1,
+                    ));
+                    if bucket_end == tick_to {
+                        return result;
+                    }
+                    bucket_start = bucket_end;
+                    bucket_end = (bucket_end + width).min(tick_to);
+                    bucket_liquidity = liquidity;
+                    bucket_amount0 = 0;
+                    bucket_amount1 = 0;
+                }
+            }
+
+            if initialized {
+                let liquidity_net = cc.get_tick(&e, next_tick, spacing).liquidity_net;
+                liquidity = Self::apply_liquidity_net(&e, liquidity, liquidity_net);
+                if next_tick == bucket_start {
+                    bucket_liquidity = liquidity;
+                }
+            }
+        }
+    }
+
+    // Market depth: input needed to move the price by `bps` basis points from the current one.
+    // Returns [amount0_in, amount1_in]: token0 sold pushes the price down by `bps`,
+    // token1 sold pushes it up by `bps`. Amounts include the swap fee.
+    // If liquidity runs out before the target price, returns the input that exhausts it.
+    fn get_depth(e: Env, bps: u32) -> Vec<u128> {
+        if bps == 0 {
+            panic_with_error!(&e, LiquidityPoolValidationError::ZeroAmount);
+        }
+
+        let slot = get_slot0(&e);
+        let one = U256::from_u32(&e, 1);
+        let limit_down = sqrt_price_moved_by_bps(&e, &slot.sqrt_price_x96, bps, false)
+            .max(min_sqrt_ratio(&e).add(&one));
+        let limit_up = sqrt_price_moved_by_bps(&e, &slot.sqrt_price_x96, bps, true)
+            .min(max_sqrt_ratio(&e).sub(&one));
+
+        let amount0_in = if limit_down < slot.sqrt_price_x96 {
+            let (amount0, _) = Self::simulate_swap_amounts(&e, true, i128::MAX, limit_down);
+            amount0 as u128
+        } else {
+            0
+        };
+        let amount1_in = if limit_up > slot.sqrt_price_x96 {
+            let (_, amount1) = Self::simulate_swap_amounts(&e, false, i128::MAX, limit_up);
+            amount1 as u128
+        } else {
+            0
+        };
+
+        Vec::from_array(&e, [amount0_in, amount1_in])
+    }
 }
@@ -1,5 +1,6 @@
 use super::*;
 use crate::bitmap;
+use crate::u512::mul_div_floor;
 
 impl ConcentratedLiquidityPool {
     pub(super) fn has_admin_role(e: &Env) -> bool {
@@ -305,6 +306,60 @@ impl ConcentratedLiquidityPool {
         }
     }
 
+    // Apply a signed liquidity_net to active liquidity (positive = liquidity enters).
+    pub(super) fn apply_liquidity_net(e: &Env, liquidity: u128, liquidity_net: i128) -> u128 {
+        if liquidity_net >= 0 {
+            liquidity.saturating_add(liquidity_net as u128)
+        } else {
+            match liquidity.checked_sub(liquidity_net.unsigned_abs()) {
+                Some(v) => v,
+                None => panic_with_error!(e, Error::LiquidityUnderflow),
+            }
+        }
+    }
+
+    // Active liquidity at `target_tick`, derived from the current active liquidity by
+    // walking initialized ticks between slot0.tick and target_tick (read-only, no crossing).
+    // Downward: crossing tick k leaves [k, ..) so liquidity_net is subtracted.
+    // Upward: crossing tick k enters [k, ..) so liquidity_net is added.
+    pub(super) fn liquidity_at_tick(e: &Env, target_tick: i32, cc: &mut ChunkCache) -> u128 {
+        let spacing = get_tick_spacing(e);
+        let mut liquidity = get_liquidity(e);
+        let mut cursor = get_slot0(e).tick;
+
+        if target_tick < cursor {
+            let min_tick = get_min_init_tick(e);
+            while cursor > target_tick && cursor >= min_tick {
+                let (next_tick, initialized) =
+                    Self::find_initialized_tick_in_word(e, cursor, spacing, true, cc);
+                if next_tick <= target_tick {
+                    break;
+                }
+                if initialized {
+                    let liquidity_net = cc.get_tick(e, next_tick, spacing).liquidity_net;
+                    liquidity = Self::apply_liquidity_net(e, liquidity, -liquidity_net);
+                }
+                cursor = next_tick - 1;
+            }
+        } else {
+            let max_tick = get_max_init_tick(e);
+            while cursor < target_tick && cursor < max_tick {
+                let (next_tick, initialized) =
+                    Self::find_initialized_tick_in_word(e, cursor, spacing, false, cc);
+                if next_tick > target_tick {
+                    break;
+                }
+                if initialized {
+                    let liquidity_net = cc.get_tick(e, next_tick, spacing).liquidity_net;
+                    liquidity = Self::apply_liquidity_net(e, liquidity, liquidity_net);
+                }
+                cursor = next_tick;
+            }
+        }
+
+        liquidity
+    }
+
     pub(super) fn update_tick_liquidity(
         e: &Env,
         tick_idx: i32,
@@ -404,6 +459,85 @@ impl ConcentratedLiquidityPool {
         }
     }
 
+    pub(super) fn is_full_range(e: &Env, tick_lower: i32, tick_upper: i32) -> bool {
+        let (full_range_lower, full_range_upper) = Self::full_range_ticks(e);
+        tick_lower == full_range_lower && tick_upper == full_range_upper
+    }
+
+    // Add `liquidity` to the owner's [tick_lower, tick_upper) position: tick updates, fee accrual,
+    // position/range bookkeeping, active and full-range liquidity.
+    // Token transfers, reserves, user liquidity totals and rewards are left to the caller.
+    pub(super) fn mint_position_liquidity(
+        e: &Env,
+        owner: &Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity: u128,
+    ) {
+        let tick_current = get_slot0(e).tick;
+
+        Self::update_tick_liquidity(e, tick_lower, liquidity as i128, false);
+        Self::update_tick_liquidity(e, tick_upper, liquidity as i128, true);
+
+        let mut position = Self::get_or_create_position(e, owner, tick_lower, tick_upper);
+        Self::accrue_position_fees(e, &mut position, tick_lower, tick_upper, tick_current);
+        position.liquidity = position.liquidity.saturating_add(liquidity);
+        set_position(e, owner, tick_lower, tick_upper, &position);
+        Self::ensure_user_range_exists(e, owner, tick_lower, tick_upper);
+
+        if tick_current >= tick_lower && tick_current < tick_upper {
+            set_liquidity(e, &get_liquidity(e).saturating_add(liquidity));
+        }
+        if Self::is_full_range(e, tick_lower, tick_upper) {
+            let full_range_liquidity = get_full_range_liquidity(e);
+            let next_full_range_liquidity = match full_range_liquidity.checked_add(liquidity) {
+                Some(value) => value,
+                None => panic_with_error!(e, Error::LiquidityOverflow),
+            };
+            set_full_range_liquidity(e, &next_full_range_liquidity);
+        }
+    }
+
+    // Remove `amount` liquidity from an already accrued position and persist it
+    // (position and user range are dropped once liquidity reaches zero).
+    // Mirrors mint_position_liquidity; tokens_owed handling is left to the caller.
+    pub(super) fn burn_position_liquidity(
+        e: &Env,
+        owner: &Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        position: &mut PositionData,
+        amount: u128,
+    ) {
+        let tick_current = get_slot0(e).tick;
+
+        position.liquidity -= amount;
+        if position.liquidity == 0 {
+            remove_position(e, owner, tick_lower, tick_upper);
+            Self::remove_user_range_if_empty(e, owner, tick_lower, tick_upper);
+        } else {
+            set_position(e, owner, tick_lower, tick_upper, position);
+        }
+
+        Self::update_tick_liquidity(e, tick_lower, -(amount as i128), false);
+        Self::update_tick_liquidity(e, tick_upper, -(amount as i128), true);
+
+        if tick_current >= tick_lower && tick_current < tick_upper {
+            let active = get_liquidity(e);
+            if active < amount {
+                panic_with_error!(e, Error::LiquidityUnderflow);
+            }
+            set_liquidity(e, &(active - amount));
+        }
+        if Self::is_full_range(e, tick_lower, tick_upper) {
+            let full_range_liquidity = get_full_range_liquidity(e);
+            if full_range_liquidity < amount {
+                panic_with_error!(e, Error::LiquidityUnderflow);
+            }
+            set_full_range_liquidity(e, &(full_range_liquidity - amount));
+        }
+    }
+
     pub(super) fn ensure_user_range_exists(
         e: &Env,
         user: &Address,
@@ -458,7 +592,7 @@ impl ConcentratedLiquidityPool {
         target_liquidity: u128,
     ) -> u128 {
         let tick_current = get_slot0(e).tick;
-        let fee = get_fee(e);
+        let max_distance = get_rewards_max_distance(e);
         let state = get_user_state(e, user);
         let mut weighted = 0u128;
         let mut target_applied = false;
@@ -478,15 +612,23 @@ impl ConcentratedLiquidityPool {
             if liq == 0 {
                 continue;
             }
-            let multiplier =
-                position_multiplier_bps(tick_current, range.tick_lower, range.tick_upper, fee);
+            let multiplier = position_multiplier_bps(
+                tick_current,
+                range.tick_lower,
+                range.tick_upper,
+                max_distance,
+            );
             weighted = weighted.saturating_add(apply_multiplier(liq, multiplier));
         }
 
         // New position not yet in user's list
         if !target_applied && target_liquidity > 0 {
-            let multiplier =
-                position_multiplier_bps(tick_current, target_tick_lower, target_tick_upper, fee);
+            let multiplier = position_multiplier_bps(
+                tick_current,
+                target_tick_lower,
+                target_tick_upper,
+                max_distance,
+            );
             weighted = weighted.saturating_add(apply_multiplier(target_liquidity, multiplier));
         }
 
@@ -542,6 +684,68 @@ impl ConcentratedLiquidityPool {
         Rewards::new(e, 100)
     }
 
+    // Claims the user's reward to the recipient, checkpointing gauges around the claim.
+    // Callers check the kill switch and authorization.
+    pub(super) fn claim_reward(e: &Env, user: &Address, recipient: &Address) -> u128 {
+        Self::recompute_user_weighted_liquidity(e, user);
+
+        let total_weighted = get_total_weighted_liquidity(e);
+        let user_weighted = get_user_weighted_liquidity(e, user);
+
+        let rewards = Self::rewards_manager(e);
+        let mut manager = rewards.manager();
+        rewards_gauge::operations::checkpoint_user(
+            e,
+            user,
+            manager.get_working_balance(user, user_weighted),
+            manager.get_working_supply(total_weighted),
+        );
+        let reward = manager.claim_reward_to(user, recipient, total_weighted, user_weighted);
+
+        let reward_token = rewards.storage().get_reward_token();
+        Self::assert_reward_reserves(e, &reward_token);
+
+        if recipient == user {
+            RewardEvents::new(e).claim(user.clone(), reward_token, reward);
+        } else {
+            RewardEvents::new(e).claim_to(user.clone(), recipient.clone(), reward_token, reward);
+        }
+
+        let manager_after = rewards.manager();
+        rewards_gauge::operations::checkpoint_user(
+            e,
+            user,
+            manager_after.get_working_balance(user, user_weighted),
+            manager_after.get_working_supply(total_weighted),
+        );
+
+        reward
+    }
+
+    // Post-claim reserve validation: ensure the reward transfer did not drain
+    // below reserves + protocol fees. Stored reserves are independent of balance
+    // (updated only by deposit/withdraw/swap/collect), so this check is meaningful.
+    pub(super) fn assert_reward_reserves(e: &Env, reward_token: &Address) {
+        let token0 = get_token0(e);
+        let token1 = get_token1(e);
+        let contract = e.current_contract_address();
+        let protocol_fees = get_protocol_fees(e);
+
+        if *reward_token == token0 {
+            let balance = SorobanTokenClient::new(e, &token0).balance(&contract) as u128;
+            let reserve = get_reserve0(e);
+            if reserve + protocol_fees.token0 > balance {
+                panic_with_error!(e, LiquidityPoolValidationError::InsufficientBalance);
+            }
+        } else if *reward_token == token1 {
+            let balance = SorobanTokenClient::new(e, &token1).balance(&contract) as u128;
+            let reserve = get_reserve1(e);
+            if reserve + protocol_fees.token1 > balance {
+                panic_with_error!(e, LiquidityPoolValidationError::InsufficientBalance);
+            }
+        }
+    }
+
     pub(super) fn rewards_checkpoint_user(e: &Env, user: &Address) {
         let rewards = Self::rewards_manager(e);
         let total_weighted = get_total_weighted_liquidity(e);
@@ -557,6 +761,20 @@ impl ConcentratedLiquidityPool {
         manager.checkpoint_user(user, total_weighted, user_weighted);
     }
 
+    // Checkpoints the user with a recalculated working balance, then syncs gauges with it.
+    pub(super) fn rewards_checkpoint_working_balance(e: &Env, user: &Address) {
+        Self::rewards_checkpoint_user(e, user);
+
+        let manager = Self::rewards_manager(e).manager();
+        let user_weighted = get_user_weighted_liquidity(e, user);
+        rewards_gauge::operations::checkpoint_user(
+            e,
+            user,
+            manager.get_working_balance(user, user_weighted),
+            manager.get_working_supply(get_total_weighted_liquidity(e)),
+        );
+    }
+
     pub(super) fn rewards_refresh_working_balance(e: &Env, user: &Address) {
         let rewards = Self::rewards_manager(e);
         let total_weighted = get_total_weighted_liquidity(e);
@@ -1326,6 +1544,8 @@ impl ConcentratedLiquidityPool {
             cc.flush(e);
             set_protocol_fees(e, &protocol_fees);
             set_liquidity(e, &liquidity);
+            // recorded regardless of opt-ins, an owner may opt in after the price has moved
+            checkpoint_ledger_start_price(e, &get_slot0(e).sqrt_price_x96);
             set_slot0(e, &slot);
         }
 
@@ -1375,6 +1595,310 @@ impl ConcentratedLiquidityPool {
         )
     }
 
+    /// Swap tokens a position owner already holds inside the pool (no token transfers).
+    ///
+    /// Used when liquidity is moved between ranges: the pool state and reserves change exactly
+    /// as in `swap_internal`, while the input is taken from — and the output credited to —
+    /// amounts the caller tracks for the owner. Input left unswapped because liquidity ran out
+    /// stays with the caller (no surplus distribution). Returns (amount_in, amount_out).
+    pub(super) fn swap_in_place(
+        e: &Env,
+        owner: &Address,
+        zero_for_one: bool,
+        amount_in: u128,
+    ) -> (u128, u128) {
+        let (
+            amount_in_used,
+            amount_out,
+            total_fee_amount,
+            slot,
+            liquidity,
+            pf_delta_0,
+            pf_delta_1,
+            _,
+            _,
+            _,
+        ) = Self::swap_loop(
+            e,
+            zero_for_one,
+            amount_in as i128,
+            U256::from_u32(e, 0),
+            false,
+        );
+
+        let token0 = get_token0(e);
+        let token1 = get_token1(e);
+        let (token_in, token_out) = if zero_for_one {
+            (token0, token1)
+        } else {
+            (token1, token0)
+        };
+
+        let mut res0 = get_reserve0(e);
+        let mut res1 = get_reserve1(e);
+        if zero_for_one {
+            res0 += amount_in_used - pf_delta_0;
+            res1 = match res1.checked_sub(amount_out) {
+                Some(v) => v,
+                None => panic_with_error!(e, LiquidityPoolValidationError::InsufficientBalance),
+            };
+        } else {
+            res1 += amount_in_used - pf_delta_1;
+            res0 = match res0.checked_sub(amount_out) {
+                Some(v) => v,
+                None => panic_with_error!(e, LiquidityPoolValidationError::InsufficientBalance),
+            };
+        }
+        set_reserve0(e, &res0);
+        set_reserve1(e, &res1);
+
+        PoolEvents::new(e).trade(
+            owner.clone(),
+            token_in,
+            token_out,
+            amount_in_used,
+            amount_out,
+            total_fee_amount,
+        );
+        Self::emit_pool_state(e, &slot, liquidity);
+
+        (amount_in_used, amount_out)
+    }
+
+    /// Swap needed to bring (amount0, amount1) to the token ratio of [tick_lower, tick_upper)
+    /// at the current price.
+    ///
+    /// Values both sides in token1 at the spot price and splits the total by the range's
+    /// value weights: w1 = sqrtP - sqrtL, w0 = (sqrtU - sqrtP) * sqrtP / sqrtU.
+    /// Fees and price impact are ignored; whatever doesn't fit the range is refunded by the caller.
+    /// Returns (zero_for_one, amount_in, expected_out at spot price); amount_in == 0 → no swap.
+    pub(super) fn ratio_swap_for_range(
+        e: &Env,
+        slot: &Slot0,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount0: u128,
+        amount1: u128,
+    ) -> (bool, u128, u128) {
+        let q96 = U256::from_u32(e, 1).shl(96);
+        let sqrt_price = &slot.sqrt_price_x96;
+        let sqrt_lower = sqrt_ratio_at_tick(e, tick_lower);
+        let sqrt_upper = sqrt_ratio_at_tick(e, tick_upper);
+
+        let to_token1 = |amount: &U256| -> U256 {
+            mul_div_floor(
+                e,
+                &mul_div_floor(e, amount, sqrt_price, &q96),
+                sqrt_price,
+                &q96,
+            )
+        };
+        let to_token0 = |amount: &U256| -> U256 {
+            mul_div_floor(
+                e,
+                &mul_div_floor(e, amount, &q96, sqrt_price),
+                &q96,
+                sqrt_price,
+            )
+        };
+        let to_u128 = |value: U256| -> u128 { value.to_u128().unwrap_or(u128::MAX) };
+
+        let amount1_u256 = U256::from_u128(e, amount1);
+        let total_value = to_token1(&U256::from_u128(e, amount0)).add(&amount1_u256);
+        let target1 = if *sqrt_price <= sqrt_lower {
+            U256::from_u32(e, 0)
+        } else if *sqrt_price >= sqrt_upper {
+            total_value
+        } else {
+            let weight1 = sqrt_price.sub(&sqrt_lower);
+            let weight0 = mul_div_floor(e, &sqrt_upper.sub(sqrt_price), sqrt_price, &sqrt_upper);
+            mul_div_floor(e, &total_value, &weight1, &weight0.add(&weight1))
+        };
+
+        if amount1_u256 > target1 {
+            let amount_in = amount1_u256.sub(&target1);
+            let expected_out = to_u128(to_token0(&amount_in));
+            (false, to_u128(amount_in), expected_out)
+        } else {
+            let value_in = target1.sub(&amount1_u256);
+            let amount_in = to_u128(to_token0(&value_in)).min(amount0);
+            (true, amount_in, to_u128(value_in))
+        }
+    }
+
+    /// Re-invest a position's owed fees as liquidity in the same range.
+    ///
+    /// `bounty_fraction` (per FEE_DENOMINATOR) of each fee token is paid to `caller` first;
+    /// the rest is swapped inside the pool to the range's token ratio and minted. The swap output
+    /// may fall short of the quote by the pool fee plus get_compound_max_slippage.
+    /// The owner compounding its own position is quoted at the spot price. A keeper could move
+    /// the spot price inside its own transaction, so for `caller != owner` the quote uses the
+    /// ledger start price and the spot price must stay within get_compound_max_slippage of it.
+    /// Tokens that don't fit stay owed on the position, so nothing is refunded.
+    /// Auth is the caller's concern.
+    /// Returns (deposited amounts, liquidity added).
+    pub(super) fn compound_position_internal(
+        e: &Env,
+        caller: &Address,
+        owner: &Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        min_liquidity: u128,
+        bounty_fraction: u32,
+    ) -> (Vec<u128>, u128) {
+        if get_is_killed_deposit(e) {
+            panic_with_error!(e, Error::DepositKilled);
+        }
+        Self::check_ticks_internal(e, tick_lower, tick_upper);
+
+        Self::recompute_user_weighted_liquidity(e, owner);
+        Self::rewards_checkpoint_user(e, owner);
+
+        let mut position = match get_position(e, owner, tick_lower, tick_upper) {
+            Some(pos) => pos,
+            None => panic_with_error!(e, Error::PositionNotFound),
+        };
+        if position.liquidity == 0 {
+            panic_with_error!(e, Error::InsufficientLiquidity);
+        }
+        let slot = get_slot0(e);
+        let max_slippage = get_compound_max_slippage(e);
+        let quote_slot = if caller == owner {
+            slot.clone()
+        } else {
+            let reference = get_ledger_start_sqrt_price(e);
+            if slot.sqrt_price_x96 < sqrt_price_moved_by_bps(e, &reference, max_slippage, false)
+                || slot.sqrt_price_x96 > sqrt_price_moved_by_bps(e, &reference, max_slippage, true)
+            {
+                panic_with_error!(e, Error::CompoundPriceMoved);
+            }
+            Slot0 {
+                sqrt_price_x96: reference,
+                tick: slot.tick,
+            }
+        };
+        Self::accrue_position_fees(e, &mut position, tick_lower, tick_upper, slot.tick);
+
+        let fees0 = position.tokens_owed_0;
+        let fees1 = position.tokens_owed_1;
+        if fees0 == 0 && fees1 == 0 {
+            panic_with_error!(e, Error::NothingToCompound);
+        }
+        position.tokens_owed_0 = 0;
+        position.tokens_owed_1 = 0;
+        set_position(e, owner, tick_lower, tick_upper, &position);
+
+        // Owed fees leave LP reserves; whatever is minted or stays owed is added back below.
+        let reserve0 = get_reserve0(e);
+        let reserve1 = get_reserve1(e);
+        if reserve0 < fees0 || reserve1 < fees1 {
+            panic_with_error!(e, LiquidityPoolValidationError::InsufficientBalance);
+        }
+        set_reserve0(e, &(reserve0 - fees0));
+        set_reserve1(e, &(reserve1 - fees1));
+
+        let bounty0 = mul_div_u128(e, fees0, bounty_fraction as u128, FEE_DENOMINATOR, false);
+        let bounty1 = mul_div_u128(e, fees1, bounty_fraction as u128, FEE_DENOMINATOR, false);
+        let mut amount0 = fees0 - bounty0;
+        let mut amount1 = fees1 - bounty1;
+
+        let (zero_for_one, swap_amount, expected_out) =
+            Self::ratio_swap_for_range(e, &quote_slot, tick_lower, tick_upper, amount0, amount1);
+        if swap_amount > 0 && expected_out > 0 {
+            if get_is_killed_swap(e) {
+                panic_with_error!(e, Error::SwapKilled);
+            }
+            let (swapped_in, swapped_out) =
+                Self::swap_in_place(e, owner, zero_for_one, swap_amount);
+            let min_out = mul_div_u128(
+                e,
+                expected_out,
+                FEE_DENOMINATOR
+                    .saturating_sub(get_fee(e) as u128)
+                    .saturating_sub(max_slippage as u128),
+                FEE_DENOMINATOR,
+                false,
+            );
+            if swapped_out < min_out {
+                panic_with_error!(e, LiquidityPoolValidationError::OutMinNotSatisfied);
+            }
+            if zero_for_one {
+                amount0 -= swapped_in;
+                amount1 += swapped_out;
+            } else {
+                amount1 -= swapped_in;
+                amount0 += swapped_out;
+            }
+        }
+
+        let liquidity =
+            Self::max_liquidity_for_amounts(e, tick_lower, tick_upper, amount0, amount1);
+        if liquidity > i128::MAX as u128 {
+            panic_with_error!(e, Error::LiquidityAmountTooLarge);
+        }
+        if liquidity == 0 || liquidity < min_liquidity {
+            panic_with_error!(e, LiquidityPoolValidationError::OutMinNotSatisfied);
+        }
+        let slot = get_slot0(e);
+        let (deposit0, deposit1) =
+            Self::amounts_for_liquidity(e, &slot, tick_lower, tick_upper, liquidity, true);
+        let deposit0 = deposit0.min(amount0);
+        let deposit1 = deposit1.min(amount1);
+
+        Self::mint_position_liquidity(e, owner, tick_lower, tick_upper, liquidity);
+        let mut position = match get_position(e, owner, tick_lower, tick_upper) {
+            Some(pos) => pos,
+            None => panic_with_error!(e, Error::PositionNotFound),
+        };
+        position.tokens_owed_0 = position.tokens_owed_0.saturating_add(amount0 - deposit0);
+        position.tokens_owed_1 = position.tokens_owed_1.saturating_add(amount1 - deposit1);
+        set_position(e, owner, tick_lower, tick_upper, &position);
+
+        let reserve0 = get_reserve0(e) + amount0;
+        let reserve1 = get_reserve1(e) + amount1;
+        set_reserve0(e, &reserve0);
+        set_reserve1(e, &reserve1);
+
+        Self::update_user_raw_liquidity(e, owner, liquidity as i128);
+        Self::recompute_user_weighted_liquidity(e, owner);
+        Self::rewards_refresh_working_balance(e, owner);
+
+        let token0 = get_token0(e);
+        let token1 = get_token1(e);
+        let contract = e.current_contract_address();
+        if bounty0 > 0 {
+            SorobanTokenClient::new(e, &token0).transfer(&contract, caller, &(bounty0 as i128));
+        }
+        if bounty1 > 0 {
+            SorobanTokenClient::new(e, &token1).transfer(&contract, caller, &(bounty1 as i128));
+        }
+        update_plane(e);
+
+        let events = PoolEvents::new(e);
+        events.deposit_liquidity(
+            Vec::from_array(e, [token0, token1]),
+            Vec::from_array(e, [deposit0, deposit1]),
+            liquidity,
+        );
+        events.update_reserves(Vec::from_array(e, [reserve0, reserve1]));
+        CompoundFees {
+            owner: owner.clone(),
+            caller: caller.clone(),
+            tick_lower,
+            tick_upper,
+            amount0: deposit0 as i128,
+            amount1: deposit1 as i128,
+            bounty0: bounty0 as i128,
+            bounty1: bounty1 as i128,
+            liquidity: liquidity as i128,
+        }
+        .publish(e);
+        Self::emit_position_update(e, owner, tick_lower, tick_upper, liquidity as i128);
+        Self::emit_pool_state(e, &slot, get_liquidity(e));
+
+        (Vec::from_array(e, [deposit0, deposit1]), liquidity)
+    }
+
     /// `user_max_in`: the user-specified maximum input amount, known at signing time.
     /// For exact-input swaps this is `in_amount`; for exact-output it is `in_max`.
     /// Auth-deterministic: always transfers `user_max_in` from user.
@@ -1,14 +1,19 @@
 use crate::constants::{
-    max_liquidity_per_tick, FEE_DENOMINATOR, MAX_TICK, MAX_USER_POSITIONS, MIN_TICK,
+    max_liquidity_per_tick, FEE_DENOMINATOR, MAX_COMPOUND_BOUNTY_FRACTION,
+    MAX_COMPOUND_MAX_SLIPPAGE, MAX_DISTRIBUTION_POINTS, MAX_TICK, MAX_USER_POSITIONS, MIN_TICK,
     TICKS_PER_CHUNK,
 };
 use crate::errors::ConcentratedPoolError as Error;
-use crate::events::ClaimFees;
+use crate::events::{
+    ClaimFees, CompoundFees, SetAutoCompound, SetCompoundBountyFraction, SetCompoundMaxSlippage,
+    SetRewardsMaxDistance,
+};
 use crate::math::{
     amount0_delta, amount1_delta, fee_growth_delta_x128, get_next_sqrt_price_from_input,
     get_next_sqrt_price_from_output, liquidity_for_amount0, liquidity_for_amount1, max_sqrt_ratio,
-    min_sqrt_ratio, mul_div_fee_growth, mul_div_u128, sqrt_price_from_amounts, sqrt_ratio_at_tick,
-    tick_at_sqrt_ratio, try_amount0_delta, try_amount1_delta, wrapping_add_u256, wrapping_sub_u256,
+    min_sqrt_ratio, mul_div_fee_growth, mul_div_u128, sqrt_price_from_amounts,
+    sqrt_price_moved_by_bps, sqrt_ratio_at_tick, tick_at_sqrt_ratio, try_amount0_delta,
+    try_amount1_delta, wrapping_add_u256, wrapping_sub_u256,
 };
 use crate::plane::update_plane;
 use crate::plane_interface::Plane;
@@ -17,21 +22,24 @@ use crate::pool_interface::{
     ManagedLiquidityPool, RewardsTrait, UpgradeableContract,
 };
 use crate::storage::{
-    chunk_address, get_chunk_bitmap_word, get_claim_killed, get_fee, get_fee_growth_global_0_x128,
-    get_fee_growth_global_1_x128, get_full_range_liquidity, get_gauge_future_wasm,
-    get_is_killed_deposit, get_is_killed_swap, get_liquidity, get_max_init_tick, get_min_init_tick,
-    get_or_create_tick_chunk, get_plane, get_position, get_protocol_fee_fraction,
-    get_protocol_fees, get_reserve0, get_reserve1, get_router, get_slot0, get_tick, get_tick_chunk,
-    get_tick_spacing, get_token0, get_token1, get_token_future_wasm, get_total_raw_liquidity,
-    get_total_weighted_liquidity, get_user_raw_liquidity, get_user_state,
+    checkpoint_ledger_start_price, chunk_address, get_auto_compound, get_chunk_bitmap_word,
+    get_claim_killed, get_compound_bounty_fraction, get_compound_max_slippage, get_fee,
+    get_fee_growth_global_0_x128, get_fee_growth_global_1_x128, get_full_range_liquidity,
+    get_gauge_future_wasm, get_is_killed_deposit, get_is_killed_swap, get_ledger_start_sqrt_price,
+    get_liquidity, get_max_init_tick, get_min_init_tick, get_or_create_tick_chunk, get_plane,
+    get_position, get_price_initialized, get_protocol_fee_fraction, get_protocol_fees,
+    get_reserve0, get_reserve1, get_rewards_max_distance, get_router, get_slot0, get_tick,
+    get_tick_chunk, get_tick_spacing, get_token0, get_token1, get_token_future_wasm,
+    get_total_raw_liquidity, get_total_weighted_liquidity, get_user_raw_liquidity, get_user_state,
     get_user_weighted_liquidity, get_word_bitmap, new_empty_chunk, remove_position,
-    set_chunk_bitmap_word, set_claim_killed, set_fee, set_fee_growth_global_0_x128,
-    set_fee_growth_global_1_x128, set_full_range_liquidity, set_gauge_future_wasm,
-    set_is_killed_deposit, set_is_killed_swap, set_liquidity, set_max_init_tick, set_min_init_tick,
-    set_plane, set_position, set_protocol_fee_fraction, set_protocol_fees, set_reserve0,
-    set_reserve1, set_router, set_slot0, set_tick_chunk, set_tick_spacing, set_token0, set_token1,
-    set_token_future_wasm, set_total_raw_liquidity, set_total_weighted_liquidity, set_user_state,
-    set_word_bitmap, ChunkCache,
+    set_auto_compound, set_chunk_bitmap_word, set_claim_killed, set_compound_bounty_fraction,
+    set_compound_max_slippage, set_fee, set_fee_growth_global_0_x128, set_fee_growth_global_1_x128,
+    set_full_range_liquidity, set_gauge_future_wasm, set_is_killed_deposit, set_is_killed_swap,
+    set_liquidity, set_max_init_tick, set_min_init_tick, set_plane, set_position,
+    set_price_initialized, set_protocol_fee_fraction, set_protocol_fees, set_reserve0,
+    set_reserve1, set_rewards_max_distance, set_router, set_slot0, set_tick_chunk,
+    set_tick_spacing, set_token0, set_token1, set_token_future_wasm, set_total_raw_liquidity,
+    set_total_weighted_liquidity, set_user_state, set_word_bitmap, ChunkCache,
 };
 use crate::types::{
     PositionData, PositionRange, ProtocolFees, Slot0, SwapResult, TickData, TickInfo,
@@ -54,10 +62,14 @@ use liqidity_pool_rewards_gauge::interface::RewardsGaugeInterface;
 use liquidity_pool_events::Events as PoolEvents;
 use liquidity_pool_events::LiquidityPoolEvents;
 use liquidity_pool_validation_errors::LiquidityPoolValidationError;
-use rewards::concentrated_weight::{apply_multiplier, position_multiplier_bps};
+use rewards::concentrated_weight::{
+    apply_multiplier, is_valid_max_distance, position_multiplier_bps,
+};
 use rewards::events::Events as RewardEvents;
 use rewards::storage::{
-    BoostFeedStorageTrait, BoostTokenStorageTrait, PoolRewardsStorageTrait, RewardTokenStorageTrait,
+    BoostConfig, BoostDelegation, BoostFeedStorageTrait, BoostTokenStorageTrait, PoolRewardConfig,
+    PoolRewardsStorageTrait, RewardClaimRecord, RewardEpochSnapshot, RewardTokenStorageTrait,
+    VestingConfig,
 };
 use rewards::Rewards;
 use soroban_sdk::token::TokenClient as SorobanTokenClient;
@@ -30,7 +30,7 @@ impl RewardsGaugeInterface for ConcentratedLiquidityPool {
         start_at: Option<u64>,
         duration: u64,
         tps: u128,
-    ) {
+    ) -> u32 {
         router.require_auth();
         distributor.require_auth();
         if router != get_router(&e) {
@@ -49,7 +49,81 @@ impl RewardsGaugeInterface for ConcentratedLiquidityPool {
             duration,
             tps,
             manager.get_working_supply(total_weighted),
-        );
+        )
+    }
+
+    // Cancel reward config on a gauge. Router + distributor auth required.
+    fn gauge_cancel_reward(
+        e: Env,
+        router: Address,
+        distributor: Address,
+        gauge: Address,
+        config_id: u32,
+    ) -> u128 {
+        router.require_auth();
+        distributor.require_auth();
+        if router != get_router(&e) {
+            panic_with_error!(&e, Error::Unauthorized);
+        }
+
+        let rewards = Self::rewards_manager(&e);
+        let total_weighted = get_total_weighted_liquidity(&e);
+        let manager = rewards.manager();
+
+        rewards_gauge::operations::cancel_rewards_config(
+            &e,
+            gauge,
+            distributor,
+            config_id,
+            manager.get_working_supply(total_weighted),
+        )
+    }
+
+    // Extend reward config on a gauge. Router + distributor auth required.
+    fn gauge_extend_reward(
+        e: Env,
+        router: Address,
+        distributor: Address,
+        gauge: Address,
+        config_id: u32,
+        extra_duration: u64,
+        extra_tps: u128,
+    ) -> u128 {
+        router.require_auth();
+        distributor.require_auth();
+        if router != get_router(&e) {
+            panic_with_error!(&e, Error::Unauthorized);
+        }
+
+        let rewards = Self::rewards_manager(&e);
+        let total_weighted = get_total_weighted_liquidity(&e);
+        let manager = rewards.manager();
+
+        rewards_gauge::operations::extend_rewards_config(
+            &e,
+            gauge,
+            distributor,
+            config_id,
+            extra_duration,
+            extra_tps,
+            manager.get_working_supply(total_weighted),
+        )
+    }
+
+    // Return the distributor's unallocated rewards. Distributor auth required.
+    fn gauge_reclaim_unallocated(e: Env, distributor: Address, reward_token: Address) -> u128 {
+        distributor.require_auth();
+
+        let rewards = Self::rewards_manager(&e);
+        let total_weighted = get_total_weighted_liquidity(&e);
+        let manager = rewards.manager();
+
+        rewards_gauge::operations::reclaim_unallocated(
+            &e,
+            reward_token,
+            distributor,
+            manager.get_working_supply(total_weighted),
+        )
     }
 
     // Kill switch for gauge reward claims.
@@ -65,11 +139,30 @@ impl RewardsGaugeInterface for ConcentratedLiquidityPool {
         rewards_gauge::operations::unkill_claim(&e);
     }
 
+    // Pause claims of a single gauge. Pause admin, emergency pause admin, or owner.
+    fn pause_gauge(e: Env, admin: Address, reward_token: Address) {
+        admin.require_auth();
+        require_pause_or_emergency_pause_admin_or_owner(&e, &admin);
+        rewards_gauge::operations::pause(&e, reward_token);
+    }
+
+    // Resume claims of a paused gauge. Pause admin or owner.
+    fn unpause_gauge(e: Env, admin: Address, reward_token: Address) {
+        admin.require_auth();
+        require_pause_admin_or_owner(&e, &admin);
+        rewards_gauge::operations::unpause(&e, reward_token);
+    }
+
     // Returns map of gauge_address → reward_token_address.
     fn get_gauges(e: Env) -> Map<Address, Address> {
         rewards_gauge::operations::list(&e)
     }
 
+    // Returns reward tokens of the paused gauges.
+    fn get_paused_gauges(e: Env) -> Vec<Address> {
+        rewards_gauge::operations::list_paused(&e)
+    }
+
     // Claim rewards from all registered gauges. Returns map of reward_token → amount.
     fn gauges_claim(e: Env, user: Address) -> Map<Address, u128> {
         user.require_auth();
@@ -87,6 +180,30 @@ impl RewardsGaugeInterface for ConcentratedLiquidityPool {
         )
     }
 
+    // Claim gauge rewards of the user to the recipient. Authorized either by the user
+    // or by the user's delegated claimer.
+    fn gauges_claim_to(
+        e: Env,
+        caller: Address,
+        user: Address,
+        recipient: Address,
+    ) -> Map<Address, u128> {
+        let manager = Self::rewards_manager(&e).manager();
+        manager.require_claim_auth(&caller, &user);
+        Self::recompute_user_weighted_liquidity(&e, &user);
+
+        let total_weighted = get_total_weighted_liquidity(&e);
+        let user_weighted = get_user_weighted_liquidity(&e, &user);
+
+        rewards_gauge::operations::claim_to(
+            &e,
+            &user,
+            &recipient,
+            manager.get_working_balance(&user, user_weighted),
+            manager.get_working_supply(total_weighted),
+        )
+    }
+
     // Query pending gauge rewards for a user without claiming.
     fn gauges_get_reward_info(e: Env, user: Address) -> Map<Address, Map<Symbol, i128>> {
         Self::recompute_user_weighted_liquidity(&e, &user);
@@ -40,6 +40,111 @@ impl RewardsTrait for ConcentratedLiquidityPool {
         storage.put_reward_boost_feed(reward_boost_feed);
     }
 
+    // Schedule new boost parameters (max multiplier, in bps, up to 3x).
+    // Applicable after the admin actions delay. Admin only.
+    fn commit_boost_config(e: Env, admin: Address, max_boost_bps: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        Self::rewards_manager(&e)
+            .manager()
+            .commit_boost_config(&BoostConfig { max_boost_bps });
+        RewardEvents::new(&e).commit_boost_config(max_boost_bps);
+    }
+
+    // Activate the committed boost parameters once the timelock passed. Admin only.
+    fn apply_boost_config(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        let config = Self::rewards_manager(&e).manager().apply_boost_config();
+        RewardEvents::new(&e).apply_boost_config(config.max_boost_bps);
+    }
+
+    // Discard the committed boost parameters. Admin only.
+    fn revert_boost_config(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        Self::rewards_manager(&e).manager().revert_boost_config();
+        RewardEvents::new(&e).revert_boost_config();
+    }
+
+    fn get_boost_config(e: Env) -> BoostConfig {
+        Self::rewards_manager(&e).manager().get_boost_config()
+    }
+
+    fn get_future_boost_config(e: Env) -> Option<BoostConfig> {
+        Self::rewards_manager(&e)
+            .manager()
+            .get_future_boost_config()
+    }
+
+    // Switch boost balances to the boost feed lock records once locks are backfilled. Admin only.
+    fn set_boost_lock_records(e: Env, admin: Address, enabled: bool) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        Self::rewards_manager(&e)
+            .manager()
+            .set_boost_lock_records_enabled(enabled);
+        RewardEvents::new(&e).set_boost_lock_records(enabled);
+    }
+
+    fn get_boost_lock_records(e: Env) -> bool {
+        Self::rewards_manager(&e)
+            .manager()
+            .get_boost_lock_records_enabled()
+    }
+
+    // Lend part of the owner's boost to the delegatee until expires_at; amount_bps = 0 revokes.
+    // Both working balances are checkpointed with the new boost. Owner auth.
+    fn delegate_boost(
+        e: Env,
+        owner: Address,
+        delegatee: Address,
+        amount_bps: u32,
+        expires_at: u64,
+    ) {
+        owner.require_auth();
+
+        Self::rewards_manager(&e)
+            .manager()
+            .set_boost_delegation(&owner, &delegatee, amount_bps, expires_at);
+        Self::rewards_checkpoint_working_balance(&e, &owner);
+        Self::rewards_checkpoint_working_balance(&e, &delegatee);
+        RewardEvents::new(&e).set_boost_delegation(owner, delegatee, amount_bps, expires_at);
+    }
+
+    fn get_boost_delegations(e: Env, owner: Address) -> Map<Address, BoostDelegation> {
+        Self::rewards_manager(&e)
+            .manager()
+            .get_boost_delegations(&owner)
+    }
+
+    fn get_boost_delegators(e: Env, delegatee: Address) -> Vec<Address> {
+        Self::rewards_manager(&e)
+            .manager()
+            .get_boost_delegators(&delegatee)
+    }
+
+    // Recalculate working balance with the current boost, checkpointing rewards and gauges.
+    // Boost delegators and delegatees of the user are checkpointed too.
+    // Permissionless, so anyone can sync the user with their lock changes.
+    fn update_boost(e: Env, user: Address) -> u128 {
+        Self::rewards_checkpoint_working_balance(&e, &user);
+        for account in Self::rewards_manager(&e)
+            .manager()
+            .get_boost_counterparties(&user)
+            .iter()
+        {
+            Self::rewards_checkpoint_working_balance(&e, &account);
+        }
+        Self::rewards_manager(&e)
+            .manager()
+            .get_working_balance(&user, get_user_weighted_liquidity(&e, &user))
+    }
+
     // Configure reward emission rate: tps = tokens per second, expired_at = end timestamp.
     // Rewards admin, owner, or router.
     fn set_rewards_config(e: Env, admin: Address, expired_at: u64, tps: u128) {
@@ -51,6 +156,25 @@ impl RewardsTrait for ConcentratedLiquidityPool {
         manager.set_reward_config(get_total_weighted_liquidity(&e), expired_at, tps);
     }
 
+    // Distance in ticks at which out-of-range positions stop earning rewards. Router only:
+    // it pushes the value of the pool's fee tier, which is timelocked in the router registry.
+    // Users pick up the new value on their next checkpoint.
+    fn set_rewards_max_distance(e: Env, router: Address, max_distance: u32) {
+        router.require_auth();
+        if router != get_router(&e) {
+            panic_with_error!(&e, Error::Unauthorized);
+        }
+        if !is_valid_max_distance(max_distance) {
+            panic_with_error!(&e, Error::InvalidMaxDistance);
+        }
+        set_rewards_max_distance(&e, &max_distance);
+        SetRewardsMaxDistance { max_distance }.publish(&e);
+    }
+
+    fn get_rewards_max_distance(e: Env) -> u32 {
+        get_rewards_max_distance(&e)
+    }
+
     // Reward tokens held by pool that exceed what's owed to LPs. Can be reclaimed.
     fn get_unused_reward(e: Env) -> u128 {
         let rewards = Self::rewards_manager(&e);
@@ -158,6 +282,34 @@ impl RewardsTrait for ConcentratedLiquidityPool {
         result.set(symbol_short!("usr_block"), user_data.last_block as i128);
         result.set(symbol_short!("to_claim"), user_data.to_claim as i128);
 
+        // claimed rewards locked in vesting and the part already releasable with claim_vested
+        let vesting = manager.get_user_vesting(&user);
+        let vesting_config = manager.get_vesting_config();
+        result.set(
+            Symbol::new(&e, "vesting_locked"),
+            vesting.as_ref().map_or(0, |v| v.total) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_claimable"),
+            vesting.as_ref().map_or(0, |v| v.unlocked) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_end"),
+            vesting.map_or(0, |v| v.end_time()) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_cliff"),
+            vesting_config.as_ref().map_or(0, |v| v.cliff) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_duration"),
+            vesting_config.as_ref().map_or(0, |v| v.duration) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_penalty"),
+            vesting_config.map_or(0, |v| v.early_exit_penalty_bps) as i128,
+        );
+
         // provide updated working balance information. if working_balance_new is bigger
         // than working_balance, it means that user has locked some tokens
         // and needs to checkpoint itself for more rewards
@@ -266,6 +418,89 @@ impl RewardsTrait for ConcentratedLiquidityPool {
 
         user.require_auth();
 
+        Self::claim_reward(&e, &user, &user)
+    }
+
+    // Claim pending rewards of the user to the recipient. Authorized either by the user
+    // or by the user's delegated claimer.
+    fn claim_to(e: Env, caller: Address, user: Address, recipient: Address) -> u128 {
+        if get_claim_killed(&e) {
+            panic_with_error!(&e, Error::ClaimKilled)
+        }
+
+        Self::rewards_manager(&e)
+            .manager()
+            .require_claim_auth(&caller, &user);
+
+        Self::claim_reward(&e, &user, &recipient)
+    }
+
+    // Delegate claim_to / gauges_claim_to of the user's rewards. None revokes the delegation.
+    fn set_reward_claimer(e: Env, user: Address, claimer: Option<Address>) {
+        user.require_auth();
+        Self::rewards_manager(&e)
+            .manager()
+            .set_reward_claimer(&user, claimer.clone());
+        RewardEvents::new(&e).set_reward_claimer(user, claimer);
+    }
+
+    fn get_reward_claimer(e: Env, user: Address) -> Option<Address> {
+        Self::rewards_manager(&e)
+            .manager()
+            .get_reward_claimer(&user)
+    }
+
+    // Vesting of claimed rewards: nothing is releasable for `cliff` seconds after a claim,
+    // then it unlocks linearly until `duration`. duration = 0 disables vesting.
+    // Rewards admin or owner.
+    fn set_rewards_vesting(
+        e: Env,
+        admin: Address,
+        cliff: u64,
+        duration: u64,
+        early_exit_penalty_bps: u32,
+    ) {
+        admin.require_auth();
+        require_rewards_admin_or_owner(&e, &admin);
+
+        let config = match duration {
+            0 => None,
+            _ => Some(VestingConfig {
+                cliff,
+                duration,
+                early_exit_penalty_bps,
+            }),
+        };
+        Self::rewards_manager(&e)
+            .manager()
+            .set_vesting_config(config);
+        RewardEvents::new(&e).set_rewards_vesting(cliff, duration, early_exit_penalty_bps);
+    }
+
+    // Transfer the vested part of the user's claimed rewards.
+    fn claim_vested(e: Env, user: Address) -> u128 {
+        if get_claim_killed(&e) {
+            panic_with_error!(&e, Error::ClaimKilled)
+        }
+
+        let rewards = Self::rewards_manager(&e);
+        let amount = rewards.manager().claim_vested(&user);
+
+        let reward_token = rewards.storage().get_reward_token();
+        Self::assert_reward_reserves(&e, &reward_token);
+        RewardEvents::new(&e).claim_vested(user, reward_token, amount);
+        amount
+    }
+
+    // Transfer the whole vesting balance. The early exit penalty on the locked part
+    // is distributed to the other liquidity providers. Returns (amount, penalty).
+    fn exit_vesting(e: Env, user: Address) -> (u128, u128) {
+        if get_claim_killed(&e) {
+            panic_with_error!(&e, Error::ClaimKilled)
+        }
+
+        user.require_auth();
+
         Self::recompute_user_weighted_liquidity(&e, &user);
 
         let total_weighted = get_total_weighted_liquidity(&e);
@@ -279,32 +514,126 @@ impl RewardsTrait for ConcentratedLiquidityPool {
             manager.get_working_balance(&user, user_weighted),
             manager.get_working_supply(total_weighted),
         );
-        let reward = manager.claim_reward(&user, total_weighted, user_weighted);
+        let (amount, penalty) = manager.exit_vesting(&user, total_weighted, user_weighted);
 
-        // Post-claim reserve validation: ensure the reward transfer did not drain
-        // below reserves + protocol fees. Stored reserves are independent of balance
-        // (updated only by deposit/withdraw/swap/collect), so this check is meaningful.
         let reward_token = rewards.storage().get_reward_token();
-        let token0 = get_token0(&e);
-        let token1 = get_token1(&e);
-        let contract = e.current_contract_address();
-        let protocol_fees = get_protocol_fees(&e);
-
-        if reward_token == token0 {
-            let balance = SorobanTokenClient::new(&e, &token0).balance(&contract) as u128;
-            let reserve = get_reserve0(&e);
-            if reserve + protocol_fees.token0 > balance {
-                panic_with_error!(&e, LiquidityPoolValidationError::InsufficientBalance);
-            }
-        } else if reward_token == token1 {
-            let balance = SorobanTokenClient::new(&e, &token1).balance(&contract) as u128;
-            let reserve = get_reserve1(&e);
-            if reserve + protocol_fees.token1 > balance {
-                panic_with_error!(&e, LiquidityPoolValidationError::InsufficientBalance);
-            }
+        Self::assert_reward_reserves(&e, &reward_token);
+        RewardEvents::new(&e).exit_vesting(user, reward_token, amount, penalty);
+        (amount, penalty)
+    }
+
+    // Enables or disables recording of reward history.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `enabled` - Whether pool reward snapshots and user claims are recorded.
+    fn set_rewards_history(e: Env, admin: Address, enabled: bool) {
+        admin.require_auth();
+        require_rewards_admin_or_owner(&e, &admin);
+
+        Self::rewards_manager(&e)
+            .manager()
+            .set_reward_history_enabled(enabled);
+        RewardEvents::new(&e).set_rewards_history(enabled);
+    }
+
+    fn get_reward_snapshots(e: Env, offset: u32, limit: u32) -> Vec<RewardEpochSnapshot> {
+        Self::rewards_manager(&e)
+            .manager()
+            .get_reward_epoch_snapshots(offset, limit)
+    }
+
+    fn get_user_reward_claims(
+        e: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<RewardClaimRecord> {
+        Self::rewards_manager(&e)
+            .manager()
+            .get_user_reward_claims(&user, offset, limit)
+    }
+
+    // Extra reward token, accounted separately from AQUA. Rewards admin or owner only.
+    fn add_reward_token(e: Env, admin: Address, token: Address) {
+        admin.require_auth();
+        require_rewards_admin_or_owner(&e, &admin);
+
+        Self::rewards_manager(&e).manager().add_reward_token(&token);
+        RewardEvents::new(&e).add_reward_token(token);
+    }
+
+    fn get_reward_tokens(e: Env) -> Vec<Address> {
+        Self::rewards_manager(&e).manager().get_reward_tokens()
+    }
+
+    // Same as set_rewards_config, for any of the reward tokens. Rewards admin or owner only.
+    fn set_token_rewards_config(
+        e: Env,
+        admin: Address,
+        token: Address,
+        expired_at: u64,
+        tps: u128,
+    ) {
+        admin.require_auth();
+        require_rewards_admin_or_owner(&e, &admin);
+
+        let mut manager = Self::rewards_manager(&e).manager();
+        manager.set_token_reward_config(&token, get_total_weighted_liquidity(&e), expired_at, tps);
+        RewardEvents::new(&e).set_token_rewards_config(token, expired_at, tps);
+    }
+
+    fn get_token_rewards_config(e: Env, token: Address) -> PoolRewardConfig {
+        Self::rewards_manager(&e)
+            .manager()
+            .get_token_reward_config(&token)
+    }
+
+    fn get_user_rewards(e: Env, user: Address) -> Map<Address, u128> {
+        Self::recompute_user_weighted_liquidity(&e, &user);
+
+        let total_weighted = get_total_weighted_liquidity(&e);
+        let user_weighted = get_user_weighted_liquidity(&e, &user);
+        let mut manager = Self::rewards_manager(&e).manager();
+
+        rewards_gauge::operations::checkpoint_user(
+            &e,
+            &user,
+            manager.get_working_balance(&user, user_weighted),
+            manager.get_working_supply(total_weighted),
+        );
+
+        manager.get_amounts_to_claim(&user, total_weighted, user_weighted)
+    }
+
+    // Same as claim, for every reward token at once.
+    fn claim_rewards(e: Env, user: Address) -> Map<Address, u128> {
+        if get_claim_killed(&e) {
+            panic_with_error!(&e, Error::ClaimKilled)
         }
 
-        RewardEvents::new(&e).claim(user.clone(), reward_token, reward);
+        user.require_auth();
+
+        Self::recompute_user_weighted_liquidity(&e, &user);
+
+        let total_weighted = get_total_weighted_liquidity(&e);
+        let user_weighted = get_user_weighted_liquidity(&e, &user);
+
+        let rewards = Self::rewards_manager(&e);
+        let mut manager = rewards.manager();
+        rewards_gauge::operations::checkpoint_user(
+            &e,
+            &user,
+            manager.get_working_balance(&user, user_weighted),
+            manager.get_working_supply(total_weighted),
+        );
+        let rewards_claimed = manager.claim_rewards_to(&user, &user, total_weighted, user_weighted);
+
+        for (reward_token, reward) in rewards_claimed.iter() {
+            Self::assert_reward_reserves(&e, &reward_token);
+            RewardEvents::new(&e).claim(user.clone(), reward_token, reward);
+        }
 
         let manager_after = rewards.manager();
         rewards_gauge::operations::checkpoint_user(
@@ -314,7 +643,7 @@ impl RewardsTrait for ConcentratedLiquidityPool {
             manager_after.get_working_supply(total_weighted),
         );
 
-        reward
+        rewards_claimed
     }
 
     // Whether user has opted into rewards (true = active, false = excluded).
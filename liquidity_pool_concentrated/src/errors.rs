@@ -36,4 +36,10 @@ pub enum ConcentratedPoolError {
     InsufficientLiquidity = 2121,
     LiquidityOverflow = 2122,
     LiquidityUnderflow = 2123,
+    InvalidSlippage = 2124,
+    AutoCompoundDisabled = 2125,
+    NothingToCompound = 2126,
+    PriceAlreadyInitialized = 2127,
+    InvalidMaxDistance = 2128,
+    CompoundPriceMoved = 2129,
 }
@@ -23,3 +23,89 @@ pub struct ClaimFees {
     pub amount0: i128,
     pub amount1: i128,
 }
+
+// topics
+// [
+//   "compound_fees": Symbol,   // event identifier
+//   owner: Address,            // position owner
+//   caller: Address,           // owner itself or the keeper that triggered the compound
+// ]
+// body
+// [
+//   tick_lower: i32,           // position range
+//   tick_upper: i32,
+//   amount0: i128,             // token0 fees re-invested as liquidity
+//   amount1: i128,             // token1 fees re-invested as liquidity
+//   bounty0: i128,             // token0 paid to the keeper
+//   bounty1: i128,             // token1 paid to the keeper
+//   liquidity: i128,           // liquidity added to the position
+// ]
+#[contractevent(data_format = "vec")]
+pub struct CompoundFees {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub caller: Address,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub amount0: i128,
+    pub amount1: i128,
+    pub bounty0: i128,
+    pub bounty1: i128,
+    pub liquidity: i128,
+}
+
+// topics
+// [
+//   "set_auto_compound": Symbol,
+//   owner: Address,
+// ]
+// body
+// [
+//   enabled: bool,
+// ]
+#[contractevent(data_format = "vec")]
+pub struct SetAutoCompound {
+    #[topic]
+    pub owner: Address,
+    pub enabled: bool,
+}
+
+// topics
+// [
+//   "set_compound_bounty_fraction": Symbol,
+// ]
+// body
+// [
+//   fraction: u32,             // keeper's share of compounded fees, per FEE_DENOMINATOR
+// ]
+#[contractevent(data_format = "vec")]
+pub struct SetCompoundBountyFraction {
+    pub fraction: u32,
+}
+
+// topics
+// [
+//   "set_compound_max_slippage": Symbol,
+// ]
+// body
+// [
+//   max_slippage: u32,         // compounding swap slippage bound, per FEE_DENOMINATOR
+// ]
+#[contractevent(data_format = "vec")]
+pub struct SetCompoundMaxSlippage {
+    pub max_slippage: u32,
+}
+
+// topics
+// [
+//   "set_rewards_max_distance": Symbol,
+// ]
+// body
+// [
+//   max_distance: u32,         // distance in ticks at which rewards decay to zero
+// ]
+#[contractevent(data_format = "vec")]
+pub struct SetRewardsMaxDistance {
+    pub max_distance: u32,
+}
@@ -650,6 +650,23 @@ pub fn sqrt_price_from_amounts(e: &Env, amount0: u128, amount1: u128) -> U256 {
     sqrt_price
 }
 
+// Move a sqrt price so that the price itself changes by `bps` basis points.
+// sqrt_target = sqrt_price * sqrt((10_000 ± bps) / 10_000), factor kept in Q64 precision.
+// Downward moves of 10_000 bps or more collapse to zero; callers clamp to MIN_SQRT_RATIO.
+pub fn sqrt_price_moved_by_bps(e: &Env, sqrt_price_x96: &U256, bps: u32, up: bool) -> U256 {
+    const BPS_DENOMINATOR: u128 = 10_000;
+    let numerator = if up {
+        BPS_DENOMINATOR + bps as u128
+    } else {
+        BPS_DENOMINATOR.saturating_sub(bps as u128)
+    };
+    let factor_q64 = u256_from_u128(e, numerator)
+        .shl(Q128_SHIFT)
+        .div(&u256_from_u128(e, BPS_DENOMINATOR))
+        .sqrt();
+    mul_div_floor(e, sqrt_price_x96, &factor_q64, &u256_one(e).shl(64))
+}
+
 #[cfg(test)]
 mod test {
     use super::{
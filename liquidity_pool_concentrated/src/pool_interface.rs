@@ -5,6 +5,9 @@ use crate::types::{
     UserPositionSnapshot,
 };
 use crate::Error;
+use rewards::storage::{
+    BoostConfig, BoostDelegation, PoolRewardConfig, RewardClaimRecord, RewardEpochSnapshot,
+};
 use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Val, Vec, U256};
 
 pub trait ManagedLiquidityPool {
@@ -86,7 +89,20 @@ pub trait RewardsTrait {
         reward_boost_token: Address,
         reward_boost_feed: Address,
     );
+    fn commit_boost_config(e: Env, admin: Address, max_boost_bps: u32);
+    fn apply_boost_config(e: Env, admin: Address);
+    fn revert_boost_config(e: Env, admin: Address);
+    fn get_boost_config(e: Env) -> BoostConfig;
+    fn get_future_boost_config(e: Env) -> Option<BoostConfig>;
+    fn set_boost_lock_records(e: Env, admin: Address, enabled: bool);
+    fn get_boost_lock_records(e: Env) -> bool;
+    fn delegate_boost(e: Env, owner: Address, delegatee: Address, amount_bps: u32, expires_at: u64);
+    fn get_boost_delegations(e: Env, owner: Address) -> Map<Address, BoostDelegation>;
+    fn get_boost_delegators(e: Env, delegatee: Address) -> Vec<Address>;
+    fn update_boost(e: Env, user: Address) -> u128;
     fn set_rewards_config(e: Env, admin: Address, expired_at: u64, tps: u128);
+    fn set_rewards_max_distance(e: Env, router: Address, max_distance: u32);
+    fn get_rewards_max_distance(e: Env) -> u32;
     fn get_unused_reward(e: Env) -> u128;
     fn return_unused_reward(e: Env, admin: Address) -> u128;
     fn get_rewards_info(e: Env, user: Address) -> Map<Symbol, i128>;
@@ -103,6 +119,55 @@ pub trait RewardsTrait {
     fn adjust_total_accumulated_reward(e: Env, admin: Address, diff: i128);
     fn get_total_claimed_reward(e: Env) -> u128;
     fn claim(e: Env, user: Address) -> u128;
+    fn claim_to(e: Env, caller: Address, user: Address, recipient: Address) -> u128;
+    fn set_reward_claimer(e: Env, user: Address, claimer: Option<Address>);
+    fn get_reward_claimer(e: Env, user: Address) -> Option<Address>;
+    fn set_rewards_vesting(
+        e: Env,
+        admin: Address,
+        cliff: u64,
+        duration: u64,
+        early_exit_penalty_bps: u32,
+    );
+    fn claim_vested(e: Env, user: Address) -> u128;
+    fn exit_vesting(e: Env, user: Address) -> (u128, u128);
+
+    // Turn recording of reward history on or off: a pool reward snapshot per reward config
+    // change and a record per user claim. Already recorded history is kept.
+    fn set_rewards_history(e: Env, admin: Address, enabled: bool);
+
+    // Get pool reward snapshots recorded on reward config changes, oldest first.
+    // At most 50 records are returned per call.
+    fn get_reward_snapshots(e: Env, offset: u32, limit: u32) -> Vec<RewardEpochSnapshot>;
+
+    // Get reward claims of the user, oldest first. At most 50 records are returned per call.
+    fn get_user_reward_claims(
+        e: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<RewardClaimRecord>;
+
+    // Add a reward token accounted separately from the primary one, up to 3 extra tokens.
+    // It yields nothing until configured with set_token_rewards_config.
+    fn add_reward_token(e: Env, admin: Address, token: Address);
+
+    // Get reward tokens of the pool, the primary one first.
+    fn get_reward_tokens(e: Env) -> Vec<Address>;
+
+    // Configure rewards of any of the pool reward tokens. The pool should hold enough tokens
+    // to pay the configured rewards.
+    fn set_token_rewards_config(e: Env, admin: Address, token: Address, expired_at: u64, tps: u128);
+
+    // Get rewards config of any of the pool reward tokens.
+    fn get_token_rewards_config(e: Env, token: Address) -> PoolRewardConfig;
+
+    // Get amounts of every reward token available for the user to claim.
+    fn get_user_rewards(e: Env, user: Address) -> Map<Address, u128>;
+
+    // Claim rewards in every reward token as a user.
+    // returns amounts of tokens rewarded to the user
+    fn claim_rewards(e: Env, user: Address) -> Map<Address, u128>;
     fn get_rewards_state(e: Env, user: Address) -> bool;
     fn set_rewards_state(e: Env, user: Address, state: bool);
     fn admin_set_rewards_state(e: Env, admin: Address, user: Address, state: bool);
@@ -131,6 +196,8 @@ pub trait AdminInterfaceTrait {
     fn get_is_killed_claim(e: Env) -> bool;
 
     fn set_protocol_fee_fraction(e: Env, admin: Address, new_fraction: u32);
+    fn set_compound_bounty_fraction(e: Env, admin: Address, new_fraction: u32);
+    fn set_compound_max_slippage(e: Env, admin: Address, max_slippage: u32);
     fn get_protocol_fees(e: Env) -> Vec<u128>;
     fn claim_protocol_fees(e: Env, admin: Address, destination: Address) -> Vec<u128>;
 }
@@ -154,6 +221,8 @@ pub trait UpgradeableContract {
 }
 
 pub trait ConcentratedPoolExtensionsTrait {
+    fn initialize_price(e: Env, admin: Address, sqrt_price_x96: U256);
+
     fn estimate_deposit_position(
         e: Env,
         tick_lower: i32,
@@ -187,6 +256,37 @@ pub trait ConcentratedPoolExtensionsTrait {
         min_amounts: Vec<u128>,
     ) -> Vec<u128>;
 
+    fn rebalance_position(
+        e: Env,
+        owner: Address,
+        old_tick_lower: i32,
+        old_tick_upper: i32,
+        new_tick_lower: i32,
+        new_tick_upper: i32,
+        max_slippage: u32,
+    ) -> (Vec<u128>, u128);
+
+    fn compound_position(
+        e: Env,
+        owner: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        min_liquidity: u128,
+    ) -> (Vec<u128>, u128);
+
+    fn compound_position_for(
+        e: Env,
+        keeper: Address,
+        owner: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+    ) -> (Vec<u128>, u128);
+
+    fn set_auto_compound(e: Env, owner: Address, enabled: bool);
+    fn get_auto_compound(e: Env, owner: Address) -> bool;
+    fn get_compound_bounty_fraction(e: Env) -> u32;
+    fn get_compound_max_slippage(e: Env) -> u32;
+
     fn get_position_fees(e: Env, owner: Address, tick_lower: i32, tick_upper: i32) -> Vec<u128>;
 
     fn claim_position_fees(e: Env, owner: Address, tick_lower: i32, tick_upper: i32) -> Vec<u128>;
@@ -219,4 +319,16 @@ pub trait ConcentratedPoolExtensionsTrait {
     // Returns (min_init_tick, max_init_tick) — the bounds of initialized ticks.
     // When min > max the pool has no initialized ticks.
     fn get_tick_bounds(e: Env) -> (i32, i32);
+
+    // Liquidity histogram over [tick_from, tick_to) in at most `max_points` buckets.
+    // Each bucket is (start_tick, active_liquidity, amount0, amount1).
+    fn get_liquidity_distribution(
+        e: Env,
+        tick_from: i32,
+        tick_to: i32,
+        max_points: u32,
+    ) -> Vec<(i32, u128, u128, u128)>;
+
+    // Input required to move the price by `bps` basis points: [token0 in (down), token1 in (up)].
+    fn get_depth(e: Env, bps: u32) -> Vec<u128>;
 }
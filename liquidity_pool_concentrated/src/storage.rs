@@ -1,9 +1,9 @@
 use crate::constants::TICKS_PER_CHUNK;
 use crate::types::{
-    PositionData, PositionRange, ProtocolFees, Slot0, TickData, TickInfo, UserState,
+    LedgerStartPrice, PositionData, ProtocolFees, Slot0, TickData, TickInfo, UserState,
 };
 use paste::paste;
-use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Vec};
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Vec, U256};
 use utils::bump::{bump_instance, bump_persistent};
 use utils::storage_errors::StorageError;
 use utils::{
@@ -28,6 +28,7 @@ pub enum DataKey {
 
     // ── Instance: pool state ──
     Slot0,                // Slot0 { sqrt_price_x96, tick }
+    PriceInitialized,     // bool — price set explicitly via initialize_price
     Liquidity,            // u128 — active liquidity at current tick
     FeeGrowthGlobal0X128, // U256
     FeeGrowthGlobal1X128, // U256
@@ -43,12 +44,18 @@ pub enum DataKey {
     TotalRawLiquidity,      // u128 — sum of all users' raw liquidity
     TotalWeightedLiquidity, // u128 — sum of all users' weighted liquidity
     FullRangeLiquidity,     // u128 — total liquidity in full-range positions
+    RewardsMaxDistance,     // u32 — fee tier override of the rewards decay distance
 
     // ── Instance: kill switches ──
     IsKilledDeposit,
     IsKilledSwap,
     ClaimKilled,
 
+    // ── Instance: auto-compounding ──
+    CompoundBountyFraction, // u32 — keeper's share of compounded fees, per FEE_DENOMINATOR
+    CompoundMaxSlippage,    // u32 — compounding swap slippage bound, per FEE_DENOMINATOR
+    LedgerStartPrice,       // LedgerStartPrice — keeper compounding reference price
+
     // ── Instance: upgrade staging ──
     TokenFutureWasm, // BytesN<32>
     GaugeFutureWasm, // BytesN<32>
@@ -64,6 +71,7 @@ pub enum DataKey {
     // ── Persistent: per-user ──
     Position(Address, i32, i32), // PositionData — keyed by (owner, tick_lower, tick_upper)
     User(Address),               // UserState — positions + raw/weighted liquidity
+    AutoCompound(Address),       // bool — keepers may compound the user's positions
 }
 
 generate_instance_storage_getter_and_setter!(router, DataKey::Router, Address);
@@ -104,6 +112,12 @@ generate_instance_storage_getter_and_setter_with_default!(
     u32,
     5_000
 );
+generate_instance_storage_getter_and_setter_with_default!(
+    price_initialized,
+    DataKey::PriceInitialized,
+    bool,
+    false
+);
 generate_instance_storage_getter_and_setter_with_default!(
     is_killed_deposit,
     DataKey::IsKilledDeposit,
@@ -150,6 +164,62 @@ generate_instance_storage_getter_and_setter_with_default!(
     bool,
     false
 );
+// Ticks from a range at which rewards weight reaches zero. Defaults to the fee-derived value.
+pub fn get_rewards_max_distance(e: &Env) -> u32 {
+    bump_instance(e);
+    match e.storage().instance().get(&DataKey::RewardsMaxDistance) {
+        Some(value) => value,
+        None => rewards::concentrated_weight::max_distance_for_fee(get_fee(e)),
+    }
+}
+
+pub fn set_rewards_max_distance(e: &Env, value: &u32) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::RewardsMaxDistance, value);
+}
+
+generate_instance_storage_getter_and_setter_with_default!(
+    compound_bounty_fraction,
+    DataKey::CompoundBountyFraction,
+    u32,
+    crate::constants::DEFAULT_COMPOUND_BOUNTY_FRACTION
+);
+generate_instance_storage_getter_and_setter_with_default!(
+    compound_max_slippage,
+    DataKey::CompoundMaxSlippage,
+    u32,
+    crate::constants::DEFAULT_COMPOUND_MAX_SLIPPAGE
+);
+// Price the pool had when the current ledger started: recorded before the first swap of the
+// ledger, or the current price if nothing has swapped yet.
+pub fn get_ledger_start_sqrt_price(e: &Env) -> U256 {
+    bump_instance(e);
+    let record: Option<LedgerStartPrice> = e.storage().instance().get(&DataKey::LedgerStartPrice);
+    match record {
+        Some(record) if record.ledger == e.ledger().sequence() => record.sqrt_price_x96,
+        _ => get_slot0(e).sqrt_price_x96,
+    }
+}
+
+// Record `sqrt_price_x96` as the ledger start price unless one was already recorded this ledger.
+pub fn checkpoint_ledger_start_price(e: &Env, sqrt_price_x96: &U256) {
+    bump_instance(e);
+    let ledger = e.ledger().sequence();
+    let record: Option<LedgerStartPrice> = e.storage().instance().get(&DataKey::LedgerStartPrice);
+    if matches!(record, Some(record) if record.ledger == ledger) {
+        return;
+    }
+    e.storage().instance().set(
+        &DataKey::LedgerStartPrice,
+        &LedgerStartPrice {
+            ledger,
+            sqrt_price_x96: sqrt_price_x96.clone(),
+        },
+    );
+}
+
 generate_instance_storage_getter_and_setter_with_default!(reserve0, DataKey::Reserve0, u128, 0);
 generate_instance_storage_getter_and_setter_with_default!(reserve1, DataKey::Reserve1, u128, 0);
 
@@ -404,6 +474,28 @@ pub fn set_user_state(e: &Env, user: &Address, state: &UserState) {
     bump_persistent(e, &key);
 }
 
+// Keeper auto-compounding opt-in. Absent entry means disabled.
+pub fn get_auto_compound(e: &Env, user: &Address) -> bool {
+    let key = DataKey::AutoCompound(user.clone());
+    match e.storage().persistent().get(&key) {
+        Some(enabled) => {
+            bump_persistent(e, &key);
+            enabled
+        }
+        None => false,
+    }
+}
+
+pub fn set_auto_compound(e: &Env, user: &Address, enabled: bool) {
+    let key = DataKey::AutoCompound(user.clone());
+    if enabled {
+        e.storage().persistent().set(&key, &true);
+        bump_persistent(e, &key);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
 // Convenience read-only accessors — delegate to get_user_state.
 pub fn get_user_raw_liquidity(e: &Env, user: &Address) -> u128 {
     get_user_state(e, user).raw_liquidity
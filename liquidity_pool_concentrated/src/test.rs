@@ -6,9 +6,9 @@ use crate::testutils::{
     assert_claim_fees_event, count_claim_fees_events, create_pool_contract, create_token_contract,
     deploy_rewards_gauge, get_token_admin_client, Setup, TestConfig,
 };
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::{Address, Env, Map, Symbol, Vec, U256};
-use utils::test_utils::jump;
+use utils::test_utils::{count_events, jump};
 
 mod pool_plane {
     soroban_sdk::contractimport!(file = "../contracts/soroban_liquidity_pool_plane_contract.wasm");
@@ -395,6 +395,62 @@ fn test_boosted_native_rewards_and_gauge() {
     );
 }
 
+#[test]
+fn test_boost_delegation() {
+    let setup = Setup::default();
+    let user = setup.user.clone();
+    let owner = Address::generate(&setup.env);
+
+    setup.mint_user_tokens(1_000_0000000, 1_000_0000000);
+    setup.pool.initialize_boost_config(
+        &setup.reward_boost_token.address,
+        &setup.reward_boost_feed.address,
+    );
+    setup
+        .pool
+        .initialize_rewards_config(&setup.reward_token.address);
+    setup.pool.deposit(
+        &user,
+        &Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]),
+        &0,
+    );
+
+    get_token_admin_client(&setup.env, &setup.reward_boost_token.address)
+        .mint(&owner, &10_000_0000000);
+    setup
+        .reward_boost_feed
+        .set_total_supply(&setup.operations_admin, &20_000_0000000);
+
+    let working_balance = |user: &Address| -> i128 {
+        setup
+            .pool
+            .get_rewards_info(user)
+            .get(Symbol::new(&setup.env, "working_balance"))
+            .unwrap()
+    };
+    let user_weighted = setup
+        .pool
+        .get_rewards_info(&user)
+        .get(Symbol::new(&setup.env, "supply"))
+        .unwrap();
+    assert_eq!(working_balance(&user), user_weighted);
+
+    // the only provider with half of the locked supply: b + 1.5 * b / 2
+    let expires_at = setup.env.ledger().timestamp() + 100;
+    setup
+        .pool
+        .delegate_boost(&owner, &user, &10_000, &expires_at);
+    assert_eq!(
+        setup.pool.get_boost_delegators(&user),
+        Vec::from_array(&setup.env, [owner.clone()])
+    );
+    assert_eq!(working_balance(&user), user_weighted * 7 / 4);
+
+    setup.pool.delegate_boost(&owner, &user, &0, &0);
+    assert_eq!(setup.pool.get_boost_delegations(&owner).len(), 0);
+    assert_eq!(working_balance(&user), user_weighted);
+}
+
 #[test]
 fn test_router_compatible_gauge_schedule_reward() {
     let setup = Setup::default();
@@ -1719,6 +1775,185 @@ fn test_rewards_state_opt_out_and_resume() {
     assert!(claimed > 0, "should accrue rewards after re-enabling");
 }
 
+#[test]
+fn test_rewards_vesting_exit_without_other_providers() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(1_000_0000000, 1_000_0000000);
+
+    setup.pool.initialize_boost_config(
+        &setup.reward_boost_token.address,
+        &setup.reward_boost_feed.address,
+    );
+    setup
+        .pool
+        .initialize_rewards_config(&setup.reward_token.address);
+    setup
+        .pool
+        .set_rewards_vesting(&setup.admin, &0, &100, &2000);
+
+    setup.pool.deposit(
+        &setup.user,
+        &Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]),
+        &0,
+    );
+
+    let tps = 1_000u128;
+    let duration = 100u64;
+    get_token_admin_client(&setup.env, &setup.reward_token.address)
+        .mint(&setup.pool.address, &((tps * duration as u128) as i128));
+    setup.pool.set_rewards_config(
+        &setup.admin,
+        &(setup.env.ledger().timestamp() + duration),
+        &tps,
+    );
+
+    jump(&setup.env, 50);
+    let claimed = setup.pool.claim(&setup.user);
+    assert!(claimed > 0);
+    assert_eq!(setup.reward_token.balance(&setup.user), 0);
+    let info = setup.pool.get_rewards_info(&setup.user);
+    assert_eq!(
+        info.get(Symbol::new(&setup.env, "vesting_locked")).unwrap(),
+        claimed as i128
+    );
+
+    // without other providers the penalty stays in the pool as unused reward
+    jump(&setup.env, 50);
+    setup
+        .pool
+        .set_rewards_config(&setup.admin, &(setup.env.ledger().timestamp() + 1), &0);
+    let unused_before = setup.pool.get_unused_reward();
+    let (amount, penalty) = setup.pool.exit_vesting(&setup.user);
+    let vested = claimed / 2;
+    assert_eq!(penalty, (claimed - vested) * 2000 / 10000);
+    assert_eq!(amount, claimed - penalty);
+    assert_eq!(setup.reward_token.balance(&setup.user) as u128, amount);
+    assert_eq!(setup.pool.get_unused_reward(), unused_before + penalty);
+    assert_eq!(setup.pool.claim_vested(&setup.user), 0);
+}
+
+#[test]
+fn test_claim_to_with_delegated_claimer() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(1_000_0000000, 1_000_0000000);
+    let claimer = Address::generate(&setup.env);
+    let recipient = Address::generate(&setup.env);
+
+    setup.pool.initialize_boost_config(
+        &setup.reward_boost_token.address,
+        &setup.reward_boost_feed.address,
+    );
+    setup
+        .pool
+        .initialize_rewards_config(&setup.reward_token.address);
+    setup.pool.deposit(
+        &setup.user,
+        &Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]),
+        &0,
+    );
+
+    let tps = 1_000u128;
+    let duration = 100u64;
+    get_token_admin_client(&setup.env, &setup.reward_token.address)
+        .mint(&setup.pool.address, &((tps * duration as u128) as i128));
+    setup.pool.set_rewards_config(
+        &setup.admin,
+        &(setup.env.ledger().timestamp() + duration),
+        &tps,
+    );
+    jump(&setup.env, 50);
+
+    setup
+        .pool
+        .set_reward_claimer(&setup.user, &Some(claimer.clone()));
+    assert_eq!(
+        setup.pool.get_reward_claimer(&setup.user),
+        Some(claimer.clone())
+    );
+    let claimed = setup.pool.claim_to(&claimer, &setup.user, &recipient);
+    assert!(claimed > 0);
+    assert_eq!(setup.env.auths()[0].0, claimer);
+    assert_eq!(setup.reward_token.balance(&recipient) as u128, claimed);
+    assert_eq!(setup.reward_token.balance(&setup.user), 0);
+
+    // the user still claims to itself with its own authorization
+    jump(&setup.env, 10);
+    assert!(setup.pool.claim(&setup.user) > 0);
+    assert_eq!(setup.env.auths()[0].0, setup.user);
+
+    // and can use claim_to while the delegation is active
+    jump(&setup.env, 10);
+    assert!(setup.pool.claim_to(&setup.user, &setup.user, &recipient) > 0);
+    assert_eq!(setup.env.auths()[0].0, setup.user);
+    assert!(setup
+        .pool
+        .try_claim_to(&Address::generate(&setup.env), &setup.user, &recipient)
+        .is_err());
+}
+
+#[test]
+fn test_claim_to_with_vesting() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(1_000_0000000, 1_000_0000000);
+    let recipient = Address::generate(&setup.env);
+
+    setup.pool.initialize_boost_config(
+        &setup.reward_boost_token.address,
+        &setup.reward_boost_feed.address,
+    );
+    setup
+        .pool
+        .initialize_rewards_config(&setup.reward_token.address);
+    setup
+        .pool
+        .set_rewards_vesting(&setup.admin, &0, &100, &2000);
+    setup.pool.deposit(
+        &setup.user,
+        &Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]),
+        &0,
+    );
+
+    let tps = 1_000u128;
+    let duration = 100u64;
+    get_token_admin_client(&setup.env, &setup.reward_token.address)
+        .mint(&setup.pool.address, &((tps * duration as u128) as i128));
+    setup.pool.set_rewards_config(
+        &setup.admin,
+        &(setup.env.ledger().timestamp() + duration),
+        &tps,
+    );
+
+    // vested rewards can't be sent to another schedule
+    jump(&setup.env, 50);
+    assert_eq!(
+        setup
+            .pool
+            .try_claim_to(&setup.user, &setup.user, &recipient)
+            .unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(711))
+    );
+    assert_eq!(
+        setup
+            .pool
+            .get_rewards_info(&recipient)
+            .get(Symbol::new(&setup.env, "vesting_locked"))
+            .unwrap(),
+        0
+    );
+
+    // claiming to itself vests as usual
+    let claimed = setup.pool.claim_to(&setup.user, &setup.user, &setup.user);
+    assert!(claimed > 0);
+    assert_eq!(
+        setup
+            .pool
+            .get_rewards_info(&setup.user)
+            .get(Symbol::new(&setup.env, "vesting_locked"))
+            .unwrap(),
+        claimed as i128
+    );
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Error handling: plane & rewards double-initialization
 // ═══════════════════════════════════════════════════════════════════════════
@@ -4564,3 +4799,705 @@ fn test_plane_update_with_extreme_liquidity() {
     let estimate_rev = setup.pool.estimate_swap(&1, &0, &1_0000000);
     assert!(estimate_rev > 0);
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Liquidity distribution & depth views
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_liquidity_distribution_buckets() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(1_000_0000000, 1_000_0000000);
+
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    let (_, liq_a) = setup
+        .pool
+        .deposit_position(&setup.user, &-100, &100, &amounts, &0);
+    let (_, liq_b) = setup
+        .pool
+        .deposit_position(&setup.user, &0, &200, &amounts, &0);
+    assert_eq!(setup.pool.get_slot0().tick, 0);
+
+    let distribution = setup.pool.get_liquidity_distribution(&-200, &300, &5);
+    assert_eq!(distribution.len(), 5);
+
+    let expected = [
+        (-200, 0),
+        (-100, liq_a),
+        (0, liq_a + liq_b),
+        (100, liq_b),
+        (200, 0),
+    ];
+    let mut total0 = 0u128;
+    let mut total1 = 0u128;
+    for (i, (tick, liquidity)) in expected.iter().enumerate() {
+        let (bucket_tick, bucket_liquidity, amount0, amount1) =
+            distribution.get_unchecked(i as u32);
+        assert_eq!(bucket_tick, *tick);
+        assert_eq!(bucket_liquidity, *liquidity);
+        if *liquidity == 0 {
+            assert_eq!((amount0, amount1), (0, 0));
+        }
+        total0 += amount0;
+        total1 += amount1;
+    }
+
+    // Buckets below the price hold token1 only, buckets above hold token0 only.
+    assert_eq!(distribution.get_unchecked(1).2, 0);
+    assert!(distribution.get_unchecked(1).3 > 0);
+    assert!(distribution.get_unchecked(2).2 > 0);
+    assert_eq!(distribution.get_unchecked(2).3, 0);
+
+    // Locked amounts add up to reserves (rounded down per bucket).
+    let reserves = setup.pool.get_reserves();
+    assert!(reserves.get_unchecked(0) - total0 <= 2);
+    assert!(reserves.get_unchecked(1) - total1 <= 2);
+}
+
+#[test]
+fn test_liquidity_distribution_range_below_price() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(1_000_0000000, 1_000_0000000);
+
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    let (_, liq) = setup
+        .pool
+        .deposit_position(&setup.user, &-50, &50, &amounts, &0);
+
+    // Move price above the range: active liquidity drops to zero.
+    setup.pool.swap(&setup.user, &1, &0, &200_0000000, &0);
+    assert!(setup.pool.get_slot0().tick >= 50);
+    assert_eq!(setup.pool.get_active_liquidity(), 0);
+
+    // Range [-20, 20) lies inside the position even though price is above it.
+    let distribution = setup.pool.get_liquidity_distribution(&-20, &20, &2);
+    assert_eq!(distribution.len(), 2);
+    assert_eq!(distribution.get_unchecked(0).1, liq);
+    assert_eq!(distribution.get_unchecked(1).1, liq);
+    assert_eq!(distribution.get_unchecked(0).2, 0);
+    assert!(distribution.get_unchecked(0).3 > 0);
+}
+
+#[test]
+fn test_liquidity_distribution_caps_points() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(1_000_0000000, 1_000_0000000);
+    setup.pool.deposit(
+        &setup.user,
+        &Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]),
+        &0,
+    );
+
+    // 1000 ticks at spacing 1 requested in 1000 points → capped at 200 buckets of 5 ticks.
+    let distribution = setup.pool.get_liquidity_distribution(&-500, &500, &1000);
+    assert_eq!(distribution.len(), 200);
+    assert_eq!(distribution.get_unchecked(1).0, -495);
+
+    // Fewer ticks than points → one bucket per tick.
+    let distribution = setup.pool.get_liquidity_distribution(&-3, &3, &100);
+    assert_eq!(distribution.len(), 6);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2018)")]
+fn test_liquidity_distribution_zero_points() {
+    let setup = Setup::default();
+    setup.pool.get_liquidity_distribution(&-10, &10, &0);
+}
+
+#[test]
+fn test_get_depth_moves_price_by_bps() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(10_000_0000000, 10_000_0000000);
+    setup.pool.deposit(
+        &setup.user,
+        &Vec::from_array(&setup.env, [1_000_0000000u128, 1_000_0000000u128]),
+        &0,
+    );
+
+    // 1% down: log_1.0001(0.99) ≈ -100.5 → tick -101.
+    let depth = setup.pool.get_depth(&100);
+    let amount0_in = depth.get_unchecked(0);
+    let amount1_in = depth.get_unchecked(1);
+    assert!(amount0_in > 0 && amount1_in > 0);
+    setup.pool.swap(&setup.user, &0, &1, &amount0_in, &0);
+    assert_eq!(setup.pool.get_slot0().tick, -101);
+
+    // Depth is measured from the current price: 1% up from tick -101 lands at tick -2.
+    let amount1_in = setup.pool.get_depth(&100).get_unchecked(1);
+    setup.pool.swap(&setup.user, &1, &0, &amount1_in, &0);
+    assert_eq!(setup.pool.get_slot0().tick, -2);
+}
+
+#[test]
+fn test_get_depth_limited_by_liquidity() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(1_000_0000000, 1_000_0000000);
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    setup
+        .pool
+        .deposit_position(&setup.user, &-10, &10, &amounts, &0);
+
+    // 10% exceeds the position range: depth equals everything the range can absorb.
+    let depth_narrow = setup.pool.get_depth(&5);
+    let depth_wide = setup.pool.get_depth(&1000);
+    assert!(depth_wide.get_unchecked(0) > depth_narrow.get_unchecked(0));
+    assert_eq!(depth_wide, setup.pool.get_depth(&2000));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Position rebalancing
+// ═══════════════════════════════════════════════════════════════════════════
+
+// Deep full-range liquidity from a second LP so the internal swap has a counterparty.
+fn seed_full_range_lp(setup: &Setup<'_>) -> Address {
+    let lp = Address::generate(&setup.env);
+    get_token_admin_client(&setup.env, &setup.token0.address).mint(&lp, &10_000_0000000);
+    get_token_admin_client(&setup.env, &setup.token1.address).mint(&lp, &10_000_0000000);
+    setup.pool.deposit(
+        &lp,
+        &Vec::from_array(&setup.env, [10_000_0000000u128, 10_000_0000000u128]),
+        &0,
+    );
+    lp
+}
+
+#[test]
+fn test_rebalance_position_moves_liquidity() {
+    let setup = Setup::default();
+    seed_full_range_lp(&setup);
+    setup.mint_user_tokens(10_0000000, 10_0000000);
+
+    let amounts = Vec::from_array(&setup.env, [10_0000000u128, 10_0000000u128]);
+    let (_, old_liquidity) = setup
+        .pool
+        .deposit_position(&setup.user, &-100, &100, &amounts, &0);
+    let raw_before = setup.pool.get_total_raw_liquidity();
+
+    // New range above price needs token0 only: token1 side is swapped inside the pool.
+    let (deposited, liquidity) =
+        setup
+            .pool
+            .rebalance_position(&setup.user, &-100, &100, &100, &300, &100);
+    assert!(liquidity > 0);
+    assert!(deposited.get_unchecked(0) > 0);
+    assert_eq!(deposited.get_unchecked(1), 0);
+
+    assert!(setup
+        .pool
+        .try_get_position(&setup.user, &-100, &100)
+        .is_err());
+    assert_eq!(
+        setup.pool.get_position(&setup.user, &100, &300).liquidity,
+        liquidity
+    );
+    assert_eq!(setup.pool.get_tick(&-100).liquidity_gross, 0);
+    assert_eq!(setup.pool.get_tick(&100).liquidity_net, liquidity as i128);
+    assert_eq!(
+        setup.pool.get_user_position_snapshot(&setup.user).ranges,
+        Vec::from_array(
+            &setup.env,
+            [crate::types::PositionRange {
+                tick_lower: 100,
+                tick_upper: 300,
+            }]
+        )
+    );
+    assert_eq!(
+        setup.pool.get_total_raw_liquidity(),
+        raw_before - old_liquidity + liquidity
+    );
+
+    // Only dust that didn't fit the new range comes back to the owner.
+    assert!(setup.token0.balance(&setup.user) < 1_0000000);
+    assert!(setup.token1.balance(&setup.user) < 1_0000000);
+
+    // Reserves still match the pool balance minus protocol fees.
+    let protocol_fees = setup.pool.get_protocol_fees();
+    let reserves = setup.pool.get_reserves();
+    assert_eq!(
+        reserves.get_unchecked(0) + protocol_fees.get_unchecked(0),
+        setup.token0.balance(&setup.pool.address) as u128
+    );
+    assert_eq!(
+        reserves.get_unchecked(1) + protocol_fees.get_unchecked(1),
+        setup.token1.balance(&setup.pool.address) as u128
+    );
+}
+
+#[test]
+fn test_rebalance_position_compounds_fees() {
+    let setup = Setup::default();
+    seed_full_range_lp(&setup);
+    setup.mint_user_tokens(1_000_0000000, 1_000_0000000);
+
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    setup
+        .pool
+        .deposit_position(&setup.user, &-100, &100, &amounts, &0);
+
+    let swapper = Address::generate(&setup.env);
+    get_token_admin_client(&setup.env, &setup.token0.address).mint(&swapper, &100_0000000);
+    get_token_admin_client(&setup.env, &setup.token1.address).mint(&swapper, &100_0000000);
+    setup.pool.swap(&swapper, &0, &1, &100_0000000, &0);
+    setup.pool.swap(&swapper, &1, &0, &100_0000000, &0);
+
+    let (fees0, fees1) = pair(setup.pool.get_position_fees(&setup.user, &-100, &100));
+    assert!(fees0 > 0 && fees1 > 0);
+
+    setup
+        .pool
+        .rebalance_position(&setup.user, &-100, &100, &-200, &200, &500);
+
+    assert_claim_fees_event(
+        &setup.env,
+        &setup.pool.address,
+        &setup.user,
+        &setup.token0.address,
+        &setup.token1.address,
+        fees0,
+        fees1,
+    );
+    let position = setup.pool.get_position(&setup.user, &-200, &200);
+    assert_eq!(position.tokens_owed_0, 0);
+    assert_eq!(position.tokens_owed_1, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2006)")]
+fn test_rebalance_position_slippage_exceeded() {
+    let setup = Setup::default();
+    seed_full_range_lp(&setup);
+    setup.mint_user_tokens(100_0000000, 100_0000000);
+
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    setup
+        .pool
+        .deposit_position(&setup.user, &-100, &100, &amounts, &0);
+
+    // Zero tolerance can't absorb the 0.3% swap fee.
+    setup
+        .pool
+        .rebalance_position(&setup.user, &-100, &100, &100, &300, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2101)")]
+fn test_rebalance_position_same_range() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(100_0000000, 100_0000000);
+
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    setup
+        .pool
+        .deposit_position(&setup.user, &-100, &100, &amounts, &0);
+    setup
+        .pool
+        .rebalance_position(&setup.user, &-100, &100, &-100, &100, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2124)")]
+fn test_rebalance_position_invalid_slippage() {
+    let setup = Setup::default();
+    setup
+        .pool
+        .rebalance_position(&setup.user, &-100, &100, &-200, &200, &10_001);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee compounding
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn next_ledger(e: &Env) {
+    e.ledger().with_mut(|li| li.sequence_number += 1);
+}
+
+// Position [-100, 100] for setup.user with fees accrued on both sides.
+fn setup_position_with_fees(setup: &Setup<'_>) {
+    seed_full_range_lp(setup);
+    setup.mint_user_tokens(100_0000000, 100_0000000);
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    setup
+        .pool
+        .deposit_position(&setup.user, &-100, &100, &amounts, &0);
+
+    let swapper = Address::generate(&setup.env);
+    get_token_admin_client(&setup.env, &setup.token0.address).mint(&swapper, &100_0000000);
+    get_token_admin_client(&setup.env, &setup.token1.address).mint(&swapper, &100_0000000);
+    setup.pool.swap(&swapper, &0, &1, &100_0000000, &0);
+    setup.pool.swap(&swapper, &1, &0, &100_0000000, &0);
+    // compound in a later ledger than the swaps
+    next_ledger(&setup.env);
+}
+
+fn assert_reserves_match_balances(setup: &Setup<'_>) {
+    let protocol_fees = setup.pool.get_protocol_fees();
+    let reserves = setup.pool.get_reserves();
+    assert_eq!(
+        reserves.get_unchecked(0) + protocol_fees.get_unchecked(0),
+        setup.token0.balance(&setup.pool.address) as u128
+    );
+    assert_eq!(
+        reserves.get_unchecked(1) + protocol_fees.get_unchecked(1),
+        setup.token1.balance(&setup.pool.address) as u128
+    );
+}
+
+#[test]
+fn test_compound_position_reinvests_fees() {
+    let setup = Setup::default();
+    setup_position_with_fees(&setup);
+
+    let liquidity_before = setup.pool.get_position(&setup.user, &-100, &100).liquidity;
+    let (fees0, fees1) = pair(setup.pool.get_position_fees(&setup.user, &-100, &100));
+    assert!(fees0 > 0 && fees1 > 0);
+    let balance0 = setup.token0.balance(&setup.user);
+    let balance1 = setup.token1.balance(&setup.user);
+
+    let (deposited, liquidity) = setup.pool.compound_position(&setup.user, &-100, &100, &1);
+    assert!(liquidity > 0);
+    assert!(deposited.get_unchecked(0) > 0 && deposited.get_unchecked(1) > 0);
+    assert_eq!(
+        count_events(&setup.env, &setup.pool.address, "compound_fees"),
+        1
+    );
+    assert_eq!(count_claim_fees_events(&setup.env, &setup.pool.address), 0);
+
+    let position = setup.pool.get_position(&setup.user, &-100, &100);
+    assert_eq!(position.liquidity, liquidity_before + liquidity);
+    // Only the part that didn't fit the range stays owed.
+    assert!(position.tokens_owed_0 < fees0 / 10);
+    assert!(position.tokens_owed_1 < fees1 / 10);
+    // Nothing is paid out to the owner.
+    assert_eq!(setup.token0.balance(&setup.user), balance0);
+    assert_eq!(setup.token1.balance(&setup.user), balance1);
+    assert_eq!(
+        setup
+            .pool
+            .get_user_position_snapshot(&setup.user)
+            .raw_liquidity,
+        liquidity_before + liquidity
+    );
+    assert_reserves_match_balances(&setup);
+}
+
+#[test]
+fn test_compound_position_single_sided_fees_swapped() {
+    let setup = Setup::default();
+    seed_full_range_lp(&setup);
+    setup.mint_user_tokens(100_0000000, 100_0000000);
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    setup
+        .pool
+        .deposit_position(&setup.user, &-100, &100, &amounts, &0);
+
+    // Only token0 -> token1 swaps: fees accrue in token0 alone.
+    let swapper = Address::generate(&setup.env);
+    get_token_admin_client(&setup.env, &setup.token0.address).mint(&swapper, &100_0000000);
+    setup.pool.swap(&swapper, &0, &1, &100_0000000, &0);
+    let (fees0, fees1) = pair(setup.pool.get_position_fees(&setup.user, &-100, &100));
+    assert!(fees0 > 0);
+    assert_eq!(fees1, 0);
+
+    let (deposited, liquidity) = setup.pool.compound_position(&setup.user, &-100, &100, &1);
+    assert!(liquidity > 0);
+    assert!(deposited.get_unchecked(1) > 0);
+    assert_reserves_match_balances(&setup);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2006)")]
+fn test_compound_position_min_liquidity_not_met() {
+    let setup = Setup::default();
+    setup_position_with_fees(&setup);
+    setup
+        .pool
+        .compound_position(&setup.user, &-100, &100, &u128::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2126)")]
+fn test_compound_position_nothing_to_compound() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(100_0000000, 100_0000000);
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    setup
+        .pool
+        .deposit_position(&setup.user, &-100, &100, &amounts, &0);
+    setup.pool.compound_position(&setup.user, &-100, &100, &0);
+}
+
+#[test]
+fn test_compound_position_keeper_bounty() {
+    let setup = Setup::default();
+    setup_position_with_fees(&setup);
+    let keeper = Address::generate(&setup.env);
+
+    assert!(!setup.pool.get_auto_compound(&setup.user));
+    assert!(setup
+        .pool
+        .try_compound_position_for(&keeper, &setup.user, &-100, &100)
+        .is_err());
+
+    setup.pool.set_auto_compound(&setup.user, &true);
+    assert!(setup.pool.get_auto_compound(&setup.user));
+    setup.pool.set_compound_bounty_fraction(&setup.admin, &100);
+
+    let (fees0, fees1) = pair(setup.pool.get_position_fees(&setup.user, &-100, &100));
+    let (_, liquidity) = setup
+        .pool
+        .compound_position_for(&keeper, &setup.user, &-100, &100);
+    assert!(liquidity > 0);
+    assert_eq!(setup.token0.balance(&keeper) as u128, fees0 / 100);
+    assert_eq!(setup.token1.balance(&keeper) as u128, fees1 / 100);
+    assert_reserves_match_balances(&setup);
+
+    // Opting out blocks keepers again.
+    setup.pool.set_auto_compound(&setup.user, &false);
+    assert!(setup
+        .pool
+        .try_compound_position_for(&keeper, &setup.user, &-100, &100)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2125)")]
+fn test_compound_position_for_not_opted_in() {
+    let setup = Setup::default();
+    setup_position_with_fees(&setup);
+    let keeper = Address::generate(&setup.env);
+    setup
+        .pool
+        .compound_position_for(&keeper, &setup.user, &-100, &100);
+}
+
+#[test]
+fn test_compound_position_for_within_max_slippage() {
+    let setup = Setup::default();
+    setup_position_with_fees(&setup);
+    let keeper = Address::generate(&setup.env);
+    setup.pool.set_auto_compound(&setup.user, &true);
+    assert_eq!(setup.pool.get_compound_max_slippage(), 100);
+
+    let (_, liquidity) = setup
+        .pool
+        .compound_position_for(&keeper, &setup.user, &-100, &100);
+    assert!(liquidity > 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2006)")]
+fn test_compound_position_for_max_slippage_exceeded() {
+    let setup = Setup::default();
+    setup_position_with_fees(&setup);
+    let keeper = Address::generate(&setup.env);
+    setup.pool.set_auto_compound(&setup.user, &true);
+
+    // the swap moves the price, so its output falls short of the spot quote beyond the fee
+    setup.pool.set_compound_max_slippage(&setup.admin, &0);
+    setup
+        .pool
+        .compound_position_for(&keeper, &setup.user, &-100, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2129)")]
+fn test_compound_position_for_price_moved_in_ledger() {
+    let setup = Setup::default();
+    setup_position_with_fees(&setup);
+    let keeper = Address::generate(&setup.env);
+    setup.pool.set_auto_compound(&setup.user, &true);
+
+    // the keeper pushes the price away right before compounding
+    get_token_admin_client(&setup.env, &setup.token0.address).mint(&keeper, &500_0000000);
+    setup.pool.swap(&keeper, &0, &1, &500_0000000, &0);
+    setup
+        .pool
+        .compound_position_for(&keeper, &setup.user, &-100, &100);
+}
+
+#[test]
+fn test_compound_position_for_price_moved_in_previous_ledger() {
+    let setup = Setup::default();
+    setup_position_with_fees(&setup);
+    let keeper = Address::generate(&setup.env);
+    setup.pool.set_auto_compound(&setup.user, &true);
+
+    let swapper = Address::generate(&setup.env);
+    get_token_admin_client(&setup.env, &setup.token0.address).mint(&swapper, &500_0000000);
+    setup.pool.swap(&swapper, &0, &1, &500_0000000, &0);
+    assert!(setup
+        .pool
+        .try_compound_position_for(&keeper, &setup.user, &-100, &100)
+        .is_err());
+
+    // the moved price is the reference once the ledger closes
+    next_ledger(&setup.env);
+    let (_, liquidity) = setup
+        .pool
+        .compound_position_for(&keeper, &setup.user, &-100, &100);
+    assert!(liquidity > 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2129)")]
+fn test_compound_position_for_opt_in_after_price_moved() {
+    let setup = Setup::default();
+    setup_position_with_fees(&setup);
+    let keeper = Address::generate(&setup.env);
+
+    // the price moves while nobody is opted in
+    next_ledger(&setup.env);
+    get_token_admin_client(&setup.env, &setup.token0.address).mint(&keeper, &500_0000000);
+    setup.pool.swap(&keeper, &0, &1, &500_0000000, &0);
+
+    // opting in later in the same ledger doesn't make the moved price the reference
+    setup.pool.set_auto_compound(&setup.user, &true);
+    setup
+        .pool
+        .compound_position_for(&keeper, &setup.user, &-100, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2124)")]
+fn test_compound_max_slippage_above_cap() {
+    let setup = Setup::default();
+    setup.pool.set_compound_max_slippage(&setup.admin, &1_001);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2003)")]
+fn test_compound_bounty_fraction_above_cap() {
+    let setup = Setup::default();
+    setup.pool.set_compound_bounty_fraction(&setup.admin, &501);
+}
+
+#[test]
+fn test_rewards_max_distance_override() {
+    let setup = Setup::default();
+    seed_full_range_lp(&setup);
+    setup.mint_user_tokens(100_0000000, 100_0000000);
+    assert_eq!(
+        setup.pool.get_rewards_max_distance(),
+        rewards::concentrated_weight::max_distance_for_fee(setup.pool.get_fee_fraction())
+    );
+
+    // 600 ticks above price: still inside the default decay window.
+    let amounts = Vec::from_array(&setup.env, [10_0000000u128, 0u128]);
+    setup
+        .pool
+        .deposit_position(&setup.user, &600, &700, &amounts, &0);
+    assert!(
+        setup
+            .pool
+            .get_user_position_snapshot(&setup.user)
+            .weighted_liquidity
+            > 0
+    );
+
+    setup.pool.set_rewards_max_distance(&setup.router, &600);
+    assert_eq!(
+        count_events(&setup.env, &setup.pool.address, "set_rewards_max_distance"),
+        1
+    );
+    assert_eq!(setup.pool.get_rewards_max_distance(), 600);
+    setup
+        .pool
+        .deposit_position(&setup.user, &600, &700, &amounts, &0);
+    assert_eq!(
+        setup
+            .pool
+            .get_user_position_snapshot(&setup.user)
+            .weighted_liquidity,
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2128)")]
+fn test_rewards_max_distance_zero() {
+    let setup = Setup::default();
+    setup.pool.set_rewards_max_distance(&setup.router, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2128)")]
+fn test_rewards_max_distance_above_tick_range() {
+    let setup = Setup::default();
+    setup.pool.set_rewards_max_distance(
+        &setup.router,
+        &(rewards::concentrated_weight::MAX_DISTANCE_LIMIT + 1),
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Explicit price initialization
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_initialize_price_allows_one_sided_first_deposit() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(100_0000000, 0);
+
+    let price = crate::math::sqrt_ratio_at_tick(&setup.env, 6931);
+    setup.pool.initialize_price(&setup.router, &price);
+    assert_eq!(
+        count_events(&setup.env, &setup.pool.address, "pool_state"),
+        1
+    );
+    let slot = setup.pool.get_slot0();
+    assert_eq!(slot.sqrt_price_x96, price);
+    assert_eq!(slot.tick, 6931);
+
+    // Range fully above price: only token0 is needed and price is not re-derived.
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 0u128]);
+    let (estimated, estimated_liq) = setup.pool.estimate_deposit_position(&8000, &9000, &amounts);
+    let (actual, liq) = setup
+        .pool
+        .deposit_position(&setup.user, &8000, &9000, &amounts, &0);
+    assert!(liq > 0);
+    assert_eq!(liq, estimated_liq);
+    assert_eq!(actual, estimated);
+    assert_eq!(actual.get_unchecked(1), 0);
+    assert_eq!(setup.pool.get_slot0().tick, 6931);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2127)")]
+fn test_initialize_price_twice() {
+    let setup = Setup::default();
+    setup.pool.initialize_price(
+        &setup.router,
+        &crate::math::sqrt_ratio_at_tick(&setup.env, 0),
+    );
+    setup.pool.initialize_price(
+        &setup.router,
+        &crate::math::sqrt_ratio_at_tick(&setup.env, 100),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2127)")]
+fn test_initialize_price_after_deposit() {
+    let setup = Setup::default();
+    setup.mint_user_tokens(100_0000000, 100_0000000);
+    let amounts = Vec::from_array(&setup.env, [100_0000000u128, 100_0000000u128]);
+    setup
+        .pool
+        .deposit_position(&setup.user, &-100, &100, &amounts, &0);
+
+    setup.pool.initialize_price(
+        &setup.router,
+        &crate::math::sqrt_ratio_at_tick(&setup.env, 100),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2104)")]
+fn test_initialize_price_out_of_bounds() {
+    let setup = Setup::default();
+    setup
+        .pool
+        .initialize_price(&setup.router, &crate::math::max_sqrt_ratio(&setup.env));
+}
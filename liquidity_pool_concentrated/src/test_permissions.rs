@@ -384,6 +384,137 @@ fn test_set_privileged_addrs() {
 // Protocol fee permissions
 // ═══════════════════════════════════════════════════════════════════════════
 
+#[test]
+fn test_set_rewards_max_distance_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+
+    assert!(setup
+        .pool
+        .try_set_rewards_max_distance(&setup.router, &300)
+        .is_ok());
+    assert_eq!(setup.pool.get_rewards_max_distance(), 300);
+
+    for addr in [
+        user,
+        setup.admin,
+        setup.rewards_admin,
+        setup.operations_admin,
+    ] {
+        assert!(setup
+            .pool
+            .try_set_rewards_max_distance(&addr, &400)
+            .is_err());
+    }
+}
+
+#[test]
+fn test_set_rewards_vesting_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+
+    assert!(setup
+        .pool
+        .try_set_rewards_vesting(&setup.rewards_admin, &10, &100, &5000)
+        .is_ok());
+    assert!(setup
+        .pool
+        .try_set_rewards_vesting(&setup.admin, &0, &0, &0)
+        .is_ok());
+
+    assert!(setup
+        .pool
+        .try_set_rewards_vesting(&user, &10, &100, &5000)
+        .is_err());
+    assert!(setup
+        .pool
+        .try_set_rewards_vesting(&setup.operations_admin, &10, &100, &5000)
+        .is_err());
+}
+
+#[test]
+fn test_set_rewards_history_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+
+    assert!(setup
+        .pool
+        .try_set_rewards_history(&setup.rewards_admin, &true)
+        .is_ok());
+    assert!(setup
+        .pool
+        .try_set_rewards_history(&setup.admin, &false)
+        .is_ok());
+
+    assert!(setup.pool.try_set_rewards_history(&user, &true).is_err());
+    assert!(setup
+        .pool
+        .try_set_rewards_history(&setup.operations_admin, &true)
+        .is_err());
+}
+
+#[test]
+fn test_reward_tokens_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+    let token = Address::generate(&setup.env);
+    let expired_at = setup.env.ledger().timestamp().saturating_add(10);
+    setup
+        .pool
+        .initialize_rewards_config(&setup.reward_token.address);
+
+    assert!(setup.pool.try_add_reward_token(&user, &token).is_err());
+    assert!(setup
+        .pool
+        .try_add_reward_token(&setup.operations_admin, &token)
+        .is_err());
+    assert!(setup
+        .pool
+        .try_add_reward_token(&setup.rewards_admin, &token)
+        .is_ok());
+
+    assert!(setup
+        .pool
+        .try_set_token_rewards_config(&user, &token, &expired_at, &1)
+        .is_err());
+    assert!(setup
+        .pool
+        .try_set_token_rewards_config(&setup.operations_admin, &token, &expired_at, &1)
+        .is_err());
+    assert!(setup
+        .pool
+        .try_set_token_rewards_config(&setup.admin, &token, &expired_at, &1)
+        .is_ok());
+}
+
+#[test]
+fn test_boost_config_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+
+    assert!(setup.pool.try_commit_boost_config(&user, &30_000).is_err());
+    assert!(setup
+        .pool
+        .try_commit_boost_config(&setup.rewards_admin, &30_000)
+        .is_err());
+    assert!(setup
+        .pool
+        .try_commit_boost_config(&setup.admin, &30_000)
+        .is_ok());
+
+    assert!(setup.pool.try_revert_boost_config(&user).is_err());
+    assert!(setup.pool.try_revert_boost_config(&setup.admin).is_ok());
+
+    assert!(setup
+        .pool
+        .try_set_boost_lock_records(&setup.rewards_admin, &true)
+        .is_err());
+    assert!(setup
+        .pool
+        .try_set_boost_lock_records(&setup.admin, &true)
+        .is_ok());
+}
+
 #[test]
 fn test_set_protocol_fee_fraction_permissions() {
     let setup = Setup::default();
@@ -414,6 +545,80 @@ fn test_set_protocol_fee_fraction_permissions() {
         .is_err());
 }
 
+#[test]
+fn test_set_compound_bounty_fraction_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+
+    assert!(setup
+        .pool
+        .try_set_compound_bounty_fraction(&setup.operations_admin, &100)
+        .is_ok());
+    assert!(setup
+        .pool
+        .try_set_compound_bounty_fraction(&setup.admin, &200)
+        .is_ok());
+    assert_eq!(setup.pool.get_compound_bounty_fraction(), 200);
+
+    assert!(setup
+        .pool
+        .try_set_compound_bounty_fraction(&user, &300)
+        .is_err());
+    assert!(setup
+        .pool
+        .try_set_compound_bounty_fraction(&setup.pause_admin, &300)
+        .is_err());
+}
+
+#[test]
+fn test_set_compound_max_slippage_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+
+    assert!(setup
+        .pool
+        .try_set_compound_max_slippage(&setup.operations_admin, &200)
+        .is_ok());
+    assert!(setup
+        .pool
+        .try_set_compound_max_slippage(&setup.admin, &300)
+        .is_ok());
+    assert_eq!(setup.pool.get_compound_max_slippage(), 300);
+
+    assert!(setup
+        .pool
+        .try_set_compound_max_slippage(&user, &400)
+        .is_err());
+    assert!(setup
+        .pool
+        .try_set_compound_max_slippage(&setup.pause_admin, &400)
+        .is_err());
+}
+
+#[test]
+fn test_initialize_price_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+    let price = crate::math::sqrt_ratio_at_tick(&setup.env, 100);
+
+    assert!(setup.pool.try_initialize_price(&user, &price).is_err());
+    assert!(setup
+        .pool
+        .try_initialize_price(&setup.operations_admin, &price)
+        .is_err());
+    assert!(setup
+        .pool
+        .try_initialize_price(&setup.router, &price)
+        .is_ok());
+
+    let setup = Setup::default();
+    let price = crate::math::sqrt_ratio_at_tick(&setup.env, 100);
+    assert!(setup
+        .pool
+        .try_initialize_price(&setup.admin, &price)
+        .is_ok());
+}
+
 #[test]
 fn test_claim_protocol_fees_permissions() {
     let setup = Setup::default();
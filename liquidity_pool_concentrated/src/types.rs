@@ -10,6 +10,15 @@ pub struct Slot0 {
     pub tick: i32,
 }
 
+// Pool price before the first swap of a ledger. Stored in instance storage
+// (DataKey::LedgerStartPrice); a record from an older ledger is stale.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LedgerStartPrice {
+    pub ledger: u32,
+    pub sqrt_price_x96: U256,
+}
+
 // Per-position state. Stored in persistent storage keyed by (owner, tick_lower, tick_upper).
 // fee_growth_inside_*_last = snapshot of cumulative fee growth inside the range at last interaction.
 // tokens_owed = uncollected fees + withdrawn tokens pending claim_position_fees.
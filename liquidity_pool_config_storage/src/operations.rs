@@ -28,6 +28,15 @@ pub fn get_value(e: &Env, key: Val) -> Val {
     }
 }
 
+// Timestamp of the last write to `key`, 0 if it was never set. Used for timelocked values.
+pub fn get_value_updated_at(e: &Env, key: Val) -> u64 {
+    e.invoke_contract(
+        &storage::get_config_storage(e),
+        &Symbol::new(e, "get_value_updated_at"),
+        Vec::from_array(e, [key]),
+    )
+}
+
 pub fn set_value(e: &Env, admin: &Address, key: Val, value: Val) {
     e.invoke_contract::<Val>(
         &storage::get_config_storage(e),
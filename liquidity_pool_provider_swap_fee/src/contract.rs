@@ -1,16 +1,207 @@
 use crate::errors::Error;
-use crate::events::{Events, ProviderFeeEvents};
+use crate::events::{Events, ProviderConfigEvents, ProviderFeeEvents};
 use crate::interface::ProviderSwapFeeInterface;
 use crate::storage::{
-    get_fee_denominator, get_fee_destination, get_max_swap_fee_fraction, get_operator, get_router,
-    set_fee_denominator, set_fee_destination, set_max_swap_fee_fraction, set_operator, set_router,
+    get_accrued_fee, get_fee_denominator, get_fee_destination, get_fee_recipients,
+    get_future_config, get_future_fee_recipients, get_max_swap_fee_fraction, get_operator,
+    get_router, get_total_accrued_fee, set_accrued_fee, set_fee_denominator, set_fee_destination,
+    set_fee_recipients, set_future_config, set_future_fee_recipients, set_max_swap_fee_fraction,
+    set_operator, set_router, set_total_accrued_fee, DataKey, ProviderConfig,
+    FEE_SHARE_DENOMINATOR, MAX_FEE_RECIPIENTS,
+};
+use access_control::access::AccessControl;
+use access_control::errors::AccessControlError;
+use access_control::events::Events as AccessControlEvents;
+use access_control::interface::TransferableContract;
+use access_control::management::SingleAddressManagementTrait;
+use access_control::role::{Role, SymbolRepresentation};
+use access_control::transfer::{
+    apply_delayed_action, commit_delayed_action, get_delayed_action_deadline,
+    revert_delayed_action, TransferOwnershipTrait,
 };
 use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
 use soroban_sdk::token::Client as SorobanTokenClient;
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, vec, Address, BytesN, Env, IntoVal, Symbol, Vec,
+    contract, contractimpl, panic_with_error, vec, Address, BytesN, Env, IntoVal, Symbol, Val, Vec,
 };
+use upgrade::events::Events as UpgradeEvents;
+use upgrade::{apply_upgrade, commit_upgrade, revert_upgrade};
+use utils::storage_errors::StorageError;
+
+fn require_operator(e: &Env, operator: &Address) {
+    operator.require_auth();
+    if operator != &get_operator(e) {
+        panic_with_error!(e, Error::Unauthorized)
+    }
+}
+
+// Balance of the fee token not yet accrued to any recipient.
+fn get_unaccrued_fee(e: &Env, token: &Address) -> u128 {
+    SorobanTokenClient::new(e, token).balance(&e.current_contract_address()) as u128
+        - get_total_accrued_fee(e, token)
+}
+
+// Splits the fee amount between the current recipients by their shares.
+// The last recipient takes the remainder left by rounding.
+fn split_fee(e: &Env, amount: u128) -> Vec<(Address, u128)> {
+    let recipients = get_fee_recipients(e);
+    let mut result = Vec::new(e);
+    let mut split = 0;
+    for (i, (recipient, share)) in recipients.iter().enumerate() {
+        let recipient_amount = if i as u32 == recipients.len() - 1 {
+            amount - split
+        } else {
+            amount * share as u128 / FEE_SHARE_DENOMINATOR as u128
+        };
+        split += recipient_amount;
+        if recipient_amount > 0 {
+            result.push_back((recipient, recipient_amount));
+        }
+    }
+    result
+}
+
+// Transfers the fee amount to the current recipients by their shares.
+fn distribute_fee(e: &Env, token: &Address, amount: u128) {
+    if amount == 0 {
+        return;
+    }
+    let token_client = SorobanTokenClient::new(e, token);
+    for (recipient, recipient_amount) in split_fee(e, amount) {
+        token_client.transfer(
+            &e.current_contract_address(),
+            &recipient,
+            &(recipient_amount as i128),
+        );
+        Events::new(e).distribute_fee(recipient, token.clone(), recipient_amount);
+    }
+}
+
+// Credits the fee amount to the current recipients by their shares. Nothing is transferred:
+// every recipient withdraws its own part with claim_fee_share.
+fn accrue_fee(e: &Env, token: &Address, amount: u128) {
+    if amount == 0 {
+        return;
+    }
+    for (recipient, recipient_amount) in split_fee(e, amount) {
+        set_accrued_fee(
+            e,
+            &recipient,
+            token,
+            &(get_accrued_fee(e, &recipient, token) + recipient_amount),
+        );
+        Events::new(e).accrue_fee(recipient, token.clone(), recipient_amount);
+    }
+    set_total_accrued_fee(e, token, &(get_total_accrued_fee(e, token) + amount));
+}
+
+fn get_fee_amount(e: &Env, amount: u128, fee_fraction: u32) -> u128 {
+    amount.fixed_mul_ceil(
+        e,
+        &(fee_fraction as u128),
+        &(get_fee_denominator(e) as u128),
+    )
+}
+
+// Fee on the part of a deposit the pool actually took. `deposited` is what is left of a gross
+// amount once the fee is taken, so the fee is deposited * fraction / (denominator - fraction).
+fn get_deposit_fee_amount(e: &Env, deposited: u128, fee_fraction: u32) -> u128 {
+    if deposited == 0 {
+        return 0;
+    }
+    deposited.fixed_mul_ceil(
+        e,
+        &(fee_fraction as u128),
+        &((get_fee_denominator(e) - fee_fraction) as u128),
+    )
+}
+
+fn get_pool_address(
+    e: &Env,
+    router: &Address,
+    tokens: &Vec<Address>,
+    pool_index: &BytesN<32>,
+) -> Address {
+    e.invoke_contract(
+        router,
+        &Symbol::new(e, "get_pool"),
+        Vec::from_array(e, [tokens.to_val(), pool_index.to_val()]),
+    )
+}
+
+// Concentrated pools have no share token (share_id returns the pool itself),
+// so liquidity can't be moved through them by share amounts.
+fn require_share_pool(e: &Env, router: &Address, tokens: &Vec<Address>, pool_index: &BytesN<32>) {
+    let pool_type: Symbol = e.invoke_contract(
+        router,
+        &Symbol::new(e, "pool_type"),
+        Vec::from_array(e, [tokens.to_val(), pool_index.to_val()]),
+    );
+    if pool_type == Symbol::new(e, "concentrated") {
+        panic_with_error!(e, Error::InvalidPoolType);
+    }
+}
+
+fn get_share_token(e: &Env, pool: &Address) -> Address {
+    e.invoke_contract(pool, &Symbol::new(e, "share_id"), Vec::new(e))
+}
+
+fn token_call_auth(
+    e: &Env,
+    token: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+) -> InvokerContractAuthEntry {
+    InvokerContractAuthEntry::Contract(SubContractInvocation {
+        context: ContractContext {
+            contract: token.clone(),
+            fn_name: Symbol::new(e, fn_name),
+            args,
+        },
+        sub_invocations: vec![e],
+    })
+}
+
+// Router passes the current contract as user to the pool, which requires its authorization
+// along with the nested token calls.
+fn authorize_pool_call(
+    e: &Env,
+    pool: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+    sub_invocations: Vec<InvokerContractAuthEntry>,
+) {
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: pool.clone(),
+                fn_name: Symbol::new(e, fn_name),
+                args,
+            },
+            sub_invocations,
+        }),
+    ]);
+}
+
+fn validate_fee_recipients(e: &Env, recipients: &Vec<(Address, u32)>) {
+    if recipients.is_empty() || recipients.len() > MAX_FEE_RECIPIENTS {
+        panic_with_error!(e, Error::InvalidFeeRecipients);
+    }
+    let mut addresses: Vec<Address> = Vec::new(e);
+    let mut total_share = 0;
+    for (recipient, share) in recipients.iter() {
+        if share == 0 || addresses.contains(&recipient) {
+            panic_with_error!(e, Error::InvalidFeeRecipients);
+        }
+        addresses.push_back(recipient);
+        total_share += share as u64;
+    }
+    if total_share != FEE_SHARE_DENOMINATOR as u64 {
+        panic_with_error!(e, Error::InvalidFeeRecipients);
+    }
+}
 
 #[contract]
 pub struct ProviderSwapFeeCollector;
@@ -66,7 +257,8 @@ impl ProviderSwapFeeCollector {
     }
 
     // get_fee_destination
-    // Returns the address where fees are sent.
+    // Returns the address receiving the whole fee until fee recipients are configured.
+    // Changed with commit_config / apply_config.
     //
     // Arguments:
     //   - e: The Soroban environment.
@@ -78,7 +270,8 @@ impl ProviderSwapFeeCollector {
     }
 
     // claim_fees
-    // Claims all fees held by the contract and transfers them to the specified address.
+    // Distributes all fees held by the contract and not accrued yet to the fee recipients
+    // pro-rata. Fees already accrued stay claimable with claim_fee_share.
     //
     // Arguments:
     //   - e: The Soroban environment.
@@ -86,25 +279,59 @@ impl ProviderSwapFeeCollector {
     //   - token: The token contract address for which fees are claimed.
     //
     // Returns:
-    //   - A u128 value representing the claimed token amount.
+    //   - A u128 value representing the distributed token amount.
     pub fn claim_fees(e: Env, operator: Address, token: Address) -> u128 {
-        operator.require_auth();
-        if operator != get_operator(&e) {
-            panic_with_error!(&e, Error::Unauthorized)
+        require_operator(&e, &operator);
+        let amount = get_unaccrued_fee(&e, &token);
+        distribute_fee(&e, &token, amount);
+        Events::new(&e).claim_fee(token.clone(), amount, token, amount);
+        amount
+    }
+
+    // claim_fee_share
+    // Withdraws the fee of the token accrued to the recipient, including its share of fees
+    // not accrued yet, without waiting for the operator to call claim_fees.
+    // Former recipients keep access to what was accrued to them.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - recipient: The fee recipient address (must be authorized).
+    //   - token: The token contract address for which fees are claimed.
+    //
+    // Returns:
+    //   - A u128 value representing the token amount sent to the recipient.
+    pub fn claim_fee_share(e: Env, recipient: Address, token: Address) -> u128 {
+        recipient.require_auth();
+        let unaccrued = get_unaccrued_fee(&e, &token);
+        if unaccrued > 0 {
+            accrue_fee(&e, &token, unaccrued);
+            Events::new(&e).claim_fee(token.clone(), unaccrued, token.clone(), unaccrued);
         }
-        let token_client = SorobanTokenClient::new(&e, &token);
-        let amount = token_client.balance(&e.current_contract_address());
-        token_client.transfer(
+
+        let amount = get_accrued_fee(&e, &recipient, &token);
+        if amount == 0 {
+            return 0;
+        }
+        set_accrued_fee(&e, &recipient, &token, &0);
+        set_total_accrued_fee(&e, &token, &(get_total_accrued_fee(&e, &token) - amount));
+        SorobanTokenClient::new(&e, &token).transfer(
             &e.current_contract_address(),
-            &get_fee_destination(&e),
-            &amount,
+            &recipient,
+            &(amount as i128),
         );
-        Events::new(&e).claim_fee(token.clone(), amount as u128, token, amount as u128);
-        amount as u128
+        Events::new(&e).claim_fee_share(recipient, token, amount);
+        amount
+    }
+
+    // get_accrued_fee
+    // Returns the fee of the token accrued to the recipient and not withdrawn yet.
+    pub fn get_accrued_fee(e: Env, recipient: Address, token: Address) -> u128 {
+        get_accrued_fee(&e, &recipient, &token)
     }
 
     // claim_fees_and_swap
-    // Claims fees and swaps them immediately using the router.
+    // Swaps fees not accrued yet using the router and distributes the output to the fee
+    // recipients pro-rata.
     //
     // Arguments:
     //   - e: The Soroban environment.
@@ -123,17 +350,13 @@ impl ProviderSwapFeeCollector {
         token: Address,
         out_min: u128,
     ) -> u128 {
-        operator.require_auth();
-        if operator != get_operator(&e) {
-            panic_with_error!(&e, Error::Unauthorized)
-        }
+        require_operator(&e, &operator);
         let (_, _, token_out) = match swaps_chain.last() {
             Some(v) => v,
             None => panic_with_error!(&e, Error::PathIsEmpty),
         };
         let router = get_router(&e);
-        let token_client = SorobanTokenClient::new(&e, &token);
-        let amount = token_client.balance(&e.current_contract_address()) as u128;
+        let amount = get_unaccrued_fee(&e, &token);
         e.authorize_as_current_contract(vec![
             &e,
             InvokerContractAuthEntry::Contract(SubContractInvocation {
@@ -160,16 +383,218 @@ impl ProviderSwapFeeCollector {
                 ],
             ),
         );
-        SorobanTokenClient::new(&e, &token_out).transfer(
-            &e.current_contract_address(),
-            &get_fee_destination(&e),
-            &(out_amount as i128),
-        );
+        distribute_fee(&e, &token_out, out_amount);
         Events::new(&e).claim_fee(token, amount, token_out, out_amount);
         out_amount
     }
 }
 
+#[contractimpl]
+impl ProviderSwapFeeCollector {
+    // get_operator
+    // Returns the address of the operator.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //
+    // Returns:
+    //   - An Address representing the operator.
+    pub fn get_operator(e: Env) -> Address {
+        get_operator(&e)
+    }
+
+    // commit_config
+    // Commits new router, fee destination and maximum swap fee.
+    // The configuration can be applied after ADMIN_ACTIONS_DELAY.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - operator: The operator address (must be authorized).
+    //   - router: The address of the new swap router contract.
+    //   - fee_destination: The new address receiving the fee while no fee recipients are set.
+    //     Fees already accrued to the former destination stay claimable by it.
+    //   - max_swap_fee_fraction: The new maximum fee in basis points (bps).
+    pub fn commit_config(
+        e: Env,
+        operator: Address,
+        router: Address,
+        fee_destination: Address,
+        max_swap_fee_fraction: u32,
+    ) {
+        require_operator(&e, &operator);
+        if max_swap_fee_fraction >= get_fee_denominator(&e) {
+            panic_with_error!(&e, Error::FeeFractionTooHigh);
+        }
+        commit_delayed_action(&e, &DataKey::FutureConfigDeadline);
+
+        set_future_config(
+            &e,
+            &Some(ProviderConfig {
+                router: router.clone(),
+                fee_destination: fee_destination.clone(),
+                max_swap_fee_fraction,
+            }),
+        );
+        Events::new(&e).commit_config(router, fee_destination, max_swap_fee_fraction);
+    }
+
+    // apply_config
+    // Applies the committed configuration once the delay has passed.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - operator: The operator address (must be authorized).
+    pub fn apply_config(e: Env, operator: Address) {
+        require_operator(&e, &operator);
+        apply_delayed_action(&e, &DataKey::FutureConfigDeadline);
+        let config = match get_future_config(&e) {
+            Some(config) => config,
+            None => panic_with_error!(&e, StorageError::ValueNotInitialized),
+        };
+
+        set_router(&e, &config.router);
+        set_fee_destination(&e, &config.fee_destination);
+        set_max_swap_fee_fraction(&e, &config.max_swap_fee_fraction);
+        set_future_config(&e, &None);
+        Events::new(&e).apply_config(
+            config.router,
+            config.fee_destination,
+            config.max_swap_fee_fraction,
+        );
+    }
+
+    // revert_config
+    // Discards the committed configuration.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - operator: The operator address (must be authorized).
+    pub fn revert_config(e: Env, operator: Address) {
+        require_operator(&e, &operator);
+        revert_delayed_action(&e, &DataKey::FutureConfigDeadline);
+        set_future_config(&e, &None);
+        Events::new(&e).revert_config();
+    }
+
+    // get_future_config
+    // Returns the committed configuration waiting for the delay, if any.
+    pub fn get_future_config(e: Env) -> Option<ProviderConfig> {
+        get_future_config(&e)
+    }
+
+    // get_config_deadline
+    // Returns the timestamp after which the committed configuration can be applied, 0 if none.
+    pub fn get_config_deadline(e: Env) -> u64 {
+        get_delayed_action_deadline(&e, &DataKey::FutureConfigDeadline)
+    }
+
+    // get_fee_recipients
+    // Returns the fee recipients with their shares in basis points.
+    // Defaults to the fee destination receiving the whole fee.
+    pub fn get_fee_recipients(e: Env) -> Vec<(Address, u32)> {
+        get_fee_recipients(&e)
+    }
+
+    // commit_fee_recipients
+    // Commits new fee recipients. They can be applied after ADMIN_ACTIONS_DELAY.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - operator: The operator address (must be authorized).
+    //   - recipients: Unique addresses with shares in basis points summing up to 10_000.
+    pub fn commit_fee_recipients(e: Env, operator: Address, recipients: Vec<(Address, u32)>) {
+        require_operator(&e, &operator);
+        validate_fee_recipients(&e, &recipients);
+        commit_delayed_action(&e, &DataKey::FutureFeeRecipientsDeadline);
+
+        set_future_fee_recipients(&e, &Some(recipients.clone()));
+        Events::new(&e).commit_fee_recipients(recipients);
+    }
+
+    // apply_fee_recipients
+    // Applies the committed fee recipients once the delay has passed.
+    // Fees of the given tokens held by the contract are accrued to the current recipients first,
+    // so fees earned under the old split stay with them.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - operator: The operator address (must be authorized).
+    //   - tokens: The fee tokens to accrue to the current recipients before the change.
+    pub fn apply_fee_recipients(e: Env, operator: Address, tokens: Vec<Address>) {
+        require_operator(&e, &operator);
+        apply_delayed_action(&e, &DataKey::FutureFeeRecipientsDeadline);
+        let recipients = match get_future_fee_recipients(&e) {
+            Some(recipients) => recipients,
+            None => panic_with_error!(&e, StorageError::ValueNotInitialized),
+        };
+
+        for token in tokens.iter() {
+            let amount = get_unaccrued_fee(&e, &token);
+            if amount > 0 {
+                accrue_fee(&e, &token, amount);
+                Events::new(&e).claim_fee(token.clone(), amount, token, amount);
+            }
+        }
+
+        set_fee_recipients(&e, &recipients);
+        set_future_fee_recipients(&e, &None);
+        Events::new(&e).apply_fee_recipients(recipients);
+    }
+
+    // revert_fee_recipients
+    // Discards the committed fee recipients.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - operator: The operator address (must be authorized).
+    pub fn revert_fee_recipients(e: Env, operator: Address) {
+        require_operator(&e, &operator);
+        revert_delayed_action(&e, &DataKey::FutureFeeRecipientsDeadline);
+        set_future_fee_recipients(&e, &None);
+        Events::new(&e).revert_fee_recipients();
+    }
+
+    // get_future_fee_recipients
+    // Returns the committed fee recipients waiting for the delay, if any.
+    pub fn get_future_fee_recipients(e: Env) -> Option<Vec<(Address, u32)>> {
+        get_future_fee_recipients(&e)
+    }
+
+    // get_fee_recipients_deadline
+    // Returns the timestamp after which the committed recipients can be applied, 0 if none.
+    pub fn get_fee_recipients_deadline(e: Env) -> u64 {
+        get_delayed_action_deadline(&e, &DataKey::FutureFeeRecipientsDeadline)
+    }
+
+    // commit_factory_upgrade
+    // Commits a new WASM hash as a pending upgrade on behalf of the factory that deployed
+    // the contract. The factory is verified by deriving the contract address from it and the salt.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - factory: The factory address (must be authorized).
+    //   - salt: The salt the contract was deployed with.
+    //   - operator: The operator address (must be authorized).
+    //   - new_wasm_hash: The new WASM hash (BytesN<32>) to be committed.
+    pub fn commit_factory_upgrade(
+        e: Env,
+        factory: Address,
+        salt: BytesN<32>,
+        operator: Address,
+        new_wasm_hash: BytesN<32>,
+    ) {
+        factory.require_auth();
+        require_operator(&e, &operator);
+        if e.deployer().with_address(factory, salt).deployed_address()
+            != e.current_contract_address()
+        {
+            panic_with_error!(&e, Error::Unauthorized);
+        }
+        commit_upgrade(&e, &new_wasm_hash);
+        UpgradeEvents::new(&e).commit_upgrade(Vec::from_array(&e, [new_wasm_hash.clone()]));
+    }
+}
+
 #[contractimpl]
 impl ProviderSwapFeeInterface for ProviderSwapFeeCollector {
     // swap_chained
@@ -354,4 +779,503 @@ impl ProviderSwapFeeInterface for ProviderSwapFeeCollector {
         Events::new(&e).charge_provider_fee(token_out, gross_out - out_amount);
         amount_in
     }
+
+    // swap
+    // Executes a single pool swap with fee deduction.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - user: The user initiating the swap (must be authorized).
+    //   - tokens: The sorted pool tokens.
+    //   - token_in: The input token address.
+    //   - token_out: The output token address.
+    //   - pool_index: The pool index hash.
+    //   - in_amount: The amount of token_in provided by the user.
+    //   - out_min: The minimum acceptable output token amount (after fee deduction).
+    //   - fee_fraction: The provider fee fraction in basis points (bps).
+    //   - fee_in_output: Charge the fee in token_out instead of token_in.
+    //
+    // Returns:
+    //   - A u128 value representing the net output tokens transferred to the user.
+    fn swap(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        token_in: Address,
+        token_out: Address,
+        pool_index: BytesN<32>,
+        in_amount: u128,
+        out_min: u128,
+        fee_fraction: u32,
+        fee_in_output: bool,
+    ) -> u128 {
+        user.require_auth();
+
+        if fee_fraction > get_max_swap_fee_fraction(&e) {
+            panic_with_error!(&e, Error::FeeFractionTooHigh);
+        }
+        let in_idx = match tokens.first_index_of(token_in.clone()) {
+            Some(idx) => idx,
+            None => panic_with_error!(&e, Error::TokenNotInPool),
+        };
+        let out_idx = match tokens.first_index_of(token_out.clone()) {
+            Some(idx) => idx,
+            None => panic_with_error!(&e, Error::TokenNotInPool),
+        };
+
+        SorobanTokenClient::new(&e, &token_in).transfer(
+            &user,
+            e.current_contract_address(),
+            &(in_amount as i128),
+        );
+        let fee_in = if fee_in_output {
+            0
+        } else {
+            get_fee_amount(&e, in_amount, fee_fraction)
+        };
+        let swap_amount = in_amount - fee_in;
+
+        let router = get_router(&e);
+        let pool = get_pool_address(&e, &router, &tokens, &pool_index);
+        authorize_pool_call(
+            &e,
+            &pool,
+            "swap",
+            (
+                e.current_contract_address(),
+                in_idx,
+                out_idx,
+                swap_amount,
+                out_min,
+            )
+                .into_val(&e),
+            vec![
+                &e,
+                token_call_auth(
+                    &e,
+                    &token_in,
+                    "transfer",
+                    (
+                        e.current_contract_address(),
+                        pool.clone(),
+                        swap_amount as i128,
+                    )
+                        .into_val(&e),
+                ),
+            ],
+        );
+        let amount_out: u128 = e.invoke_contract(
+            &router,
+            &Symbol::new(&e, "swap"),
+            Vec::from_array(
+                &e,
+                [
+                    e.current_contract_address().to_val(),
+                    tokens.to_val(),
+                    token_in.to_val(),
+                    token_out.to_val(),
+                    pool_index.to_val(),
+                    swap_amount.into_val(&e),
+                    out_min.into_val(&e),
+                ],
+            ),
+        );
+
+        let fee_out = if fee_in_output {
+            get_fee_amount(&e, amount_out, fee_fraction)
+        } else {
+            0
+        };
+        let amount_out_w_fee = amount_out - fee_out;
+        if amount_out_w_fee < out_min {
+            panic_with_error!(&e, Error::OutMinNotSatisfied);
+        }
+        SorobanTokenClient::new(&e, &token_out).transfer(
+            &e.current_contract_address(),
+            &user,
+            &(amount_out_w_fee as i128),
+        );
+        if fee_in_output {
+            Events::new(&e).charge_provider_fee(token_out, fee_out);
+        } else {
+            Events::new(&e).charge_provider_fee(token_in, fee_in);
+        }
+        amount_out_w_fee
+    }
+
+    // deposit
+    // Deposits liquidity into the pool with fee deduction.
+    // A fee in input tokens is charged only on the part the pool accepts, the rest is refunded.
+    // Concentrated pools have no share token and are rejected.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - user: The user depositing the tokens (must be authorized).
+    //   - tokens: The sorted pool tokens.
+    //   - pool_index: The pool index hash.
+    //   - desired_amounts: The amounts of tokens provided by the user.
+    //   - min_shares: The minimum acceptable amount of shares (after fee deduction).
+    //   - fee_fraction: The provider fee fraction in basis points (bps).
+    //   - fee_in_output: Charge the fee in pool shares instead of deposited tokens.
+    //
+    // Returns:
+    //   - A tuple of the amounts deposited into the pool and the net shares transferred to the user.
+    fn deposit(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        desired_amounts: Vec<u128>,
+        min_shares: u128,
+        fee_fraction: u32,
+        fee_in_output: bool,
+    ) -> (Vec<u128>, u128) {
+        user.require_auth();
+
+        if fee_fraction > get_max_swap_fee_fraction(&e) {
+            panic_with_error!(&e, Error::FeeFractionTooHigh);
+        }
+
+        let router = get_router(&e);
+        require_share_pool(&e, &router, &tokens, &pool_index);
+        let pool = get_pool_address(&e, &router, &tokens, &pool_index);
+
+        let mut fee_amounts = Vec::new(&e);
+        let mut deposit_amounts = Vec::new(&e);
+        let mut transfers_auth = Vec::new(&e);
+        for (token, amount) in tokens.iter().zip(desired_amounts.iter()) {
+            SorobanTokenClient::new(&e, &token).transfer(
+                &user,
+                e.current_contract_address(),
+                &(amount as i128),
+            );
+            let fee_amount = if fee_in_output {
+                0
+            } else {
+                get_fee_amount(&e, amount, fee_fraction)
+            };
+            fee_amounts.push_back(fee_amount);
+            deposit_amounts.push_back(amount - fee_amount);
+            transfers_auth.push_back(token_call_auth(
+                &e,
+                &token,
+                "transfer",
+                (
+                    e.current_contract_address(),
+                    pool.clone(),
+                    (amount - fee_amount) as i128,
+                )
+                    .into_val(&e),
+            ));
+        }
+
+        authorize_pool_call(
+            &e,
+            &pool,
+            "deposit",
+            (
+                e.current_contract_address(),
+                deposit_amounts.clone(),
+                min_shares,
+            )
+                .into_val(&e),
+            transfers_auth,
+        );
+        let (amounts, share_amount): (Vec<u128>, u128) = e.invoke_contract(
+            &router,
+            &Symbol::new(&e, "deposit"),
+            Vec::from_array(
+                &e,
+                [
+                    e.current_contract_address().to_val(),
+                    tokens.to_val(),
+                    pool_index.to_val(),
+                    deposit_amounts.to_val(),
+                    min_shares.into_val(&e),
+                ],
+            ),
+        );
+
+        // Pool returns the unused part of the desired amounts. The fee is kept only on the
+        // deposited part; the rest of the reserved fee goes back to the user with it.
+        for (i, token) in tokens.iter().enumerate() {
+            let i = i as u32;
+            let amount = amounts.get(i).unwrap_or(0);
+            let reserved_fee = fee_amounts.get(i).unwrap_or(0);
+            let fee_amount = if fee_in_output {
+                0
+            } else {
+                get_deposit_fee_amount(&e, amount, fee_fraction).min(reserved_fee)
+            };
+            let refund = deposit_amounts.get(i).unwrap_or(0) - amount + reserved_fee - fee_amount;
+            if refund > 0 {
+                SorobanTokenClient::new(&e, &token).transfer(
+                    &e.current_contract_address(),
+                    &user,
+                    &(refund as i128),
+                );
+            }
+            if fee_amount > 0 {
+                Events::new(&e).charge_provider_fee(token, fee_amount);
+            }
+        }
+
+        let share_token = get_share_token(&e, &pool);
+        let fee_shares = if fee_in_output {
+            get_fee_amount(&e, share_amount, fee_fraction)
+        } else {
+            0
+        };
+        let share_amount_w_fee = share_amount - fee_shares;
+        if share_amount_w_fee < min_shares {
+            panic_with_error!(&e, Error::OutMinNotSatisfied);
+        }
+        SorobanTokenClient::new(&e, &share_token).transfer(
+            &e.current_contract_address(),
+            &user,
+            &(share_amount_w_fee as i128),
+        );
+        if fee_shares > 0 {
+            Events::new(&e).charge_provider_fee(share_token, fee_shares);
+        }
+        (amounts, share_amount_w_fee)
+    }
+
+    // withdraw
+    // Withdraws liquidity from the pool with fee deduction.
+    // Concentrated pools have no share token and are rejected.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - user: The user withdrawing the liquidity (must be authorized).
+    //   - tokens: The sorted pool tokens.
+    //   - pool_index: The pool index hash.
+    //   - share_amount: The amount of pool shares provided by the user.
+    //   - min_amounts: The minimum acceptable amounts of tokens (after fee deduction).
+    //   - fee_fraction: The provider fee fraction in basis points (bps).
+    //   - fee_in_output: Charge the fee in withdrawn tokens instead of pool shares.
+    //
+    // Returns:
+    //   - A vector of the net token amounts transferred to the user.
+    fn withdraw(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        share_amount: u128,
+        min_amounts: Vec<u128>,
+        fee_fraction: u32,
+        fee_in_output: bool,
+    ) -> Vec<u128> {
+        user.require_auth();
+
+        if fee_fraction > get_max_swap_fee_fraction(&e) {
+            panic_with_error!(&e, Error::FeeFractionTooHigh);
+        }
+
+        let router = get_router(&e);
+        require_share_pool(&e, &router, &tokens, &pool_index);
+        let pool = get_pool_address(&e, &router, &tokens, &pool_index);
+        let share_token = get_share_token(&e, &pool);
+        SorobanTokenClient::new(&e, &share_token).transfer(
+            &user,
+            e.current_contract_address(),
+            &(share_amount as i128),
+        );
+        let fee_shares = if fee_in_output {
+            0
+        } else {
+            get_fee_amount(&e, share_amount, fee_fraction)
+        };
+        let withdraw_shares = share_amount - fee_shares;
+
+        authorize_pool_call(
+            &e,
+            &pool,
+            "withdraw",
+            (
+                e.current_contract_address(),
+                withdraw_shares,
+                min_amounts.clone(),
+            )
+                .into_val(&e),
+            vec![
+                &e,
+                token_call_auth(
+                    &e,
+                    &share_token,
+                    "burn",
+                    (e.current_contract_address(), withdraw_shares as i128).into_val(&e),
+                ),
+            ],
+        );
+        let amounts: Vec<u128> = e.invoke_contract(
+            &router,
+            &Symbol::new(&e, "withdraw"),
+            Vec::from_array(
+                &e,
+                [
+                    e.current_contract_address().to_val(),
+                    tokens.to_val(),
+                    pool_index.to_val(),
+                    withdraw_shares.into_val(&e),
+                    min_amounts.to_val(),
+                ],
+            ),
+        );
+        if fee_shares > 0 {
+            Events::new(&e).charge_provider_fee(share_token, fee_shares);
+        }
+
+        let mut amounts_w_fee = Vec::new(&e);
+        for (i, (token, amount)) in tokens.iter().zip(amounts.iter()).enumerate() {
+            let fee_amount = if fee_in_output {
+                get_fee_amount(&e, amount, fee_fraction)
+            } else {
+                0
+            };
+            let amount_w_fee = amount - fee_amount;
+            if amount_w_fee < min_amounts.get(i as u32).unwrap_or(0) {
+                panic_with_error!(&e, Error::OutMinNotSatisfied);
+            }
+            SorobanTokenClient::new(&e, &token).transfer(
+                &e.current_contract_address(),
+                &user,
+                &(amount_w_fee as i128),
+            );
+            if fee_amount > 0 {
+                Events::new(&e).charge_provider_fee(token, fee_amount);
+            }
+            amounts_w_fee.push_back(amount_w_fee);
+        }
+        amounts_w_fee
+    }
+}
+
+// Upgrades are committed only by the factory with its current swap fee contract WASM,
+// see commit_factory_upgrade. Provider contracts have no emergency admin, so there is
+// neither a direct commit_upgrade nor an emergency mode switch.
+#[contractimpl]
+impl ProviderSwapFeeCollector {
+    // version
+    // Returns the current version number of the contract.
+    //
+    // Returns:
+    //   - A u32 representing the version.
+    pub fn version() -> u32 {
+        200
+    }
+
+    // Get contract type symbolic name
+    pub fn contract_name(e: Env) -> Symbol {
+        Symbol::new(&e, "ProviderSwapFee")
+    }
+
+    // apply_upgrade
+    // Applies the previously committed upgrade.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - admin: The operator address (must be authorized).
+    //
+    // Returns:
+    //   - The new WASM hash (BytesN<32>) that was applied.
+    pub fn apply_upgrade(e: Env, admin: Address) -> BytesN<32> {
+        require_operator(&e, &admin);
+        let new_wasm_hash = apply_upgrade(&e);
+        UpgradeEvents::new(&e).apply_upgrade(Vec::from_array(&e, [new_wasm_hash.clone()]));
+        new_wasm_hash
+    }
+
+    // revert_upgrade
+    // Reverts a pending upgrade that has not yet been applied.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - admin: The operator address (must be authorized).
+    pub fn revert_upgrade(e: Env, admin: Address) {
+        require_operator(&e, &admin);
+        revert_upgrade(&e);
+        UpgradeEvents::new(&e).revert_upgrade();
+    }
+}
+
+#[contractimpl]
+impl TransferableContract for ProviderSwapFeeCollector {
+    // commit_transfer_ownership
+    // Commits to transferring the operator role to a new address.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - admin: The operator address (must be authorized).
+    //   - role_name: The symbol representing the role. Only "Admin" (the operator) is supported.
+    //   - new_address: The new address to assume the role.
+    fn commit_transfer_ownership(e: Env, admin: Address, role_name: Symbol, new_address: Address) {
+        require_operator(&e, &admin);
+        let role = Role::from_symbol(&e, role_name);
+        if !matches!(role, Role::Admin) {
+            panic_with_error!(&e, AccessControlError::BadRoleUsage);
+        }
+
+        // move operator from the legacy storage so the transfer delay is enforced
+        let access_control = AccessControl::new(&e);
+        if access_control.get_role_safe(&Role::Admin).is_none() {
+            set_operator(&e, &admin);
+        }
+        access_control.commit_transfer_ownership(&role, &new_address);
+        AccessControlEvents::new(&e).commit_transfer_ownership(role, new_address);
+    }
+
+    // apply_transfer_ownership
+    // Applies the pending operator transfer.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - admin: The operator address (must be authorized).
+    //   - role_name: The symbol representing the role.
+    fn apply_transfer_ownership(e: Env, admin: Address, role_name: Symbol) {
+        require_operator(&e, &admin);
+        let role = Role::from_symbol(&e, role_name);
+        let new_address = AccessControl::new(&e).apply_transfer_ownership(&role);
+        AccessControlEvents::new(&e).apply_transfer_ownership(role, new_address);
+    }
+
+    // revert_transfer_ownership
+    // Reverts a pending operator transfer.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - admin: The operator address (must be authorized).
+    //   - role_name: The symbol representing the role.
+    fn revert_transfer_ownership(e: Env, admin: Address, role_name: Symbol) {
+        require_operator(&e, &admin);
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).revert_transfer_ownership(&role);
+        AccessControlEvents::new(&e).revert_transfer_ownership(role);
+    }
+
+    // get_future_address
+    // Returns the pending future address for a role if an ownership transfer is committed;
+    // otherwise, returns the current role address.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - role_name: The symbol representing the role.
+    //
+    // Returns:
+    //   - The Address scheduled to assume the role, or the current address if none pending.
+    fn get_future_address(e: Env, role_name: Symbol) -> Address {
+        let access_control = AccessControl::new(&e);
+        let role = Role::from_symbol(&e, role_name);
+        match access_control.get_transfer_ownership_deadline(&role) {
+            0 => match role {
+                Role::Admin => get_operator(&e),
+                _ => match access_control.get_role_safe(&role) {
+                    Some(address) => address,
+                    None => panic_with_error!(&e, AccessControlError::RoleNotFound),
+                },
+            },
+            _ => access_control.get_future_address(&role),
+        }
+    }
 }
@@ -6,7 +6,10 @@ use soroban_sdk::contracterror;
 pub enum Error {
     Unauthorized = 102,
     PathIsEmpty = 307,
+    InvalidPoolType = 316,
+    TokenNotInPool = 328,
     OutMinNotSatisfied = 2006,
     InMaxNotSatisfied = 2020,
     FeeFractionTooHigh = 2904,
+    InvalidFeeRecipients = 3300,
 }
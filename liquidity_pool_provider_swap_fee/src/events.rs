@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, Env, Symbol, Vec};
 
 #[derive(Clone)]
 pub(crate) struct Events(Env);
@@ -15,10 +15,30 @@ impl Events {
     }
 }
 
+pub(crate) trait ProviderConfigEvents {
+    fn commit_config(&self, router: Address, fee_destination: Address, max_swap_fee_fraction: u32);
+
+    fn apply_config(&self, router: Address, fee_destination: Address, max_swap_fee_fraction: u32);
+
+    fn revert_config(&self);
+
+    fn commit_fee_recipients(&self, recipients: Vec<(Address, u32)>);
+
+    fn apply_fee_recipients(&self, recipients: Vec<(Address, u32)>);
+
+    fn revert_fee_recipients(&self);
+}
+
 pub(crate) trait ProviderFeeEvents {
     fn charge_provider_fee(&self, token: Address, amount: u128);
 
     fn claim_fee(&self, token: Address, amount: u128, swapped_to: Address, swapped_amount: u128);
+
+    fn distribute_fee(&self, recipient: Address, token: Address, amount: u128);
+
+    fn accrue_fee(&self, recipient: Address, token: Address, amount: u128);
+
+    fn claim_fee_share(&self, recipient: Address, token: Address, amount: u128);
 }
 
 impl ProviderFeeEvents for Events {
@@ -35,4 +55,67 @@ impl ProviderFeeEvents for Events {
             (token, amount, swapped_to, swapped_amount),
         );
     }
+
+    fn distribute_fee(&self, recipient: Address, token: Address, amount: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "distribute_fee"), recipient),
+            (token, amount),
+        );
+    }
+
+    fn accrue_fee(&self, recipient: Address, token: Address, amount: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "accrue_fee"), recipient),
+            (token, amount),
+        );
+    }
+
+    fn claim_fee_share(&self, recipient: Address, token: Address, amount: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "claim_fee_share"), recipient),
+            (token, amount),
+        );
+    }
+}
+
+impl ProviderConfigEvents for Events {
+    fn commit_config(&self, router: Address, fee_destination: Address, max_swap_fee_fraction: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "commit_config"),),
+            (router, fee_destination, max_swap_fee_fraction),
+        );
+    }
+
+    fn apply_config(&self, router: Address, fee_destination: Address, max_swap_fee_fraction: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "apply_config"),),
+            (router, fee_destination, max_swap_fee_fraction),
+        );
+    }
+
+    fn revert_config(&self) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "revert_config"),), ());
+    }
+
+    fn commit_fee_recipients(&self, recipients: Vec<(Address, u32)>) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "commit_fee_recipients"),),
+            (recipients,),
+        );
+    }
+
+    fn apply_fee_recipients(&self, recipients: Vec<(Address, u32)>) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "apply_fee_recipients"),),
+            (recipients,),
+        );
+    }
+
+    fn revert_fee_recipients(&self) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "revert_fee_recipients"),), ());
+    }
 }
@@ -48,4 +48,92 @@ pub trait ProviderSwapFeeInterface {
         in_max: u128,
         fee_fraction: u32,
     ) -> u128;
+
+    // swap
+    // Executes a single pool swap with fee deduction.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - user: The user initiating the swap (must be authorized).
+    //   - tokens: The sorted pool tokens.
+    //   - token_in: The input token address.
+    //   - token_out: The output token address.
+    //   - pool_index: The pool index hash.
+    //   - in_amount: The amount of token_in provided by the user.
+    //   - out_min: The minimum acceptable output token amount (after fee deduction).
+    //   - fee_fraction: The provider fee fraction in basis points (bps).
+    //   - fee_in_output: Charge the fee in token_out instead of token_in.
+    //
+    // Returns:
+    //   - A u128 value representing the net output tokens transferred to the user.
+    #[allow(clippy::too_many_arguments)]
+    fn swap(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        token_in: Address,
+        token_out: Address,
+        pool_index: BytesN<32>,
+        in_amount: u128,
+        out_min: u128,
+        fee_fraction: u32,
+        fee_in_output: bool,
+    ) -> u128;
+
+    // deposit
+    // Deposits liquidity into the pool with fee deduction.
+    // A fee in input tokens is charged only on the part the pool accepts, the rest is refunded.
+    // Concentrated pools have no share token and are rejected.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - user: The user depositing the tokens (must be authorized).
+    //   - tokens: The sorted pool tokens.
+    //   - pool_index: The pool index hash.
+    //   - desired_amounts: The amounts of tokens provided by the user.
+    //   - min_shares: The minimum acceptable amount of shares (after fee deduction).
+    //   - fee_fraction: The provider fee fraction in basis points (bps).
+    //   - fee_in_output: Charge the fee in pool shares instead of deposited tokens.
+    //
+    // Returns:
+    //   - A tuple of the amounts deposited into the pool and the net shares transferred to the user.
+    #[allow(clippy::too_many_arguments)]
+    fn deposit(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        desired_amounts: Vec<u128>,
+        min_shares: u128,
+        fee_fraction: u32,
+        fee_in_output: bool,
+    ) -> (Vec<u128>, u128);
+
+    // withdraw
+    // Withdraws liquidity from the pool with fee deduction.
+    // Concentrated pools have no share token and are rejected.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - user: The user withdrawing the liquidity (must be authorized).
+    //   - tokens: The sorted pool tokens.
+    //   - pool_index: The pool index hash.
+    //   - share_amount: The amount of pool shares provided by the user.
+    //   - min_amounts: The minimum acceptable amounts of tokens (after fee deduction).
+    //   - fee_fraction: The provider fee fraction in basis points (bps).
+    //   - fee_in_output: Charge the fee in withdrawn tokens instead of pool shares.
+    //
+    // Returns:
+    //   - A vector of the net token amounts transferred to the user.
+    #[allow(clippy::too_many_arguments)]
+    fn withdraw(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        share_amount: u128,
+        min_amounts: Vec<u128>,
+        fee_fraction: u32,
+        fee_in_output: bool,
+    ) -> Vec<u128>;
 }
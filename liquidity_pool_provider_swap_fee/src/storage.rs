@@ -1,6 +1,9 @@
+use access_control::access::AccessControl;
+use access_control::management::SingleAddressManagementTrait;
+use access_control::role::Role;
 use paste::paste;
-use soroban_sdk::{contracttype, panic_with_error, Address, Env};
-use utils::bump::bump_instance;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+use utils::bump::{bump_instance, bump_persistent};
 use utils::storage_errors::StorageError;
 use utils::{
     generate_instance_storage_getter, generate_instance_storage_getter_and_setter,
@@ -9,16 +12,35 @@ use utils::{
 
 #[derive(Clone)]
 #[contracttype]
-enum DataKey {
-    Router,             // Address of the AMM router.
-    Operator, // Address of the operator. Operator is capable to configure fees and claim them.
+pub(crate) enum DataKey {
+    Router,                       // Address of the AMM router.
+    Operator, // Legacy address of the operator. Operator is capable to configure fees and claim them.
     FeeDestination, // Fee destination address
     MaxSwapFeeFraction, // Max swap fee in basis points (100 = 1% considering denominator is 10_000)
     FeeDenominator, // Fee denominator
+    FutureConfig, // Configuration committed to be applied after the delay
+    FutureConfigDeadline, // Timestamp after which the committed configuration can be applied
+    FeeRecipients, // Fee recipients with their shares in basis points
+    FutureFeeRecipients, // Fee recipients committed to be applied after the delay
+    FutureFeeRecipientsDeadline, // Timestamp after which the committed recipients can be applied
+    AccruedFee(Address, Address), // Fee of the token accrued to the recipient and not withdrawn yet
+    TotalAccruedFee(Address), // Fee of the token accrued to all recipients and not withdrawn yet
+}
+
+// Denominator for fee recipient shares (basis points)
+pub(crate) const FEE_SHARE_DENOMINATOR: u32 = 10_000;
+// Max number of fee recipients to keep claims within the budget
+pub(crate) const MAX_FEE_RECIPIENTS: u32 = 10;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct ProviderConfig {
+    pub router: Address,
+    pub fee_destination: Address,
+    pub max_swap_fee_fraction: u32,
 }
 
 generate_instance_storage_getter_and_setter!(router, DataKey::Router, Address);
-generate_instance_storage_getter_and_setter!(operator, DataKey::Operator, Address);
 generate_instance_storage_getter_and_setter!(fee_destination, DataKey::FeeDestination, Address);
 generate_instance_storage_getter_and_setter!(
     max_swap_fee_fraction,
@@ -26,3 +48,91 @@ generate_instance_storage_getter_and_setter!(
     u32
 );
 generate_instance_storage_getter_and_setter!(fee_denominator, DataKey::FeeDenominator, u32);
+generate_instance_storage_setter!(fee_recipients, DataKey::FeeRecipients, Vec<(Address, u32)>);
+
+// Operator is stored as the access control admin to support delayed transfers.
+// Contracts deployed before keep it under the legacy key until the first transfer.
+pub fn get_operator(e: &Env) -> Address {
+    match AccessControl::new(e).get_role_safe(&Role::Admin) {
+        Some(operator) => operator,
+        None => {
+            bump_instance(e);
+            match e.storage().instance().get(&DataKey::Operator) {
+                Some(operator) => operator,
+                None => panic_with_error!(e, StorageError::ValueNotInitialized),
+            }
+        }
+    }
+}
+
+pub fn set_operator(e: &Env, operator: &Address) {
+    AccessControl::new(e).set_role_address(&Role::Admin, operator);
+}
+
+pub fn get_future_config(e: &Env) -> Option<ProviderConfig> {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::FutureConfig)
+}
+
+pub fn set_future_config(e: &Env, config: &Option<ProviderConfig>) {
+    bump_instance(e);
+    match config {
+        Some(config) => e.storage().instance().set(&DataKey::FutureConfig, config),
+        None => e.storage().instance().remove(&DataKey::FutureConfig),
+    }
+}
+
+// Fees go entirely to the fee destination until recipients are configured.
+pub fn get_fee_recipients(e: &Env) -> Vec<(Address, u32)> {
+    bump_instance(e);
+    match e.storage().instance().get(&DataKey::FeeRecipients) {
+        Some(recipients) => recipients,
+        None => Vec::from_array(e, [(get_fee_destination(e), FEE_SHARE_DENOMINATOR)]),
+    }
+}
+
+pub fn get_future_fee_recipients(e: &Env) -> Option<Vec<(Address, u32)>> {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::FutureFeeRecipients)
+}
+
+pub fn set_future_fee_recipients(e: &Env, recipients: &Option<Vec<(Address, u32)>>) {
+    bump_instance(e);
+    match recipients {
+        Some(recipients) => e
+            .storage()
+            .instance()
+            .set(&DataKey::FutureFeeRecipients, recipients),
+        None => e.storage().instance().remove(&DataKey::FutureFeeRecipients),
+    }
+}
+
+pub fn get_accrued_fee(e: &Env, recipient: &Address, token: &Address) -> u128 {
+    let key = DataKey::AccruedFee(recipient.clone(), token.clone());
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value.unwrap_or(0)
+}
+
+pub fn set_accrued_fee(e: &Env, recipient: &Address, token: &Address, value: &u128) {
+    let key = DataKey::AccruedFee(recipient.clone(), token.clone());
+    e.storage().persistent().set(&key, value);
+    bump_persistent(e, &key);
+}
+
+pub fn get_total_accrued_fee(e: &Env, token: &Address) -> u128 {
+    let key = DataKey::TotalAccruedFee(token.clone());
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value.unwrap_or(0)
+}
+
+pub fn set_total_accrued_fee(e: &Env, token: &Address, value: &u128) {
+    let key = DataKey::TotalAccruedFee(token.clone());
+    e.storage().persistent().set(&key, value);
+    bump_persistent(e, &key);
+}
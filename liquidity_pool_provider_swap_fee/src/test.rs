@@ -1,16 +1,20 @@
 #![cfg(test)]
 extern crate std;
 
+use crate::contract::ProviderSwapFeeCollectorClient;
 use crate::testutils::{
     create_contract, create_reward_boost_feed_contract, create_token_contract,
-    deploy_plane_contract, get_token_admin_client, install_liq_pool_hash,
-    install_stableswap_liq_pool_hash, install_token_wasm, liquidity_pool, swap_router, Setup,
+    deploy_plane_contract, get_token_admin_client, install_concentrated_liq_pool_hash,
+    install_liq_pool_hash, install_stableswap_liq_pool_hash, install_token_wasm, liquidity_pool,
+    swap_router, Setup,
 };
+use access_control::constants::ADMIN_ACTIONS_DELAY;
 use liquidity_pool_config_storage::testutils::deploy_config_storage;
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
 use soroban_sdk::token::TokenClient as SorobanTokenClient;
-use soroban_sdk::{vec, Address, Env, Vec};
+use soroban_sdk::{symbol_short, vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
 use utils::test_rebasing_token;
+use utils::test_utils::{install_dummy_wasm, jump};
 
 #[test]
 fn test_strict_send() {
@@ -553,3 +557,1024 @@ fn test_strict_receive_rebasing_token() {
     );
     assert_eq!(rebasing.balance(&contract.address), 0);
 }
+
+#[test]
+fn test_commit_config() {
+    let setup = Setup::default();
+    let new_router = Address::generate(&setup.env);
+
+    let new_fee_destination = Address::generate(&setup.env);
+
+    setup
+        .contract
+        .commit_config(&setup.operator, &new_router, &new_fee_destination, &50);
+    let future_config = setup.contract.get_future_config().unwrap();
+    assert_eq!(future_config.router, new_router);
+    assert_eq!(future_config.fee_destination, new_fee_destination);
+    assert_eq!(future_config.max_swap_fee_fraction, 50);
+    assert_eq!(
+        setup.contract.get_config_deadline(),
+        setup.env.ledger().timestamp() + ADMIN_ACTIONS_DELAY
+    );
+
+    jump(&setup.env, ADMIN_ACTIONS_DELAY - 1);
+    assert!(setup.contract.try_apply_config(&setup.operator).is_err());
+    assert_eq!(setup.contract.get_router(), setup.router.address);
+
+    jump(&setup.env, 1);
+    setup.contract.apply_config(&setup.operator);
+    assert_eq!(setup.contract.get_router(), new_router);
+    assert_eq!(setup.contract.get_fee_destination(), new_fee_destination);
+    assert_eq!(setup.contract.get_max_swap_fee_fraction(), 50);
+    assert!(setup.contract.get_future_config().is_none());
+    assert_eq!(setup.contract.get_config_deadline(), 0);
+}
+
+#[test]
+fn test_revert_config() {
+    let setup = Setup::default();
+
+    setup.contract.commit_config(
+        &setup.operator,
+        &setup.router.address,
+        &setup.fee_destination,
+        &50,
+    );
+    setup.contract.revert_config(&setup.operator);
+    assert!(setup.contract.get_future_config().is_none());
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    assert!(setup.contract.try_apply_config(&setup.operator).is_err());
+    assert_eq!(setup.contract.get_max_swap_fee_fraction(), 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2906)")]
+fn test_commit_config_twice() {
+    let setup = Setup::default();
+
+    setup.contract.commit_config(
+        &setup.operator,
+        &setup.router.address,
+        &setup.fee_destination,
+        &50,
+    );
+    setup.contract.commit_config(
+        &setup.operator,
+        &setup.router.address,
+        &setup.fee_destination,
+        &60,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2904)")]
+fn test_commit_config_fee_over_denominator() {
+    let setup = Setup::default();
+    setup.contract.commit_config(
+        &setup.operator,
+        &setup.router.address,
+        &setup.fee_destination,
+        &10_000,
+    );
+}
+
+#[test]
+fn test_transfer_operator() {
+    let setup = Setup::default();
+    let new_operator = Address::generate(&setup.env);
+    setup
+        .token_a_admin_client
+        .mint(&setup.contract.address, &1_0000000);
+
+    setup.contract.commit_transfer_ownership(
+        &setup.operator,
+        &symbol_short!("Admin"),
+        &new_operator,
+    );
+    assert_eq!(
+        setup.contract.get_future_address(&symbol_short!("Admin")),
+        new_operator
+    );
+    assert!(setup
+        .contract
+        .try_apply_transfer_ownership(&setup.operator, &symbol_short!("Admin"))
+        .is_err());
+    assert_eq!(setup.contract.get_operator(), setup.operator);
+
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    setup
+        .contract
+        .apply_transfer_ownership(&setup.operator, &symbol_short!("Admin"));
+    assert_eq!(setup.contract.get_operator(), new_operator);
+    assert!(setup
+        .contract
+        .try_claim_fees(&setup.operator, &setup.token_a.address)
+        .is_err());
+    assert_eq!(
+        setup
+            .contract
+            .claim_fees(&new_operator, &setup.token_a.address),
+        1_0000000
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_transfer_emergency_admin() {
+    let setup = Setup::default();
+    setup.contract.commit_transfer_ownership(
+        &setup.operator,
+        &Symbol::new(&setup.env, "EmergencyAdmin"),
+        &Address::generate(&setup.env),
+    );
+}
+
+#[test]
+fn test_upgrade() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let new_wasm = install_dummy_wasm(e);
+    let user = Address::generate(e);
+
+    // contract deployed by the factory
+    let factory = Address::generate(e);
+    let salt = BytesN::from_array(e, &[1; 32]);
+    let contract = ProviderSwapFeeCollectorClient::new(
+        e,
+        &e.register_at(
+            &e.deployer()
+                .with_address(factory.clone(), salt.clone())
+                .deployed_address(),
+            crate::ProviderSwapFeeCollector,
+            (
+                &setup.router.address,
+                &setup.operator,
+                &setup.fee_destination,
+                100_u32,
+                10_000_u32,
+            ),
+        ),
+    );
+
+    // other factories and salts are rejected
+    assert!(contract
+        .try_commit_factory_upgrade(&Address::generate(e), &salt, &setup.operator, &new_wasm)
+        .is_err());
+    assert!(contract
+        .try_commit_factory_upgrade(
+            &factory,
+            &BytesN::from_array(e, &[2; 32]),
+            &setup.operator,
+            &new_wasm
+        )
+        .is_err());
+    assert!(contract
+        .try_commit_factory_upgrade(&factory, &salt, &user, &new_wasm)
+        .is_err());
+
+    contract.commit_factory_upgrade(&factory, &salt, &setup.operator, &new_wasm);
+    assert!(contract.try_apply_upgrade(&setup.operator).is_err());
+    jump(e, ADMIN_ACTIONS_DELAY + 1);
+    assert!(contract.try_apply_upgrade(&user).is_err());
+    contract.apply_upgrade(&setup.operator);
+    assert_eq!(contract.version(), 130);
+}
+
+#[test]
+fn test_no_direct_upgrade_entrypoints() {
+    let setup = Setup::default();
+    let e = &setup.env;
+
+    // operator can't commit an arbitrary wasm directly, nor skip the delay in emergency mode
+    assert!(e
+        .try_invoke_contract::<(), soroban_sdk::Error>(
+            &setup.contract.address,
+            &Symbol::new(e, "commit_upgrade"),
+            vec![
+                e,
+                setup.operator.into_val(e),
+                install_dummy_wasm(e).into_val(e)
+            ],
+        )
+        .is_err());
+    assert!(e
+        .try_invoke_contract::<(), soroban_sdk::Error>(
+            &setup.contract.address,
+            &Symbol::new(e, "set_emergency_mode"),
+            vec![e, setup.operator.into_val(e), true.into_val(e)],
+        )
+        .is_err());
+}
+
+#[test]
+fn test_config_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+
+    for (address, is_ok) in [(user.clone(), false), (setup.operator.clone(), true)] {
+        assert_eq!(
+            setup
+                .contract
+                .try_commit_config(&address, &setup.router.address, &setup.fee_destination, &50)
+                .is_ok(),
+            is_ok
+        );
+    }
+    for (address, is_ok) in [(user.clone(), false), (setup.operator.clone(), true)] {
+        assert_eq!(setup.contract.try_revert_config(&address).is_ok(), is_ok);
+    }
+    for (address, is_ok) in [(user.clone(), false), (setup.operator.clone(), true)] {
+        assert_eq!(
+            setup
+                .contract
+                .try_commit_transfer_ownership(&address, &symbol_short!("Admin"), &user)
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_fee_recipients_default() {
+    let setup = Setup::default();
+    assert_eq!(
+        setup.contract.get_fee_recipients(),
+        vec![&setup.env, (setup.fee_destination.clone(), 10_000)]
+    );
+    assert!(setup.contract.get_future_fee_recipients().is_none());
+}
+
+#[test]
+fn test_claim_fees_split_between_recipients() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let recipient_1 = Address::generate(e);
+    let recipient_2 = Address::generate(e);
+    let recipient_3 = Address::generate(e);
+    let recipients = vec![
+        e,
+        (recipient_1.clone(), 5000),
+        (recipient_2.clone(), 3333),
+        (recipient_3.clone(), 1667),
+    ];
+
+    setup
+        .contract
+        .commit_fee_recipients(&setup.operator, &recipients);
+    assert_eq!(
+        setup.contract.get_future_fee_recipients(),
+        Some(recipients.clone())
+    );
+    assert_eq!(
+        setup.contract.get_fee_recipients_deadline(),
+        e.ledger().timestamp() + ADMIN_ACTIONS_DELAY
+    );
+    assert!(setup
+        .contract
+        .try_apply_fee_recipients(&setup.operator, &Vec::new(&setup.env))
+        .is_err());
+    jump(e, ADMIN_ACTIONS_DELAY + 1);
+    setup
+        .contract
+        .apply_fee_recipients(&setup.operator, &Vec::new(&setup.env));
+    assert_eq!(setup.contract.get_fee_recipients(), recipients);
+    assert!(setup.contract.get_future_fee_recipients().is_none());
+    assert_eq!(setup.contract.get_fee_recipients_deadline(), 0);
+
+    setup
+        .token_a_admin_client
+        .mint(&setup.contract.address, &1_0000001);
+    assert_eq!(
+        setup
+            .contract
+            .claim_fees(&setup.operator, &setup.token_a.address),
+        1_0000001
+    );
+    // every recipient is paid its share directly
+    for (recipient, amount) in [
+        (&recipient_1, 5000000),
+        (&recipient_2, 3333000),
+        (&recipient_3, 1667001),
+        (&setup.fee_destination, 0),
+    ] {
+        assert_eq!(setup.token_a.balance(recipient), amount);
+        assert_eq!(
+            setup
+                .contract
+                .get_accrued_fee(recipient, &setup.token_a.address),
+            0
+        );
+    }
+    assert_eq!(setup.token_a.balance(&setup.contract.address), 0);
+    assert_eq!(
+        setup
+            .contract
+            .claim_fees(&setup.operator, &setup.token_a.address),
+        0
+    );
+}
+
+#[test]
+fn test_apply_fee_recipients_accrues_pending_fees() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let recipient = Address::generate(e);
+    setup
+        .token_a_admin_client
+        .mint(&setup.contract.address, &1_0000000);
+    setup
+        .contract
+        .commit_fee_recipients(&setup.operator, &vec![e, (recipient.clone(), 10_000)]);
+    jump(e, ADMIN_ACTIONS_DELAY + 1);
+    setup
+        .contract
+        .apply_fee_recipients(&setup.operator, &vec![e, setup.token_a.address.clone()]);
+
+    // fees earned under the old split stay with the former recipient
+    assert_eq!(
+        setup
+            .contract
+            .get_accrued_fee(&setup.fee_destination, &setup.token_a.address),
+        1_0000000
+    );
+    assert_eq!(
+        setup
+            .contract
+            .claim_fees(&setup.operator, &setup.token_a.address),
+        0
+    );
+    assert_eq!(
+        setup
+            .contract
+            .claim_fee_share(&recipient, &setup.token_a.address),
+        0
+    );
+    assert_eq!(
+        setup
+            .contract
+            .claim_fee_share(&setup.fee_destination, &setup.token_a.address),
+        1_0000000
+    );
+    assert_eq!(setup.token_a.balance(&setup.fee_destination), 1_0000000);
+}
+
+#[test]
+fn test_claim_fee_share() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let recipient = Address::generate(e);
+    setup.contract.commit_fee_recipients(
+        &setup.operator,
+        &vec![
+            e,
+            (setup.fee_destination.clone(), 7500),
+            (recipient.clone(), 2500),
+        ],
+    );
+    jump(e, ADMIN_ACTIONS_DELAY + 1);
+    setup
+        .contract
+        .apply_fee_recipients(&setup.operator, &Vec::new(&setup.env));
+
+    // recipient withdraws its own share without the operator, others are not paid
+    setup
+        .token_a_admin_client
+        .mint(&setup.contract.address, &1_0000000);
+    assert_eq!(
+        setup
+            .contract
+            .claim_fee_share(&recipient, &setup.token_a.address),
+        2500000
+    );
+    assert_eq!(setup.token_a.balance(&recipient), 2500000);
+    assert_eq!(setup.token_a.balance(&setup.fee_destination), 0);
+    assert_eq!(setup.token_a.balance(&setup.contract.address), 7500000);
+    assert_eq!(
+        setup
+            .contract
+            .get_accrued_fee(&setup.fee_destination, &setup.token_a.address),
+        7500000
+    );
+    assert_eq!(
+        setup
+            .contract
+            .claim_fee_share(&recipient, &setup.token_a.address),
+        0
+    );
+
+    // former recipient keeps what was accrued to it
+    setup
+        .contract
+        .commit_fee_recipients(&setup.operator, &vec![e, (recipient.clone(), 10_000)]);
+    jump(e, ADMIN_ACTIONS_DELAY + 1);
+    setup
+        .contract
+        .apply_fee_recipients(&setup.operator, &Vec::new(&setup.env));
+    setup
+        .token_a_admin_client
+        .mint(&setup.contract.address, &1_0000000);
+    assert_eq!(
+        setup
+            .contract
+            .claim_fee_share(&setup.fee_destination, &setup.token_a.address),
+        7500000
+    );
+    assert_eq!(
+        setup
+            .contract
+            .claim_fee_share(&recipient, &setup.token_a.address),
+        1_0000000
+    );
+    assert_eq!(setup.token_a.balance(&setup.fee_destination), 7500000);
+    assert_eq!(setup.token_a.balance(&recipient), 1_2500000);
+    assert_eq!(setup.token_a.balance(&setup.contract.address), 0);
+}
+
+#[test]
+fn test_claim_fee_share_not_recipient() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+    setup
+        .token_a_admin_client
+        .mint(&setup.contract.address, &1_0000000);
+    assert_eq!(
+        setup
+            .contract
+            .claim_fee_share(&user, &setup.token_a.address),
+        0
+    );
+    assert_eq!(setup.token_a.balance(&user), 0);
+    assert_eq!(
+        setup
+            .contract
+            .get_accrued_fee(&setup.fee_destination, &setup.token_a.address),
+        1_0000000
+    );
+}
+
+#[test]
+fn test_revert_fee_recipients() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    setup
+        .contract
+        .commit_fee_recipients(&setup.operator, &vec![e, (Address::generate(e), 10_000)]);
+    assert!(setup
+        .contract
+        .try_commit_fee_recipients(&setup.operator, &vec![e, (Address::generate(e), 10_000)])
+        .is_err());
+    setup.contract.revert_fee_recipients(&setup.operator);
+    assert!(setup.contract.get_future_fee_recipients().is_none());
+    jump(e, ADMIN_ACTIONS_DELAY + 1);
+    assert!(setup
+        .contract
+        .try_apply_fee_recipients(&setup.operator, &Vec::new(&setup.env))
+        .is_err());
+    assert_eq!(
+        setup.contract.get_fee_recipients(),
+        vec![e, (setup.fee_destination.clone(), 10_000)]
+    );
+}
+
+#[test]
+fn test_invalid_fee_recipients() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let recipient = Address::generate(e);
+
+    let mut too_many = Vec::new(e);
+    for _ in 0..11 {
+        too_many.push_back((Address::generate(e), 1000));
+    }
+    for recipients in [
+        Vec::new(e),
+        vec![e, (recipient.clone(), 9_999)],
+        vec![e, (recipient.clone(), 10_000), (Address::generate(e), 0)],
+        vec![e, (recipient.clone(), 5_000), (recipient.clone(), 5_000)],
+        too_many,
+    ] {
+        assert!(setup
+            .contract
+            .try_commit_fee_recipients(&setup.operator, &recipients)
+            .is_err());
+    }
+}
+
+#[test]
+fn test_fee_recipients_permissions() {
+    let setup = Setup::default();
+    let user = Address::generate(&setup.env);
+    let recipients = vec![&setup.env, (user.clone(), 10_000)];
+
+    for (address, is_ok) in [(user.clone(), false), (setup.operator.clone(), true)] {
+        assert_eq!(
+            setup
+                .contract
+                .try_commit_fee_recipients(&address, &recipients)
+                .is_ok(),
+            is_ok
+        );
+    }
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    for (address, is_ok) in [(user.clone(), false), (setup.operator.clone(), true)] {
+        assert_eq!(
+            setup
+                .contract
+                .try_apply_fee_recipients(&address, &Vec::new(&setup.env))
+                .is_ok(),
+            is_ok
+        );
+    }
+    setup
+        .contract
+        .commit_fee_recipients(&setup.operator, &recipients);
+    for (address, is_ok) in [(user.clone(), false), (setup.operator.clone(), true)] {
+        assert_eq!(
+            setup.contract.try_revert_fee_recipients(&address).is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3300)")]
+fn test_fee_recipients_shares_sum() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    setup.contract.commit_fee_recipients(
+        &setup.operator,
+        &vec![
+            e,
+            (Address::generate(e), 5000),
+            (Address::generate(e), 4000),
+        ],
+    );
+}
+
+#[test]
+fn test_swap_fee_in_input() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    let (pool_index, _) = setup.router.get_pools(&tokens).iter().next_back().unwrap();
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &1_0000000);
+    let result = setup.contract.swap(
+        &user,
+        &tokens,
+        &setup.token_a.address,
+        &setup.token_b.address,
+        &pool_index,
+        &1_0000000,
+        &0,
+        &100,
+        &false,
+    );
+    assert_eq!(result, 9870299); // (10000000 - 1%) - .3%
+    assert_eq!(setup.token_a.balance(&user), 0);
+    assert_eq!(setup.token_b.balance(&user), result as i128);
+    assert_eq!(setup.token_a.balance(&setup.contract.address), 100000);
+    assert_eq!(setup.token_b.balance(&setup.contract.address), 0);
+}
+
+#[test]
+fn test_swap_fee_in_output() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    let (pool_index, _) = setup.router.get_pools(&tokens).iter().next_back().unwrap();
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &1_0000000);
+    let result = setup.contract.swap(
+        &user,
+        &tokens,
+        &setup.token_a.address,
+        &setup.token_b.address,
+        &pool_index,
+        &1_0000000,
+        &9870299,
+        &100,
+        &true,
+    );
+    assert_eq!(result, 9870299); // (10000000 - .3%) - 1%
+    assert_eq!(setup.token_b.balance(&user), 9870299);
+    assert_eq!(setup.token_a.balance(&setup.contract.address), 0);
+    assert_eq!(setup.token_b.balance(&setup.contract.address), 99700);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2006)")]
+fn test_swap_fee_in_output_out_min() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    let (pool_index, _) = setup.router.get_pools(&tokens).iter().next_back().unwrap();
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &1_0000000);
+    setup.contract.swap(
+        &user,
+        &tokens,
+        &setup.token_a.address,
+        &setup.token_b.address,
+        &pool_index,
+        &1_0000000,
+        &9870300,
+        &100,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2904)")]
+fn test_swap_fee_over_max() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    let (pool_index, _) = setup.router.get_pools(&tokens).iter().next_back().unwrap();
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &1_0000000);
+    setup.contract.swap(
+        &user,
+        &tokens,
+        &setup.token_a.address,
+        &setup.token_b.address,
+        &pool_index,
+        &1_0000000,
+        &0,
+        &101,
+        &false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #328)")]
+fn test_swap_token_not_in_pool() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    let (pool_index, _) = setup.router.get_pools(&tokens).iter().next_back().unwrap();
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &1_0000000);
+    setup.contract.swap(
+        &user,
+        &tokens,
+        &setup.token_a.address,
+        &Address::generate(e),
+        &pool_index,
+        &1_0000000,
+        &0,
+        &100,
+        &false,
+    );
+}
+
+#[test]
+fn test_deposit_and_withdraw_fee_in_input() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    let (pool_index, pool_address) = setup.router.get_pools(&tokens).iter().next_back().unwrap();
+    let share_token =
+        SorobanTokenClient::new(e, &liquidity_pool::Client::new(e, &pool_address).share_id());
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &1_0000000);
+    setup.token_b_admin_client.mint(&user, &2_0000000);
+    let (amounts, shares) = setup.contract.deposit(
+        &user,
+        &tokens,
+        &pool_index,
+        &vec![e, 1_0000000, 2_0000000],
+        &0,
+        &100,
+        &false,
+    );
+    assert_eq!(amounts, vec![e, 9900000, 9900000]);
+    assert_eq!(shares, 9900000);
+    assert_eq!(share_token.balance(&user), shares as i128);
+    assert_eq!(setup.token_a.balance(&user), 0);
+    // unused part of token_b returned by the pool together with its part of the fee
+    assert_eq!(setup.token_b.balance(&user), 1_0000000);
+    assert_eq!(setup.token_a.balance(&setup.contract.address), 100000);
+    assert_eq!(setup.token_b.balance(&setup.contract.address), 100000);
+    assert_eq!(share_token.balance(&setup.contract.address), 0);
+
+    let amounts = setup.contract.withdraw(
+        &user,
+        &tokens,
+        &pool_index,
+        &shares,
+        &vec![e, 0, 0],
+        &100,
+        &false,
+    );
+    assert_eq!(amounts, vec![e, 9801000, 9801000]);
+    assert_eq!(share_token.balance(&user), 0);
+    assert_eq!(setup.token_a.balance(&user), 9801000);
+    assert_eq!(setup.token_b.balance(&user), 1_0000000 + 9801000);
+    assert_eq!(share_token.balance(&setup.contract.address), 99000);
+}
+
+#[test]
+fn test_deposit_unbalanced_fee_on_deposited_part() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    let (pool_index, _) = setup.router.get_pools(&tokens).iter().next_back().unwrap();
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &1_0000000);
+    setup.token_b_admin_client.mint(&user, &5_0000000);
+    let (amounts, _) = setup.contract.deposit(
+        &user,
+        &tokens,
+        &pool_index,
+        &vec![e, 1_0000000, 5_0000000],
+        &0,
+        &100,
+        &false,
+    );
+    assert_eq!(amounts, vec![e, 9900000, 9900000]);
+    // both tokens pay the same fee for the same deposited amount
+    assert_eq!(setup.token_a.balance(&setup.contract.address), 100000);
+    assert_eq!(setup.token_b.balance(&setup.contract.address), 100000);
+    assert_eq!(setup.token_a.balance(&user), 0);
+    assert_eq!(setup.token_b.balance(&user), 5_0000000 - 9900000 - 100000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #316)")]
+fn test_deposit_concentrated_pool() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    setup
+        .router
+        .set_concentrated_pool_hash(&setup.admin, &install_concentrated_liq_pool_hash(e));
+    setup.token_a_admin_client.mint(&setup.admin, &1_0000000);
+    let (pool_index, _) = setup
+        .router
+        .init_concentrated_pool(&setup.admin, &tokens, &30);
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &1_0000000);
+    setup.token_b_admin_client.mint(&user, &1_0000000);
+    setup.contract.deposit(
+        &user,
+        &tokens,
+        &pool_index,
+        &vec![e, 1_0000000, 1_0000000],
+        &0,
+        &100,
+        &false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #316)")]
+fn test_withdraw_concentrated_pool() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    setup
+        .router
+        .set_concentrated_pool_hash(&setup.admin, &install_concentrated_liq_pool_hash(e));
+    setup.token_a_admin_client.mint(&setup.admin, &1_0000000);
+    let (pool_index, _) = setup
+        .router
+        .init_concentrated_pool(&setup.admin, &tokens, &30);
+
+    let user = Address::generate(e);
+    setup.contract.withdraw(
+        &user,
+        &tokens,
+        &pool_index,
+        &1,
+        &vec![e, 0, 0],
+        &100,
+        &false,
+    );
+}
+
+#[test]
+fn test_deposit_and_withdraw_fee_in_output() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    let (pool_index, pool_address) = setup.router.get_pools(&tokens).iter().next_back().unwrap();
+    let share_token =
+        SorobanTokenClient::new(e, &liquidity_pool::Client::new(e, &pool_address).share_id());
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &1_0000000);
+    setup.token_b_admin_client.mint(&user, &1_0000000);
+    let (amounts, shares) = setup.contract.deposit(
+        &user,
+        &tokens,
+        &pool_index,
+        &vec![e, 1_0000000, 1_0000000],
+        &0,
+        &100,
+        &true,
+    );
+    assert_eq!(amounts, vec![e, 1_0000000, 1_0000000]);
+    assert_eq!(shares, 9900000);
+    assert_eq!(share_token.balance(&user), shares as i128);
+    assert_eq!(share_token.balance(&setup.contract.address), 100000);
+
+    let amounts = setup.contract.withdraw(
+        &user,
+        &tokens,
+        &pool_index,
+        &shares,
+        &vec![e, 0, 0],
+        &100,
+        &true,
+    );
+    assert_eq!(amounts, vec![e, 9801000, 9801000]);
+    assert_eq!(share_token.balance(&user), 0);
+    assert_eq!(setup.token_a.balance(&user), 9801000);
+    assert_eq!(setup.token_b.balance(&user), 9801000);
+    assert_eq!(setup.token_a.balance(&setup.contract.address), 99000);
+    assert_eq!(setup.token_b.balance(&setup.contract.address), 99000);
+}
+
+#[test]
+fn test_pool_calls_authorized_by_contract() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let tokens = vec![
+        e,
+        setup.token_a.address.clone(),
+        setup.token_b.address.clone(),
+    ];
+    let (pool_index, pool_address) = setup.router.get_pools(&tokens).iter().next_back().unwrap();
+    let share_token =
+        SorobanTokenClient::new(e, &liquidity_pool::Client::new(e, &pool_address).share_id());
+
+    let user = Address::generate(e);
+    setup.token_a_admin_client.mint(&user, &2_0000000);
+    setup.token_b_admin_client.mint(&user, &1_0000000);
+
+    // only user authorization is mocked, pool calls must be authorized by the contract itself
+    e.mock_auths(&[MockAuth {
+        address: &user,
+        invoke: &MockAuthInvoke {
+            contract: &setup.contract.address,
+            fn_name: "swap",
+            args: (
+                user.clone(),
+                tokens.clone(),
+                setup.token_a.address.clone(),
+                setup.token_b.address.clone(),
+                pool_index.clone(),
+                1_0000000_u128,
+                0_u128,
+                100_u32,
+                false,
+            )
+                .into_val(e),
+            sub_invokes: &[MockAuthInvoke {
+                contract: &setup.token_a.address,
+                fn_name: "transfer",
+                args: (user.clone(), setup.contract.address.clone(), 1_0000000_i128).into_val(e),
+                sub_invokes: &[],
+            }],
+        },
+    }]);
+    setup.contract.swap(
+        &user,
+        &tokens,
+        &setup.token_a.address,
+        &setup.token_b.address,
+        &pool_index,
+        &1_0000000,
+        &0,
+        &100,
+        &false,
+    );
+
+    let desired_amounts = vec![e, 1_0000000_u128, 1_0000000_u128];
+    e.mock_auths(&[MockAuth {
+        address: &user,
+        invoke: &MockAuthInvoke {
+            contract: &setup.contract.address,
+            fn_name: "deposit",
+            args: (
+                user.clone(),
+                tokens.clone(),
+                pool_index.clone(),
+                desired_amounts.clone(),
+                0_u128,
+                100_u32,
+                false,
+            )
+                .into_val(e),
+            sub_invokes: &[
+                MockAuthInvoke {
+                    contract: &setup.token_a.address,
+                    fn_name: "transfer",
+                    args: (user.clone(), setup.contract.address.clone(), 1_0000000_i128)
+                        .into_val(e),
+                    sub_invokes: &[],
+                },
+                MockAuthInvoke {
+                    contract: &setup.token_b.address,
+                    fn_name: "transfer",
+                    args: (user.clone(), setup.contract.address.clone(), 1_0000000_i128)
+                        .into_val(e),
+                    sub_invokes: &[],
+                },
+            ],
+        },
+    }]);
+    let (_, shares) = setup.contract.deposit(
+        &user,
+        &tokens,
+        &pool_index,
+        &desired_amounts,
+        &0,
+        &100,
+        &false,
+    );
+
+    let min_amounts = vec![e, 0_u128, 0_u128];
+    e.mock_auths(&[MockAuth {
+        address: &user,
+        invoke: &MockAuthInvoke {
+            contract: &setup.contract.address,
+            fn_name: "withdraw",
+            args: (
+                user.clone(),
+                tokens.clone(),
+                pool_index.clone(),
+                shares,
+                min_amounts.clone(),
+                100_u32,
+                true,
+            )
+                .into_val(e),
+            sub_invokes: &[MockAuthInvoke {
+                contract: &share_token.address,
+                fn_name: "transfer",
+                args: (user.clone(), setup.contract.address.clone(), shares as i128).into_val(e),
+                sub_invokes: &[],
+            }],
+        },
+    }]);
+    setup.contract.withdraw(
+        &user,
+        &tokens,
+        &pool_index,
+        &shares,
+        &min_amounts,
+        &100,
+        &true,
+    );
+    assert_eq!(share_token.balance(&user), 0);
+}
@@ -191,6 +191,13 @@ pub(crate) fn install_stableswap_liq_pool_hash(e: &Env) -> BytesN<32> {
     e.deployer().upload_contract_wasm(WASM)
 }
 
+pub(crate) fn install_concentrated_liq_pool_hash(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../contracts/soroban_liquidity_pool_concentrated_contract.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
 pub(crate) fn deploy_plane_contract(e: &Env) -> Address {
     soroban_sdk::contractimport!(file = "../contracts/soroban_liquidity_pool_plane_contract.wasm");
     Client::new(e, &e.register(WASM, ())).address
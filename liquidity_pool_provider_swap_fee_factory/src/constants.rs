@@ -0,0 +1,2 @@
+// max number of contracts returned by a get_deployed_contracts page
+pub(crate) const MAX_DEPLOYED_CONTRACTS_PAGE_SIZE: u32 = 50;
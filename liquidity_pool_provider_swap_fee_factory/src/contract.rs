@@ -1,6 +1,9 @@
+use crate::constants::MAX_DEPLOYED_CONTRACTS_PAGE_SIZE;
+use crate::errors::FactoryError;
 use crate::events::{Events, FactoryConfigEvents, FactoryEvents};
 use crate::storage::{
-    get_contract_sequence, get_fee_contract_wasm, get_router, set_contract_sequence,
+    get_contract_salt, get_contract_sequence, get_deployed_contract, get_fee_contract_wasm,
+    get_router, set_contract_salt, set_contract_sequence, set_deployed_contract,
     set_fee_contract_wasm, set_router,
 };
 use access_control::access::{AccessControl, AccessControlTrait};
@@ -13,7 +16,7 @@ use access_control::role::{Role, SymbolRepresentation};
 use access_control::transfer::TransferOwnershipTrait;
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, Address, Bytes, BytesN, Env, Symbol, Vec,
+    contract, contractimpl, panic_with_error, Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec,
 };
 use upgrade::events::Events as UpgradeEvents;
 use upgrade::interface::UpgradeableContract;
@@ -88,19 +91,19 @@ impl ProviderSwapFeeFactory {
         let mut salt = Bytes::new(&e);
         salt.append(&operator.clone().to_xdr(&e));
         salt.append(&sequence.to_xdr(&e));
-        let address = e
-            .deployer()
-            .with_current_contract(e.crypto().sha256(&salt))
-            .deploy_v2(
-                get_fee_contract_wasm(&e),
-                (
-                    get_router(&e),
-                    operator.clone(),
-                    fee_destination.clone(),
-                    max_swap_fee_fraction,
-                    swap_fee_fraction_denominator,
-                ),
-            );
+        let salt = e.crypto().sha256(&salt).to_bytes();
+        let address = e.deployer().with_current_contract(salt.clone()).deploy_v2(
+            get_fee_contract_wasm(&e),
+            (
+                get_router(&e),
+                operator.clone(),
+                fee_destination.clone(),
+                max_swap_fee_fraction,
+                swap_fee_fraction_denominator,
+            ),
+        );
+        set_deployed_contract(&e, operator.clone(), sequence, &address);
+        set_contract_salt(&e, address.clone(), &salt);
         Events::new(&e).deploy(
             operator,
             fee_destination,
@@ -110,6 +113,92 @@ impl ProviderSwapFeeFactory {
         );
         address
     }
+
+    // get_deployed_contracts_count
+    // Returns the number of swap fee contracts deployed for the operator.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - operator: The address of the operator.
+    //
+    // Returns:
+    //   - The number of deployed contracts.
+    pub fn get_deployed_contracts_count(e: Env, operator: Address) -> u32 {
+        get_contract_sequence(&e, operator)
+    }
+
+    // get_deployed_contracts
+    // Returns the swap fee contracts deployed for the operator in deployment order.
+    // Contracts deployed before the registry was introduced are omitted.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - operator: The address of the operator.
+    //   - offset: The index of the first contract to return.
+    //   - limit: The maximum number of contracts to return.
+    //     Capped at MAX_DEPLOYED_CONTRACTS_PAGE_SIZE.
+    //
+    // Returns:
+    //   - A vector of deployed contract addresses.
+    pub fn get_deployed_contracts(
+        e: Env,
+        operator: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        let count = get_contract_sequence(&e, operator.clone());
+        let end = offset
+            .saturating_add(limit.min(MAX_DEPLOYED_CONTRACTS_PAGE_SIZE))
+            .min(count);
+        let mut result = Vec::new(&e);
+        for index in offset..end {
+            if let Some(address) = get_deployed_contract(&e, operator.clone(), index) {
+                result.push_back(address);
+            }
+        }
+        result
+    }
+
+    // upgrade_swap_fee_contract
+    // Commits an upgrade of the deployed swap fee contract to the current factory WASM.
+    // The contract accepts upgrades only from the factory that deployed it.
+    // The upgrade can be applied on the contract by its operator after the upgrade delay.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - operator: The current operator of the swap fee contract (must be authorized).
+    //   - swap_fee_contract: The address of the contract deployed by the factory.
+    //
+    // Returns:
+    //   - The WASM hash (BytesN<32>) committed for the upgrade.
+    pub fn upgrade_swap_fee_contract(
+        e: Env,
+        operator: Address,
+        swap_fee_contract: Address,
+    ) -> BytesN<32> {
+        operator.require_auth();
+        let salt = match get_contract_salt(&e, swap_fee_contract.clone()) {
+            Some(salt) => salt,
+            None => panic_with_error!(&e, FactoryError::ContractNotDeployed),
+        };
+
+        let new_wasm = get_fee_contract_wasm(&e);
+        e.invoke_contract::<()>(
+            &swap_fee_contract,
+            &Symbol::new(&e, "commit_factory_upgrade"),
+            Vec::from_array(
+                &e,
+                [
+                    e.current_contract_address().to_val(),
+                    salt.to_val(),
+                    operator.to_val(),
+                    new_wasm.into_val(&e),
+                ],
+            ),
+        );
+        Events::new(&e).upgrade_contract(operator, swap_fee_contract, new_wasm.clone());
+        new_wasm
+    }
 }
 
 #[contractimpl]
@@ -0,0 +1,8 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone)]
+#[repr(u32)]
+pub enum FactoryError {
+    ContractNotDeployed = 3200,
+}
@@ -24,6 +24,8 @@ pub(crate) trait FactoryEvents {
         swap_fee_fraction_denominator: u32,
         address: Address,
     );
+
+    fn upgrade_contract(&self, operator: Address, address: Address, new_wasm: BytesN<32>);
 }
 
 pub(crate) trait FactoryConfigEvents {
@@ -50,6 +52,13 @@ impl FactoryEvents for Events {
             ),
         );
     }
+
+    fn upgrade_contract(&self, operator: Address, address: Address, new_wasm: BytesN<32>) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "upgrade_contract"),),
+            (operator, address, new_wasm),
+        );
+    }
 }
 
 impl FactoryConfigEvents for Events {
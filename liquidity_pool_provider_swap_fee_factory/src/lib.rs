@@ -1,6 +1,8 @@
 #![no_std]
 
+mod constants;
 mod contract;
+mod errors;
 mod events;
 mod storage;
 mod test;
@@ -13,6 +13,8 @@ enum DataKey {
     Router,
     FeeContractWASM,
     ContractSequence(Address),
+    DeployedContract(Address, u32),
+    ContractSalt(Address),
 }
 
 generate_instance_storage_getter_and_setter!(router, DataKey::Router, Address);
@@ -38,3 +40,34 @@ pub(crate) fn set_contract_sequence(env: &Env, operator: Address, sequence: u32)
     env.storage().persistent().set(&key, &sequence);
     bump_persistent(env, &key);
 }
+
+pub(crate) fn get_deployed_contract(env: &Env, operator: Address, index: u32) -> Option<Address> {
+    let key = DataKey::DeployedContract(operator, index);
+    let value = env.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(env, &key);
+    }
+    value
+}
+
+pub(crate) fn set_deployed_contract(env: &Env, operator: Address, index: u32, address: &Address) {
+    let key = DataKey::DeployedContract(operator, index);
+    env.storage().persistent().set(&key, address);
+    bump_persistent(env, &key);
+}
+
+// Salt of the contract deployed by the factory, none for contracts deployed elsewhere.
+pub(crate) fn get_contract_salt(env: &Env, address: Address) -> Option<BytesN<32>> {
+    let key = DataKey::ContractSalt(address);
+    let value = env.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(env, &key);
+    }
+    value
+}
+
+pub(crate) fn set_contract_salt(env: &Env, address: Address, salt: &BytesN<32>) {
+    let key = DataKey::ContractSalt(address);
+    env.storage().persistent().set(&key, salt);
+    bump_persistent(env, &key);
+}
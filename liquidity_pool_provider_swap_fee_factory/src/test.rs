@@ -1,6 +1,7 @@
 #![cfg(test)]
 extern crate std;
 
+use crate::storage::get_contract_salt;
 use crate::testutils::{swap_fee_collector, Setup};
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Address, Vec};
@@ -91,3 +92,113 @@ fn test_deploy_multiple_times() {
             .deploy_swap_fee_contract(&operator, &fee_destination, &100, &10_000);
     assert_ne!(swap_fee_collector_1, swap_fee_collector_2,);
 }
+
+#[test]
+fn test_deployed_contracts_registry() {
+    let setup = Setup::default();
+
+    let operator = Address::generate(&setup.env);
+    let other_operator = Address::generate(&setup.env);
+    let fee_destination = Address::generate(&setup.env);
+    let mut deployed = Vec::new(&setup.env);
+    for _ in 0..3 {
+        deployed.push_back(setup.contract.deploy_swap_fee_contract(
+            &operator,
+            &fee_destination,
+            &100,
+            &10_000,
+        ));
+    }
+    let other_contract =
+        setup
+            .contract
+            .deploy_swap_fee_contract(&other_operator, &fee_destination, &100, &10_000);
+
+    assert_eq!(setup.contract.get_deployed_contracts_count(&operator), 3);
+    assert_eq!(
+        setup.contract.get_deployed_contracts(&operator, &0, &10),
+        deployed
+    );
+    assert_eq!(
+        setup.contract.get_deployed_contracts(&operator, &1, &1),
+        Vec::from_array(&setup.env, [deployed.get(1).unwrap()])
+    );
+    assert_eq!(
+        setup.contract.get_deployed_contracts(&operator, &2, &10),
+        Vec::from_array(&setup.env, [deployed.get(2).unwrap()])
+    );
+    assert_eq!(
+        setup.contract.get_deployed_contracts(&operator, &3, &10),
+        Vec::new(&setup.env)
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_deployed_contracts(&other_operator, &0, &10),
+        Vec::from_array(&setup.env, [other_contract])
+    );
+}
+
+#[test]
+fn test_get_deployed_contracts_page_size() {
+    let setup = Setup::default();
+    let operator = Address::generate(&setup.env);
+    let fee_destination = Address::generate(&setup.env);
+    for _ in 0..51 {
+        setup
+            .contract
+            .deploy_swap_fee_contract(&operator, &fee_destination, &100, &10_000);
+    }
+
+    assert_eq!(setup.contract.get_deployed_contracts_count(&operator), 51);
+    assert_eq!(
+        setup
+            .contract
+            .get_deployed_contracts(&operator, &0, &u32::MAX)
+            .len(),
+        50
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_deployed_contracts(&operator, &50, &u32::MAX)
+            .len(),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3200)")]
+fn test_upgrade_not_deployed_contract() {
+    let setup = Setup::default();
+
+    let operator = Address::generate(&setup.env);
+    setup
+        .contract
+        .upgrade_swap_fee_contract(&operator, &Address::generate(&setup.env));
+}
+
+#[test]
+fn test_deployed_contract_salt() {
+    let setup = Setup::default();
+
+    let operator = Address::generate(&setup.env);
+    let fee_destination = Address::generate(&setup.env);
+    let swap_fee_collector =
+        setup
+            .contract
+            .deploy_swap_fee_contract(&operator, &fee_destination, &100, &10_000);
+
+    // the salt passed on upgrades proves the contract was deployed by the factory
+    let salt = setup.env.as_contract(&setup.contract.address, || {
+        get_contract_salt(&setup.env, swap_fee_collector.clone()).unwrap()
+    });
+    assert_eq!(
+        setup
+            .env
+            .deployer()
+            .with_address(setup.contract.address.clone(), salt)
+            .deployed_address(),
+        swap_fee_collector
+    );
+}
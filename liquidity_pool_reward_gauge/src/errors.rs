@@ -8,4 +8,5 @@ pub enum GaugeError {
     GaugesOverMax = 305,
     GaugeAlreadyExists = 401,
     GaugeNotFound = 404,
+    GaugeNotPaused = 405,
 }
@@ -40,6 +40,23 @@ impl GaugeEvents {
             .publish((Symbol::new(self.env(), "rewards_gauge_unkill_claim"),), ())
     }
 
+    pub fn pause(&self, reward_token: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "rewards_gauge_pause"), reward_token),
+            (),
+        )
+    }
+
+    pub fn unpause(&self, reward_token: Address) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "rewards_gauge_unpause"),
+                reward_token,
+            ),
+            (),
+        )
+    }
+
     pub fn schedule_reward(
         &self,
         reward_token: Address,
@@ -56,6 +73,44 @@ impl GaugeEvents {
         )
     }
 
+    pub fn cancel_reward(&self, reward_token: Address, config_id: u32, refund: u128) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "rewards_gauge_cancel_reward"),
+                reward_token,
+            ),
+            (config_id, refund),
+        )
+    }
+
+    pub fn extend_reward(
+        &self,
+        reward_token: Address,
+        config_id: u32,
+        extra_duration: u64,
+        extra_tps: u128,
+        amount: u128,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "rewards_gauge_extend_reward"),
+                reward_token,
+            ),
+            (config_id, extra_duration, extra_tps, amount),
+        )
+    }
+
+    pub fn reclaim_unallocated(&self, reward_token: Address, distributor: Address, amount: u128) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "rewards_gauge_reclaim_unallocated"),
+                reward_token,
+                distributor,
+            ),
+            (amount,),
+        )
+    }
+
     pub fn claim(&self, user: Address, reward_token: Address, amount: u128) {
         self.env().events().publish(
             (
@@ -66,4 +121,26 @@ impl GaugeEvents {
             (amount,),
         )
     }
+
+    pub fn claim_skipped(&self, user: Address, reward_token: Address) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "rewards_gauge_claim_skipped"),
+                reward_token,
+                user,
+            ),
+            (),
+        )
+    }
+
+    pub fn claim_to(&self, user: Address, recipient: Address, reward_token: Address, amount: u128) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "rewards_gauge_claim_to"),
+                reward_token,
+                user,
+            ),
+            (recipient, amount),
+        )
+    }
 }
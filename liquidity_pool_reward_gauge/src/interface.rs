@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, Map, Symbol};
+use soroban_sdk::{Address, Env, Map, Symbol, Vec};
 
 pub trait RewardsGaugeInterface {
     // Admin functions
@@ -8,7 +8,7 @@ pub trait RewardsGaugeInterface {
     // Removes a rewards gauge from the contract by reward token address.
     fn gauge_remove(e: Env, admin: Address, reward_token: Address);
 
-    // Schedules a reward for a specific gauge.
+    // Schedules a reward for a specific gauge. Returns the id of the scheduled config.
     fn gauge_schedule_reward(
         e: Env,
         router: Address,
@@ -17,7 +17,32 @@ pub trait RewardsGaugeInterface {
         start_at: Option<u64>,
         duration: u64,
         tps: u128,
-    );
+    ) -> u32;
+
+    // Cancels the reward config scheduled by the distributor and refunds the remaining amount.
+    fn gauge_cancel_reward(
+        e: Env,
+        router: Address,
+        distributor: Address,
+        gauge: Address,
+        config_id: u32,
+    ) -> u128;
+
+    // Extends the reward config scheduled by the distributor.
+    fn gauge_extend_reward(
+        e: Env,
+        router: Address,
+        distributor: Address,
+        gauge: Address,
+        config_id: u32,
+        extra_duration: u64,
+        extra_tps: u128,
+    ) -> u128;
+
+    // Returns rewards of the distributor's configs generated while nobody was staked.
+    // Configs expired since the last pool activity are rolled over with the current
+    // working supply, so nobody has to touch the pool first.
+    fn gauge_reclaim_unallocated(e: Env, distributor: Address, reward_token: Address) -> u128;
 
     // Kills the gauges claim functionality, preventing users from claiming rewards.
     fn kill_gauges_claim(e: Env, admin: Address);
@@ -25,13 +50,35 @@ pub trait RewardsGaugeInterface {
     // Restores the gauges claim functionality, allowing users to claim rewards again.
     fn unkill_gauges_claim(e: Env, admin: Address);
 
+    // Pauses claims of a single gauge by reward token address.
+    fn pause_gauge(e: Env, admin: Address, reward_token: Address);
+
+    // Resumes claims of the paused gauge.
+    fn unpause_gauge(e: Env, admin: Address, reward_token: Address);
+
     // Public functions
     // Lists all reward gauges.
     fn get_gauges(e: Env) -> Map<Address, Address>;
 
+    // Lists reward tokens of the paused gauges.
+    fn get_paused_gauges(e: Env) -> Vec<Address>;
+
     // Claims rewards for a user across all gauges.
+    // Paused gauges, listed by get_paused_gauges, are missing from the result and each skip
+    // emits a claim_skipped event.
     fn gauges_claim(e: Env, user: Address) -> Map<Address, u128>;
 
+    // Claims rewards for a user across all gauges and sends them to the recipient.
+    // Paused gauges are missing from the result, each skip emits a claim_skipped event.
+    // The caller must be the user or the user's delegated claimer, passed explicitly
+    // since a claim routed through the router has the router as invoker.
+    fn gauges_claim_to(
+        e: Env,
+        caller: Address,
+        user: Address,
+        recipient: Address,
+    ) -> Map<Address, u128>;
+
     // Rewards info getter
     fn gauges_get_reward_info(e: Env, user: Address) -> Map<Address, Map<Symbol, i128>>;
 }
@@ -2,8 +2,8 @@ use crate::constants::MAX_GAUGES;
 use crate::errors::GaugeError;
 use crate::events::GaugeEvents;
 use crate::storage::{
-    get_is_killed_gauges_claim, get_reward_gauges, set_is_killed_gauges_claim, set_reward_gauges,
-    RewardConfig,
+    get_is_killed_gauges_claim, get_paused_gauges, get_reward_gauges, set_is_killed_gauges_claim,
+    set_paused_gauges, set_reward_gauges, RewardConfig,
 };
 use soroban_sdk::{panic_with_error, Address, BytesN, Env, IntoVal, Map, Symbol, Val, Vec};
 
@@ -32,6 +32,11 @@ pub fn remove(e: &Env, reward_token: Address) {
     } else {
         panic_with_error!(e, GaugeError::GaugeNotFound);
     }
+    let mut paused_gauges = get_paused_gauges(e);
+    if let Some(index) = paused_gauges.first_index_of(reward_token.clone()) {
+        paused_gauges.remove(index);
+        set_paused_gauges(e, &paused_gauges);
+    }
     GaugeEvents::new(e).remove(reward_token, gauge_address);
 }
 
@@ -58,6 +63,36 @@ pub fn unkill_claim(e: &Env) {
     GaugeEvents::new(e).unkill_claim();
 }
 
+// Pauses claims of a single gauge, e.g. when its reward token misbehaves.
+// Other gauges keep working.
+pub fn pause(e: &Env, reward_token: Address) {
+    if !get_reward_gauges(e).contains_key(reward_token.clone()) {
+        panic_with_error!(e, GaugeError::GaugeNotFound);
+    }
+    let mut paused_gauges = get_paused_gauges(e);
+    if !paused_gauges.contains(reward_token.clone()) {
+        paused_gauges.push_back(reward_token.clone());
+        set_paused_gauges(e, &paused_gauges);
+    }
+    GaugeEvents::new(e).pause(reward_token);
+}
+
+pub fn unpause(e: &Env, reward_token: Address) {
+    let mut paused_gauges = get_paused_gauges(e);
+    match paused_gauges.first_index_of(reward_token.clone()) {
+        Some(index) => {
+            paused_gauges.remove(index);
+            set_paused_gauges(e, &paused_gauges);
+        }
+        None => panic_with_error!(e, GaugeError::GaugeNotPaused),
+    }
+    GaugeEvents::new(e).unpause(reward_token);
+}
+
+pub fn list_paused(e: &Env) -> Vec<Address> {
+    get_paused_gauges(e)
+}
+
 pub fn list(e: &Env) -> Map<Address, Address> {
     get_reward_gauges(e)
 }
@@ -85,27 +120,79 @@ pub fn claim(
     user: &Address,
     working_balance: u128,
     working_supply: u128,
+) -> Map<Address, u128> {
+    claim_gauges(e, user, None, working_balance, working_supply)
+}
+
+// Claims rewards of the user across all gauges and sends them to `recipient`.
+pub fn claim_to(
+    e: &Env,
+    user: &Address,
+    recipient: &Address,
+    working_balance: u128,
+    working_supply: u128,
+) -> Map<Address, u128> {
+    claim_gauges(e, user, Some(recipient), working_balance, working_supply)
+}
+
+fn claim_gauges(
+    e: &Env,
+    user: &Address,
+    recipient: Option<&Address>,
+    working_balance: u128,
+    working_supply: u128,
 ) -> Map<Address, u128> {
     if get_is_killed_gauges_claim(e) {
         panic_with_error!(e, GaugeError::ClaimKilled);
     }
 
+    // paused gauges are left out of the result and reported with a claim_skipped event.
+    // rewards keep accruing there and can be claimed after unpause
+    let paused_gauges = get_paused_gauges(e);
     let mut result = Map::new(e);
     for (reward_token, gauge) in get_reward_gauges(e) {
-        let claimed_amount = e.invoke_contract(
-            &gauge,
-            &Symbol::new(e, "claim"),
-            Vec::from_array(
-                e,
-                [
-                    e.current_contract_address().to_val(),
-                    user.to_val(),
-                    working_balance.into_val(e),
-                    working_supply.into_val(e),
-                ],
+        if paused_gauges.contains(reward_token.clone()) {
+            GaugeEvents::new(e).claim_skipped(user.clone(), reward_token);
+            continue;
+        }
+        let claimed_amount = match recipient {
+            None => e.invoke_contract(
+                &gauge,
+                &Symbol::new(e, "claim"),
+                Vec::from_array(
+                    e,
+                    [
+                        e.current_contract_address().to_val(),
+                        user.to_val(),
+                        working_balance.into_val(e),
+                        working_supply.into_val(e),
+                    ],
+                ),
             ),
-        );
-        GaugeEvents::new(e).claim(user.clone(), reward_token.clone(), claimed_amount);
+            Some(recipient) => e.invoke_contract(
+                &gauge,
+                &Symbol::new(e, "claim_to"),
+                Vec::from_array(
+                    e,
+                    [
+                        e.current_contract_address().to_val(),
+                        user.to_val(),
+                        recipient.to_val(),
+                        working_balance.into_val(e),
+                        working_supply.into_val(e),
+                    ],
+                ),
+            ),
+        };
+        match recipient {
+            None => GaugeEvents::new(e).claim(user.clone(), reward_token.clone(), claimed_amount),
+            Some(recipient) => GaugeEvents::new(e).claim_to(
+                user.clone(),
+                recipient.clone(),
+                reward_token.clone(),
+                claimed_amount,
+            ),
+        }
         result.set(reward_token, claimed_amount);
     }
     result
@@ -161,8 +248,8 @@ pub fn schedule_rewards_config(
     duration: u64,
     tps: u128,
     working_supply: u128,
-) {
-    e.invoke_contract::<()>(
+) -> u32 {
+    let config_id: u32 = e.invoke_contract(
         &gauge,
         &Symbol::new(e, "schedule_rewards_config"),
         Vec::from_array(
@@ -183,5 +270,92 @@ pub fn schedule_rewards_config(
         start_at,
         start_at + duration,
         tps,
-    )
+    );
+    config_id
+}
+
+pub fn cancel_rewards_config(
+    e: &Env,
+    gauge: Address,
+    distributor: Address,
+    config_id: u32,
+    working_supply: u128,
+) -> u128 {
+    let refund: u128 = e.invoke_contract(
+        &gauge,
+        &Symbol::new(e, "cancel_scheduled_reward"),
+        Vec::from_array(
+            e,
+            [
+                e.current_contract_address().to_val(),
+                distributor.to_val(),
+                config_id.into_val(e),
+                working_supply.into_val(e),
+            ],
+        ),
+    );
+    GaugeEvents::new(e).cancel_reward(crate::token::get_reward_token(e, &gauge), config_id, refund);
+    refund
+}
+
+pub fn extend_rewards_config(
+    e: &Env,
+    gauge: Address,
+    distributor: Address,
+    config_id: u32,
+    extra_duration: u64,
+    extra_tps: u128,
+    working_supply: u128,
+) -> u128 {
+    let amount: u128 = e.invoke_contract(
+        &gauge,
+        &Symbol::new(e, "extend_reward"),
+        Vec::from_array(
+            e,
+            [
+                e.current_contract_address().to_val(),
+                distributor.to_val(),
+                config_id.into_val(e),
+                extra_duration.into_val(e),
+                extra_tps.into_val(e),
+                working_supply.into_val(e),
+            ],
+        ),
+    );
+    GaugeEvents::new(e).extend_reward(
+        crate::token::get_reward_token(e, &gauge),
+        config_id,
+        extra_duration,
+        extra_tps,
+        amount,
+    );
+    amount
+}
+
+// Returns the distributor's unallocated rewards from the gauge of the reward token.
+// The working supply lets the gauge roll over configs expired since the last checkpoint.
+pub fn reclaim_unallocated(
+    e: &Env,
+    reward_token: Address,
+    distributor: Address,
+    working_supply: u128,
+) -> u128 {
+    let gauge = match get_reward_gauges(e).get(reward_token.clone()) {
+        Some(gauge) => gauge,
+        None => panic_with_error!(e, GaugeError::GaugeNotFound),
+    };
+    let amount: u128 = e.invoke_contract(
+        &gauge,
+        &Symbol::new(e, "reclaim_unallocated"),
+        Vec::from_array(
+            e,
+            [
+                e.current_contract_address().to_val(),
+                distributor.to_val(),
+                working_supply.into_val(e),
+            ],
+        ),
+    );
+    GaugeEvents::new(e).reclaim_unallocated(reward_token, distributor, amount);
+    amount
 }
@@ -1,5 +1,5 @@
 use paste::paste;
-use soroban_sdk::{contracttype, Address, Env, Map};
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
 use utils::bump::bump_instance;
 use utils::{
     generate_instance_storage_getter_and_setter_with_default,
@@ -18,6 +18,7 @@ pub struct RewardConfig {
 enum DataKey {
     RewardGaugesMap,
     IsKilledGaugesClaim,
+    PausedGauges,
 }
 
 generate_instance_storage_getter_and_setter_with_default!(
@@ -39,3 +40,17 @@ pub(crate) fn set_reward_gauges(e: &Env, value: &Map<Address, Address>) {
     bump_instance(e);
     e.storage().instance().set(&DataKey::RewardGaugesMap, value)
 }
+
+// Reward tokens of the gauges with paused claims.
+pub(crate) fn get_paused_gauges(e: &Env) -> Vec<Address> {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::PausedGauges)
+        .unwrap_or(Vec::new(e))
+}
+
+pub(crate) fn set_paused_gauges(e: &Env, value: &Vec<Address>) {
+    bump_instance(e);
+    e.storage().instance().set(&DataKey::PausedGauges, value)
+}
@@ -0,0 +1,28 @@
+use crate::pool_utils::assert_tokens_sorted;
+use crate::storage::get_pool;
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Map, Symbol, Vec};
+
+// Syncs the user's working balance with the current boost in each pool.
+// A failing pool fails the whole call, including pools deployed before `update_boost`.
+//
+// # Returns
+//
+// A map of pool address to the new working balance of the user.
+pub(crate) fn update_boost_for_pools(
+    e: &Env,
+    user: &Address,
+    pools: &Vec<(Vec<Address>, BytesN<32>)>,
+) -> Map<Address, u128> {
+    let mut result = Map::new(e);
+    for (tokens, pool_index) in pools.iter() {
+        assert_tokens_sorted(e, &tokens);
+        let pool_id = get_pool(e, &tokens, pool_index);
+        let working_balance = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(e, "update_boost"),
+            Vec::from_array(e, [user.into_val(e)]),
+        );
+        result.set(pool_id, working_balance);
+    }
+    result
+}
@@ -0,0 +1,110 @@
+use crate::constants::{
+    CONCENTRATED_DEFAULT_FEE_TIERS, CONCENTRATED_FEE_DENOMINATOR, CONCENTRATED_MAX_TICK_SPACING,
+};
+use crate::errors::LiquidityPoolRouterError;
+use crate::storage::{ConcentratedFeeTier, DataKey};
+use access_control::transfer::{
+    apply_delayed_action, commit_delayed_action, get_delayed_action_deadline, revert_delayed_action,
+};
+use liquidity_pool_config_storage as config_storage;
+use rewards::concentrated_weight::{is_valid_max_distance, max_distance_for_fee};
+use soroban_sdk::{panic_with_error, Address, Env, IntoVal, Map, TryFromVal};
+use utils::bump::bump_instance;
+use utils::storage_errors::StorageError;
+
+// Fee tier registry for concentrated pools, kept in config storage.
+// Until the first tier is added the built-in tiers are served; adding a tier is timelocked
+// by ADMIN_ACTIONS_DELAY through commit/apply with the delayed-action helpers.
+
+fn default_fee_tiers(e: &Env) -> Map<u32, ConcentratedFeeTier> {
+    let mut tiers = Map::new(e);
+    for (fee, tick_spacing) in CONCENTRATED_DEFAULT_FEE_TIERS {
+        tiers.set(
+            fee,
+            ConcentratedFeeTier {
+                fee,
+                tick_spacing,
+                max_distance: max_distance_for_fee(fee),
+            },
+        );
+    }
+    tiers
+}
+
+pub(crate) fn get_concentrated_fee_tiers(e: &Env) -> Map<u32, ConcentratedFeeTier> {
+    match config_storage::operations::get_value_safe(e, DataKey::ConcentratedFeeTiers.into_val(e)) {
+        Some(value) => match Map::<u32, ConcentratedFeeTier>::try_from_val(e, &value) {
+            Ok(tiers) => tiers,
+            Err(_) => panic_with_error!(e, StorageError::ValueConversionError),
+        },
+        None => default_fee_tiers(e),
+    }
+}
+
+pub(crate) fn get_concentrated_fee_tier(e: &Env, fee: u32) -> ConcentratedFeeTier {
+    match get_concentrated_fee_tiers(e).get(fee) {
+        Some(tier) => tier,
+        None => panic_with_error!(e, LiquidityPoolRouterError::BadFee),
+    }
+}
+
+// Committed tier waiting for the timelock. Kept in the router storage, so only
+// commit_concentrated_fee_tier can schedule it.
+pub(crate) fn get_future_concentrated_fee_tier(e: &Env) -> Option<ConcentratedFeeTier> {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::FutureFeeTiers)
+}
+
+fn set_future_concentrated_fee_tier(e: &Env, tier: &Option<ConcentratedFeeTier>) {
+    bump_instance(e);
+    match tier {
+        Some(tier) => e.storage().instance().set(&DataKey::FutureFeeTiers, tier),
+        None => e.storage().instance().remove(&DataKey::FutureFeeTiers),
+    }
+}
+
+// Timestamp after which the pending tier can be applied, 0 if nothing is pending.
+pub(crate) fn get_concentrated_fee_tier_deadline(e: &Env) -> u64 {
+    get_delayed_action_deadline(e, &DataKey::FutureFeeTiersDeadline)
+}
+
+pub(crate) fn commit_concentrated_fee_tier(e: &Env, tier: &ConcentratedFeeTier) {
+    if tier.fee == 0 || tier.fee >= CONCENTRATED_FEE_DENOMINATOR {
+        panic_with_error!(e, LiquidityPoolRouterError::BadFee);
+    }
+    if tier.tick_spacing <= 0 || tier.tick_spacing > CONCENTRATED_MAX_TICK_SPACING {
+        panic_with_error!(e, LiquidityPoolRouterError::BadTickSpacing);
+    }
+    if !is_valid_max_distance(tier.max_distance) {
+        panic_with_error!(e, LiquidityPoolRouterError::BadMaxDistance);
+    }
+    if get_concentrated_fee_tiers(e).contains_key(tier.fee) {
+        panic_with_error!(e, LiquidityPoolRouterError::FeeTierAlreadyExists);
+    }
+    commit_delayed_action(e, &DataKey::FutureFeeTiersDeadline);
+    set_future_concentrated_fee_tier(e, &Some(tier.clone()));
+}
+
+pub(crate) fn apply_concentrated_fee_tier(e: &Env, admin: &Address) -> ConcentratedFeeTier {
+    apply_delayed_action(e, &DataKey::FutureFeeTiersDeadline);
+    let tier = match get_future_concentrated_fee_tier(e) {
+        Some(tier) => tier,
+        None => panic_with_error!(e, StorageError::ValueNotInitialized),
+    };
+
+    let mut tiers = get_concentrated_fee_tiers(e);
+    tiers.set(tier.fee, tier.clone());
+    config_storage::operations::set_value(
+        e,
+        admin,
+        DataKey::ConcentratedFeeTiers.into_val(e),
+        tiers.into_val(e),
+    );
+    set_future_concentrated_fee_tier(e, &None);
+    tier
+}
+
+pub(crate) fn revert_concentrated_fee_tier(e: &Env) {
+    revert_delayed_action(e, &DataKey::FutureFeeTiersDeadline);
+    set_future_concentrated_fee_tier(e, &None);
+}
@@ -1,6 +1,11 @@
+use crate::boost::update_boost_for_pools;
+use crate::concentrated_fee_tiers::{
+    apply_concentrated_fee_tier, commit_concentrated_fee_tier, get_concentrated_fee_tier,
+    get_concentrated_fee_tier_deadline, get_concentrated_fee_tiers,
+    get_future_concentrated_fee_tier, revert_concentrated_fee_tier,
+};
 use crate::constants::{
-    CONCENTRATED_FEE_AVAILABLE, CONSTANT_PRODUCT_FEE_AVAILABLE, STABLESWAP_DEFAULT_A,
-    STABLESWAP_MAX_FEE, STABLESWAP_MAX_TOKENS,
+    CONSTANT_PRODUCT_FEE_AVAILABLE, STABLESWAP_DEFAULT_A, STABLESWAP_MAX_FEE, STABLESWAP_MAX_TOKENS,
 };
 use crate::errors::LiquidityPoolRouterError;
 use crate::events::{Events, LiquidityPoolRouterEvents};
@@ -11,30 +16,38 @@ use crate::pool_interface::{
 };
 use crate::pool_utils::{
     assert_tokens_sorted, deploy_concentrated_pool, deploy_stableswap_pool, deploy_standard_pool,
-    get_concentrated_pool_salt, get_stableswap_pool_salt, get_standard_pool_salt, get_tokens_salt,
-    get_total_liquidity, validate_tokens_contracts,
+    execute_swaps_chain, get_concentrated_pool_salt, get_stableswap_pool_salt,
+    get_standard_pool_salt, get_tokens_salt, initialize_concentrated_pool_price,
+    validate_tokens_contracts,
 };
+use crate::reinvest::claim_and_reinvest;
+use crate::reward_history::{get_pools_reward_snapshots, get_user_reward_claims};
 use crate::rewards::get_rewards_manager;
+use crate::rewards_epoch::{
+    advance_rewards_epoch, configure_pool_rewards, fill_tokens_liquidity, record_votes_snapshot,
+    set_global_rewards,
+};
 use crate::rewards_gauge::{
     calculate_equivalent_reward, deploy_rewards_gauge, gauge_get_reward_duration_threshold,
     gauge_get_reward_per_day_threshold, gauge_set_reward_duration_threshold,
-    gauge_set_reward_per_day_threshold,
+    gauge_set_reward_per_day_threshold, get_gauge_scheduled_reward, get_pool_gauge,
 };
 use crate::router_interface::AdminInterface;
 use crate::storage::{
-    get_concentrated_pool_hash, get_gauge_rewards_enabled_for,
-    get_init_concentrated_pool_payment_amount, get_init_pool_payment_address,
-    get_init_pool_payment_token, get_init_stable_pool_payment_amount,
-    get_init_standard_pool_payment_amount, get_liquidity_calculator, get_pool, get_pool_plane,
-    get_pools_plain, get_protocol_fee_fraction, get_reward_tokens, get_reward_tokens_detailed,
-    get_rewards_config, get_tokens_set, get_tokens_set_count, has_pool, remove_pool,
-    set_concentrated_pool_hash, set_constant_product_pool_hash, set_gauge_rewards_enabled_for,
+    get_concentrated_pool_creator, get_concentrated_pool_hash, get_gauge_price_feed,
+    get_gauge_rewards_enabled_for, get_init_concentrated_pool_payment_amount,
+    get_init_pool_payment_address, get_init_pool_payment_token,
+    get_init_stable_pool_payment_amount, get_init_standard_pool_payment_amount,
+    get_liquidity_calculator, get_pool, get_pool_plane, get_pools_plain, get_protocol_fee_fraction,
+    get_reward_tokens, get_rewards_config, get_rewards_epoch, get_rewards_epoch_cursor,
+    get_rewards_votes_feed, get_tokens_set, get_tokens_set_count, get_votes_snapshot, has_pool,
+    remove_pool, set_concentrated_pool_creator, set_concentrated_pool_hash,
+    set_constant_product_pool_hash, set_gauge_price_feed, set_gauge_rewards_enabled_for,
     set_init_concentrated_pool_payment_amount, set_init_pool_payment_address,
     set_init_pool_payment_token, set_init_stable_pool_payment_amount,
     set_init_standard_pool_payment_amount, set_liquidity_calculator, set_pool_plane,
-    set_protocol_fee_fraction, set_reward_tokens, set_reward_tokens_detailed, set_rewards_config,
-    set_stableswap_pool_hash, set_token_hash, DataKey, GlobalRewardsConfig,
-    LiquidityPoolRewardInfo,
+    set_protocol_fee_fraction, set_rewards_votes_feed, set_stableswap_pool_hash, set_token_hash,
+    ConcentratedFeeTier, DataKey, VotesSnapshot,
 };
 use access_control::access::{AccessControl, AccessControlTrait};
 use access_control::emergency::{get_emergency_mode, set_emergency_mode};
@@ -48,7 +61,10 @@ use access_control::transfer::TransferOwnershipTrait;
 use access_control::utils::{require_operations_admin_or_owner, require_rewards_admin_or_owner};
 use liquidity_pool_config_storage as config_storage;
 use liquidity_pool_config_storage::interface::ConfigStorageInterface;
-use rewards::storage::{BoostFeedStorageTrait, BoostTokenStorageTrait, RewardTokenStorageTrait};
+use rewards::storage::{
+    BoostFeedStorageTrait, BoostTokenStorageTrait, RewardClaimRecord, RewardEpochSnapshot,
+    RewardTokenStorageTrait,
+};
 use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
 use soroban_sdk::token::Client as SorobanTokenClient;
 use soroban_sdk::{
@@ -58,7 +74,6 @@ use soroban_sdk::{
 use upgrade::events::Events as UpgradeEvents;
 use upgrade::interface::UpgradeableContract;
 use upgrade::{apply_upgrade, commit_upgrade, revert_upgrade};
-use utils::storage_errors::StorageError;
 
 #[contract]
 pub struct LiquidityPoolRouter;
@@ -815,36 +830,7 @@ impl RewardsInterfaceTrait for LiquidityPoolRouter {
     ) {
         user.require_auth();
         require_rewards_admin_or_owner(&e, &user);
-
-        let mut tokens_with_liquidity = Map::new(&e);
-        for (tokens, voting_share) in tokens_votes {
-            assert_tokens_sorted(&e, &tokens);
-
-            tokens_with_liquidity.set(
-                tokens,
-                LiquidityPoolRewardInfo {
-                    voting_share,
-                    processed: false,
-                    total_liquidity: U256::from_u32(&e, 0),
-                },
-            );
-        }
-        let mut sum = 0;
-        for (_, reward_info) in tokens_with_liquidity.iter() {
-            sum += reward_info.voting_share;
-        }
-        if sum > 1_0000000 {
-            panic_with_error!(e, LiquidityPoolRouterError::VotingShareExceedsMax);
-        }
-
-        set_reward_tokens(&e, &tokens_with_liquidity);
-        set_rewards_config(
-            &e,
-            &GlobalRewardsConfig {
-                tps: reward_tps,
-                expired_at,
-            },
-        )
+        set_global_rewards(&e, reward_tps, expired_at, &tokens_votes);
     }
 
     // Fills the aggregated liquidity information for a given set of tokens.
@@ -856,30 +842,7 @@ impl RewardsInterfaceTrait for LiquidityPoolRouter {
     fn fill_liquidity(e: Env, admin: Address, tokens: Vec<Address>) {
         admin.require_auth();
         require_rewards_admin_or_owner(&e, &admin);
-
-        assert_tokens_sorted(&e, &tokens);
-        let tokens_salt = get_tokens_salt(&e, &tokens);
-        let calculator = get_liquidity_calculator(&e);
-        let (pools, total_liquidity) = get_total_liquidity(&e, &tokens, calculator);
-
-        let mut pools_with_processed_info = Map::new(&e);
-        for (key, value) in pools {
-            pools_with_processed_info.set(key, (value, false));
-        }
-
-        let mut tokens_with_liquidity = get_reward_tokens(&e);
-        let mut token_data = match tokens_with_liquidity.get(tokens.clone()) {
-            Some(v) => v,
-            None => panic_with_error!(e, LiquidityPoolRouterError::TokensAreNotForReward),
-        };
-        if token_data.processed {
-            panic_with_error!(e, LiquidityPoolRouterError::LiquidityAlreadyFilled);
-        }
-        token_data.processed = true;
-        token_data.total_liquidity = total_liquidity;
-        tokens_with_liquidity.set(tokens, token_data);
-        set_reward_tokens(&e, &tokens_with_liquidity);
-        set_reward_tokens_detailed(&e, tokens_salt, &pools_with_processed_info);
+        fill_tokens_liquidity(&e, &tokens);
     }
 
     // Configures the rewards for a specific pool.
@@ -912,76 +875,76 @@ impl RewardsInterfaceTrait for LiquidityPoolRouter {
     ) -> u128 {
         admin.require_auth();
         require_rewards_admin_or_owner(&e, &admin);
+        configure_pool_rewards(&e, &tokens, &pool_index)
+    }
 
-        assert_tokens_sorted(&e, &tokens);
-        let pool_id = get_pool(&e, &tokens, pool_index.clone());
-
-        let rewards_config = get_rewards_config(&e);
-        let tokens_salt = get_tokens_salt(&e, &tokens);
-        let mut tokens_detailed = get_reward_tokens_detailed(&e, tokens_salt.clone());
-        let tokens_reward = get_reward_tokens(&e);
-        let tokens_reward_info = tokens_reward.get(tokens.clone());
-
-        let (pool_liquidity, pool_configured) = if tokens_reward_info.is_some() {
-            tokens_detailed
-                .get(pool_index.clone())
-                .unwrap_or((U256::from_u32(&e, 0), false))
-        } else {
-            (U256::from_u32(&e, 0), false)
-        };
-
-        if pool_configured {
-            panic_with_error!(&e, LiquidityPoolRouterError::RewardsAlreadyConfigured);
-        }
+    // Sets the address allowed to record votes snapshots, e.g. a feed contract
+    // mirroring AQUA voting results.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `feed` - The votes feed address.
+    fn set_rewards_votes_feed(e: Env, admin: Address, feed: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_rewards_votes_feed(&e, &feed);
+        Events::new(&e).set_rewards_votes_feed(feed);
+    }
 
-        let reward_info = match tokens_reward_info {
-            Some(v) => v,
-            // if tokens not found in current config, deactivate them
-            None => LiquidityPoolRewardInfo {
-                voting_share: 0,
-                processed: true,
-                total_liquidity: U256::from_u32(&e, 0),
-            },
-        };
+    fn get_rewards_votes_feed(e: Env) -> Option<Address> {
+        get_rewards_votes_feed(&e)
+    }
 
-        if !reward_info.processed {
-            panic_with_error!(&e, LiquidityPoolRouterError::LiquidityNotFilled);
+    // Records vote weights per token set for the next rewards epoch.
+    // The epoch keeps the reward tps and expiration set by `config_global_rewards`.
+    //
+    // # Arguments
+    //
+    // * `feed` - The votes feed address, see `set_rewards_votes_feed`.
+    // * `tokens_votes` - Voting share per token set, 0_0000000 .. 1_0000000.
+    //
+    // # Returns
+    //
+    // The epoch number of the recorded snapshot.
+    fn record_votes_snapshot(e: Env, feed: Address, tokens_votes: Vec<(Vec<Address>, u32)>) -> u64 {
+        feed.require_auth();
+        if get_rewards_votes_feed(&e) != Some(feed) {
+            panic_with_error!(&e, AccessControlError::Unauthorized);
         }
-        // it's safe to convert tps to u128 since it cannot be bigger than total tps which is u128
-        let pool_tps = if pool_liquidity > U256::from_u32(&e, 0) {
-            U256::from_u128(&e, rewards_config.tps)
-                .mul(&U256::from_u32(&e, reward_info.voting_share))
-                .mul(&pool_liquidity)
-                .div(&reward_info.total_liquidity)
-                .div(&U256::from_u32(&e, 1_0000000))
-                .to_u128()
-                .unwrap()
-        } else {
-            0
-        };
+        let token_sets = tokens_votes.len();
+        let epoch = record_votes_snapshot(&e, tokens_votes);
+        Events::new(&e).record_votes_snapshot(epoch, token_sets);
+        epoch
+    }
 
-        e.invoke_contract::<Val>(
-            &pool_id,
-            &Symbol::new(&e, "set_rewards_config"),
-            Vec::from_array(
-                &e,
-                [
-                    e.current_contract_address().to_val(),
-                    rewards_config.expired_at.into_val(&e),
-                    pool_tps.into_val(&e),
-                ],
-            ),
-        );
+    fn get_votes_snapshot(e: Env) -> Option<VotesSnapshot> {
+        get_votes_snapshot(&e)
+    }
 
-        if pool_tps > 0 {
-            // mark pool as configured to avoid reentrancy
-            tokens_detailed.set(pool_index, (pool_liquidity, true));
-            set_reward_tokens_detailed(&e, tokens_salt, &tokens_detailed);
-        }
+    fn get_rewards_epoch(e: Env) -> u64 {
+        get_rewards_epoch(&e)
+    }
 
-        Events::new(&e).config_rewards(tokens, pool_id, pool_tps, rewards_config.expired_at);
+    // Fills liquidity and configures pools for the current rewards configuration. Permissionless,
+    // replaces calling `fill_liquidity` and `config_pool_rewards` one by one.
+    // A newer votes snapshot, if any, is activated first; `get_votes_snapshot` shows the pending one.
+    //
+    // # Arguments
+    //
+    // * `cursor` - Index of the first token set to process, see `get_rewards_epoch_cursor`.
+    //   If it differs from the stored progress nothing is processed.
+    // * `batch_size` - Number of token sets to process.
+    //
+    // # Returns
+    //
+    // The cursor to continue from. Equal to the number of token sets once all are processed.
+    fn advance_rewards_epoch(e: Env, cursor: u32, batch_size: u32) -> u32 {
+        advance_rewards_epoch(&e, cursor, batch_size)
+    }
 
-        pool_tps
+    fn get_rewards_epoch_cursor(e: Env) -> u32 {
+        get_rewards_epoch_cursor(&e)
     }
 
     // Get rewards status for the pool, including amount available for the user
@@ -1218,6 +1181,148 @@ impl RewardsInterfaceTrait for LiquidityPoolRouter {
 
         amount
     }
+
+    // Claims the reward of the user to the recipient.
+    // The pool checks that the caller is the user or the user's delegated claimer.
+    // The caller is passed explicitly since the pool sees the router as the invoker
+    // and has to know whose authorization to require.
+    //
+    // # Arguments
+    //
+    // * `e` - The environment.
+    // * `caller` - The user or the user's delegated claimer.
+    // * `user` - The address of the user.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    // * `recipient` - The address receiving the reward tokens.
+    //
+    // # Returns
+    //
+    // The amount of tokens rewarded as a u128.
+    fn claim_to(
+        e: Env,
+        caller: Address,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        recipient: Address,
+    ) -> u128 {
+        caller.require_auth();
+        assert_tokens_sorted(&e, &tokens);
+
+        let pool_id = get_pool(&e, &tokens, pool_index);
+
+        let amount = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "claim_to"),
+            Vec::from_array(
+                &e,
+                [
+                    caller.into_val(&e),
+                    user.clone().into_val(&e),
+                    recipient.into_val(&e),
+                ],
+            ),
+        );
+
+        Events::new(&e).claim(
+            tokens,
+            user,
+            pool_id,
+            get_rewards_manager(&e).storage().get_reward_token(),
+            amount,
+        );
+
+        amount
+    }
+
+    // Claims the reward and deposits it back into the same pool.
+    //
+    // # Arguments
+    //
+    // * `e` - The environment.
+    // * `user` - The address of the user.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    // * `swaps_chains` - Pairs of the claimed token and the swaps converting it into a pool token.
+    //   Each swap is a tuple of pool tokens, pool index hash and the token to obtain.
+    //   Empty swaps deposit the claimed token as is.
+    // * `min_shares` - The minimum amount of pool shares to mint.
+    //
+    // # Returns
+    //
+    // A tuple containing the amounts of each token deposited and the amount of shares minted.
+    fn claim_and_reinvest(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        swaps_chains: Vec<(Address, Vec<(Vec<Address>, BytesN<32>, Address)>)>,
+        min_shares: u128,
+    ) -> (Vec<u128>, u128) {
+        user.require_auth();
+        claim_and_reinvest(&e, &user, &tokens, pool_index, &swaps_chains, min_shares)
+    }
+
+    // Returns reward snapshots of the pools recorded on reward config changes.
+    //
+    // # Arguments
+    //
+    // * `pools` - (tokens, pool_index) pairs of the pools.
+    // * `offset` - Index of the first snapshot of each pool.
+    // * `limit` - Maximum number of snapshots per pool.
+    //
+    // # Returns
+    //
+    // A map of pool address to its page of snapshots, oldest first.
+    fn get_pools_reward_snapshots(
+        e: Env,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+        offset: u32,
+        limit: u32,
+    ) -> Map<Address, Vec<RewardEpochSnapshot>> {
+        get_pools_reward_snapshots(&e, &pools, offset, limit)
+    }
+
+    // Returns reward claims of the user in the pools.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user.
+    // * `pools` - (tokens, pool_index) pairs of the pools.
+    // * `offset` - Index of the first claim in each pool.
+    // * `limit` - Maximum number of claims per pool.
+    //
+    // # Returns
+    //
+    // A map of pool address to its page of the user's claims, oldest first.
+    fn get_user_reward_claims(
+        e: Env,
+        user: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+        offset: u32,
+        limit: u32,
+    ) -> Map<Address, Vec<RewardClaimRecord>> {
+        get_user_reward_claims(&e, &user, &pools, offset, limit)
+    }
+
+    // Recalculates the user's working balance in the pools after a lock change.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user.
+    // * `pools` - (tokens, pool_index) pairs of the pools.
+    //
+    // # Returns
+    //
+    // A map of pool address to the new working balance of the user.
+    fn update_boost_for_pools(
+        e: Env,
+        user: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+    ) -> Map<Address, u128> {
+        update_boost_for_pools(&e, &user, &pools)
+    }
 }
 
 // The `PoolsManagementTrait` trait provides the interface for managing liquidity pools.
@@ -1339,8 +1444,11 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
     //
     // * `user` - The address of the user initializing the pool.
     // * `tokens` - Exactly two token addresses of the pool.
-    // * `fee` - Fee tier: must be one of 10 (0.1%), 30 (0.3%), 100 (1.0%).
-    //   Tick spacing is derived automatically from the fee tier.
+    // * `fee` - Fee tier: must be registered in the fee tier registry
+    //   (10 (0.1%), 30 (0.3%), 100 (1.0%) by default). Tick spacing is taken from the tier.
+    // * `sqrt_price_x96` - Optional starting sqrt(price) in Q64.96 format, so the first deposits
+    //   can be one-sided. Set in the same call the pool is deployed, before anyone can deposit.
+    //   Ignored if the pool already exists.
     //
     // # Returns
     //
@@ -1352,6 +1460,7 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
         user: Address,
         tokens: Vec<Address>,
         fee: u32,
+        sqrt_price_x96: Option<U256>,
     ) -> (BytesN<32>, Address) {
         user.require_auth();
         validate_tokens_contracts(&e, &tokens);
@@ -1360,16 +1469,12 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
         if tokens.len() != 2 {
             panic_with_error!(&e, LiquidityPoolRouterError::UnsupportedTokensNum);
         }
-        if !CONCENTRATED_FEE_AVAILABLE.contains(&fee) {
-            panic_with_error!(&e, LiquidityPoolRouterError::BadFee);
-        }
-
-        let tick_spacing = crate::constants::concentrated_tick_spacing(fee);
+        let tier = get_concentrated_fee_tier(&e, fee);
 
         let _hash = get_concentrated_pool_hash(&e);
         let salt = get_tokens_salt(&e, &tokens);
         let pools = get_pools_plain(&e, salt);
-        let pool_index = get_concentrated_pool_salt(&e, &fee, &tick_spacing);
+        let pool_index = get_concentrated_pool_salt(&e, &fee);
 
         match pools.get(pool_index.clone()) {
             Some(pool_address) => (pool_index, pool_address),
@@ -1385,11 +1490,109 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
                     );
                 }
 
-                deploy_concentrated_pool(&e, &tokens, fee, tick_spacing)
+                let (pool_index, pool_address) = deploy_concentrated_pool(&e, &tokens, &tier);
+                set_concentrated_pool_creator(&e, pool_address.clone(), &user);
+                if let Some(sqrt_price_x96) = sqrt_price_x96 {
+                    initialize_concentrated_pool_price(&e, &pool_address, &sqrt_price_x96);
+                }
+                (pool_index, pool_address)
             }
         }
     }
 
+    // Sets the starting price of an empty concentrated pool so the first deposits
+    // can be one-sided ranges. Can be called once, by the address that created the pool.
+    // Fails once anyone deposited, prefer passing the price to `init_concentrated_pool`.
+    //
+    // # Arguments
+    //
+    // * `user` - The address that created the pool.
+    // * `tokens` - The pool tokens.
+    // * `pool_index` - The pool index hash.
+    // * `sqrt_price_x96` - Initial sqrt(price) in Q64.96 format.
+    fn init_concentrated_pool_price(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        sqrt_price_x96: U256,
+    ) {
+        user.require_auth();
+        let pool_id = get_pool(&e, &tokens, pool_index);
+        if get_concentrated_pool_creator(&e, pool_id.clone()) != Some(user) {
+            panic_with_error!(&e, AccessControlError::Unauthorized);
+        }
+
+        initialize_concentrated_pool_price(&e, &pool_id, &sqrt_price_x96);
+    }
+
+    // Commits a new concentrated fee tier. It can be applied after ADMIN_ACTIONS_DELAY.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `fee` - Fee in basis points, 1 = 0.01%. Must not be registered yet.
+    // * `tick_spacing` - Tick spacing for pools of this tier.
+    // * `max_distance` - Distance in ticks at which out-of-range positions stop earning rewards.
+    fn commit_concentrated_fee_tier(
+        e: Env,
+        admin: Address,
+        fee: u32,
+        tick_spacing: i32,
+        max_distance: u32,
+    ) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        commit_concentrated_fee_tier(
+            &e,
+            &ConcentratedFeeTier {
+                fee,
+                tick_spacing,
+                max_distance,
+            },
+        );
+        Events::new(&e).commit_concentrated_fee_tier(fee, tick_spacing, max_distance);
+    }
+
+    // Adds the committed fee tier to the registry once the timelock has passed.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    fn apply_concentrated_fee_tier(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        let tier = apply_concentrated_fee_tier(&e, &admin);
+        Events::new(&e).apply_concentrated_fee_tier(tier.fee, tier.tick_spacing, tier.max_distance);
+    }
+
+    // Discards the committed fee tier.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    fn revert_concentrated_fee_tier(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        revert_concentrated_fee_tier(&e);
+        Events::new(&e).revert_concentrated_fee_tier();
+    }
+
+    // Returns the registered concentrated fee tiers keyed by fee.
+    fn get_concentrated_fee_tiers(e: Env) -> Map<u32, ConcentratedFeeTier> {
+        get_concentrated_fee_tiers(&e)
+    }
+
+    // Returns the committed fee tier waiting for the timelock, if any.
+    fn get_future_concentrated_fee_tier(e: Env) -> Option<ConcentratedFeeTier> {
+        get_future_concentrated_fee_tier(&e)
+    }
+
+    // Returns the timestamp after which the committed fee tier can be applied, 0 if none.
+    fn get_conc_fee_tier_deadline(e: Env) -> u64 {
+        get_concentrated_fee_tier_deadline(&e)
+    }
+
     // Returns a map of pools for given set of tokens.
     //
     // # Arguments
@@ -1501,6 +1704,26 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
         gauge_get_reward_duration_threshold(&e)
     }
 
+    // Sets the price feed used to value gauge rewards in the token.
+    // With the feed configured, swaps chain proof becomes optional for the token.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `token` - The gauge reward token.
+    // * `feed` - The price feed address. `None` removes the feed.
+    fn pool_gauge_set_price_feed(e: Env, admin: Address, token: Address, feed: Option<Address>) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        set_gauge_price_feed(&e, &token, &feed);
+        Events::new(&e).pool_gauge_set_price_feed(token, feed);
+    }
+
+    fn pool_gauge_get_price_feed(e: Env, token: Address) -> Option<Address> {
+        get_gauge_price_feed(&e, &token)
+    }
+
     // Switches the rewards gauge for a specific pool token.
     fn pool_gauge_switch_token(e: Env, admin: Address, token: Address, enabled: bool) {
         admin.require_auth();
@@ -1515,6 +1738,11 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
     }
 
     // Schedules an additional LP reward for a specific pool through rewards gauge.
+    //
+    // # Returns
+    //
+    // The rewards gauge address and the id of the scheduled config,
+    // to be used with pool_gauge_cancel_reward and pool_gauge_extend_reward.
     fn pool_gauge_schedule_reward(
         e: Env,
         distributor: Address,
@@ -1525,7 +1753,7 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
         start_at: Option<u64>,
         duration: u64,
         swaps_chain_proof: Vec<(Vec<Address>, BytesN<32>, Address)>,
-    ) -> Address {
+    ) -> (Address, u32) {
         distributor.require_auth();
 
         let pool = get_pool(&e, &pool_tokens, pool_hash);
@@ -1585,8 +1813,8 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
         //         start_at: Option<u64>,
         //         duration: u64,
         //         tps: u128,
-        //     )
-        e.invoke_contract::<Val>(
+        //     ) -> u32
+        let config_id: u32 = e.invoke_contract(
             &pool,
             &Symbol::new(&e, "gauge_schedule_reward"),
             Vec::from_array(
@@ -1601,7 +1829,105 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
                 ],
             ),
         );
-        rewards_gauge
+        (rewards_gauge, config_id)
+    }
+
+    // Cancels the gauge reward scheduled by the distributor.
+    // Not started reward is refunded in full, running one - pro-rata.
+    // Running reward can't be cut below the minimum duration threshold.
+    //
+    // # Returns
+    //
+    // The refunded amount.
+    fn pool_gauge_cancel_reward(
+        e: Env,
+        distributor: Address,
+        pool_tokens: Vec<Address>,
+        pool_hash: BytesN<32>,
+        reward_token: Address,
+        config_id: u32,
+    ) -> u128 {
+        distributor.require_auth();
+
+        let (pool, rewards_gauge) = get_pool_gauge(&e, &pool_tokens, pool_hash, &reward_token);
+
+        // verify shortened duration against minimum threshold
+        let config = get_gauge_scheduled_reward(&e, &rewards_gauge, config_id);
+        let now = e.ledger().timestamp();
+        if config.start_at < now && now - config.start_at < gauge_get_reward_duration_threshold(&e)
+        {
+            panic_with_error!(e, LiquidityPoolRouterError::RewardDurationTooShort);
+        }
+
+        e.invoke_contract(
+            &pool,
+            &Symbol::new(&e, "gauge_cancel_reward"),
+            Vec::from_array(
+                &e,
+                [
+                    e.current_contract_address().to_val(),
+                    distributor.into_val(&e),
+                    rewards_gauge.into_val(&e),
+                    config_id.into_val(&e),
+                ],
+            ),
+        )
+    }
+
+    // Extends the gauge reward scheduled by the distributor.
+    // Updated reward should satisfy the same thresholds as the newly scheduled one.
+    //
+    // # Returns
+    //
+    // The amount charged from the distributor.
+    fn pool_gauge_extend_reward(
+        e: Env,
+        distributor: Address,
+        pool_tokens: Vec<Address>,
+        pool_hash: BytesN<32>,
+        reward_token: Address,
+        config_id: u32,
+        extra_duration: u64,
+        extra_tps: u128,
+        swaps_chain_proof: Vec<(Vec<Address>, BytesN<32>, Address)>,
+    ) -> u128 {
+        distributor.require_auth();
+
+        let (pool, rewards_gauge) = get_pool_gauge(&e, &pool_tokens, pool_hash, &reward_token);
+        let config = get_gauge_scheduled_reward(&e, &rewards_gauge, config_id);
+
+        // verify duration against minimum threshold
+        let duration = config.expired_at + extra_duration - config.start_at;
+        if duration < gauge_get_reward_duration_threshold(&e) {
+            panic_with_error!(e, LiquidityPoolRouterError::RewardDurationTooShort);
+        }
+
+        // verify equivalent reward per day in reward token using swaps chain proof
+        let reward_equivalent_per_day = calculate_equivalent_reward(
+            &e,
+            &reward_token,
+            (config.tps + extra_tps) * 86400, // reward per day
+            swaps_chain_proof,
+        );
+        if reward_equivalent_per_day < gauge_get_reward_per_day_threshold(&e) {
+            panic_with_error!(e, LiquidityPoolRouterError::RewardAmountTooLow);
+        }
+
+        e.invoke_contract(
+            &pool,
+            &Symbol::new(&e, "gauge_extend_reward"),
+            Vec::from_array(
+                &e,
+                [
+                    e.current_contract_address().to_val(),
+                    distributor.into_val(&e),
+                    rewards_gauge.into_val(&e),
+                    config_id.into_val(&e),
+                    extra_duration.into_val(&e),
+                    extra_tps.into_val(&e),
+                ],
+            ),
+        )
     }
 }
 
@@ -1656,105 +1982,7 @@ impl CombinedSwapInterface for LiquidityPoolRouter {
         out_min: u128,
     ) -> u128 {
         user.require_auth();
-        let mut last_token_out: Option<Address> = None;
-        let mut last_swap_result = 0;
-
-        if swaps_chain.len() == 0 {
-            panic_with_error!(&e, LiquidityPoolRouterError::PathIsEmpty);
-        }
-
-        SorobanTokenClient::new(&e, &token_in).transfer(
-            &user,
-            &e.current_contract_address(),
-            &(in_amount as i128),
-        );
-
-        for i in 0..swaps_chain.len() {
-            let (tokens, pool_index, token_out) = swaps_chain.get(i).unwrap();
-            assert_tokens_sorted(&e, &tokens);
-
-            let pool_id = get_pool(&e, &tokens, pool_index);
-
-            let mut out_min_local = 0;
-            let token_in_local;
-            let in_amount_local;
-            if i == 0 {
-                token_in_local = token_in.clone();
-                in_amount_local = in_amount;
-            } else {
-                token_in_local = match last_token_out {
-                    Some(v) => v,
-                    None => panic_with_error!(&e, StorageError::ValueNotInitialized),
-                };
-                in_amount_local = last_swap_result;
-            }
-
-            if i == swaps_chain.len() - 1 {
-                out_min_local = out_min;
-            }
-
-            e.authorize_as_current_contract(vec![
-                &e,
-                InvokerContractAuthEntry::Contract(SubContractInvocation {
-                    context: ContractContext {
-                        contract: token_in_local.clone(),
-                        fn_name: Symbol::new(&e, "transfer"),
-                        args: (
-                            e.current_contract_address(),
-                            pool_id.clone(),
-                            in_amount_local as i128,
-                        )
-                            .into_val(&e),
-                    },
-                    sub_invocations: vec![&e],
-                }),
-            ]);
-
-            last_swap_result = e.invoke_contract(
-                &pool_id,
-                &symbol_short!("swap"),
-                Vec::from_array(
-                    &e,
-                    [
-                        e.current_contract_address().into_val(&e),
-                        tokens
-                            .first_index_of(token_in_local.clone())
-                            .unwrap()
-                            .into_val(&e),
-                        tokens
-                            .first_index_of(token_out.clone())
-                            .unwrap()
-                            .into_val(&e),
-                        in_amount_local.into_val(&e),
-                        out_min_local.into_val(&e),
-                    ],
-                ),
-            );
-
-            Events::new(&e).swap(
-                tokens,
-                user.clone(),
-                pool_id,
-                token_in_local.clone(),
-                token_out.clone(),
-                in_amount_local,
-                last_swap_result,
-            );
-
-            last_token_out = Some(token_out);
-        }
-
-        let token_out_address = match last_token_out {
-            Some(v) => v,
-            None => panic_with_error!(&e, StorageError::ValueNotInitialized),
-        };
-        SorobanTokenClient::new(&e, &token_out_address).transfer(
-            &e.current_contract_address(),
-            &user,
-            &(last_swap_result as i128),
-        );
-
-        last_swap_result
+        execute_swaps_chain(&e, &user, &swaps_chain, &token_in, in_amount, out_min)
     }
 
     // Executes a chain of token swaps to exchange an input token for an output token.
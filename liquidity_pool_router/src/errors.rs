@@ -27,6 +27,11 @@ pub enum LiquidityPoolRouterError {
     ConcentratedPoolsOverMax = 322,
     RewardTokenChangeWhileActive = 323,
     SwapChainAmountIsZero = 324,
+    BadTickSpacing = 325,
+    FeeTierAlreadyExists = 326,
+    TokenNotInPool = 328, // swaps chain doesn't end with a pool token
+    GaugeNotFound = 329,  // pool has no gauge for the reward token
+    BadMaxDistance = 330, // rewards decay distance out of bounds
 
     TokensNotSorted = 2002,
     InMaxNotSatisfied = 2020,
@@ -74,6 +74,22 @@ pub(crate) trait LiquidityPoolRouterEvents {
     fn set_protocol_fee_fraction(&self, fraction: u32);
 
     fn pool_gauge_switch_token(&self, token: Address, enabled: bool);
+
+    fn pool_gauge_set_price_feed(&self, token: Address, feed: Option<Address>);
+
+    fn commit_concentrated_fee_tier(&self, fee: u32, tick_spacing: i32, max_distance: u32);
+
+    fn apply_concentrated_fee_tier(&self, fee: u32, tick_spacing: i32, max_distance: u32);
+
+    fn revert_concentrated_fee_tier(&self);
+
+    fn set_rewards_votes_feed(&self, feed: Address);
+
+    fn record_votes_snapshot(&self, epoch: u64, token_sets: u32);
+
+    fn roll_rewards_epoch(&self, epoch: u64, reward_tps: u128, expired_at: u64);
+
+    fn rewards_epoch_completed(&self, epoch: u64, token_sets: u32);
 }
 
 impl LiquidityPoolRouterEvents for Events {
@@ -185,4 +201,95 @@ impl LiquidityPoolRouterEvents for Events {
             (enabled,),
         )
     }
+
+    fn pool_gauge_set_price_feed(&self, token: Address, feed: Option<Address>) {
+        let e = self.env();
+        e.events().publish(
+            (Symbol::new(e, "pool_gauge_set_price_feed"), token),
+            (feed,),
+        )
+    }
+
+    fn commit_concentrated_fee_tier(&self, fee: u32, tick_spacing: i32, max_distance: u32) {
+        // topics
+        // [
+        //   "commit_concentrated_fee_tier": Symbol, // event identifier
+        //   fee: u32, // fee tier, 30 = 0.3%
+        // ]
+        //
+        // body
+        // [
+        //   tick_spacing: i32,
+        //   max_distance: u32, // rewards decay distance in ticks
+        // ]
+        let e = self.env();
+        e.events().publish(
+            (Symbol::new(e, "commit_concentrated_fee_tier"), fee),
+            (tick_spacing, max_distance),
+        )
+    }
+
+    fn apply_concentrated_fee_tier(&self, fee: u32, tick_spacing: i32, max_distance: u32) {
+        let e = self.env();
+        e.events().publish(
+            (Symbol::new(e, "apply_concentrated_fee_tier"), fee),
+            (tick_spacing, max_distance),
+        )
+    }
+
+    fn revert_concentrated_fee_tier(&self) {
+        let e = self.env();
+        e.events()
+            .publish((Symbol::new(e, "revert_concentrated_fee_tier"),), ())
+    }
+
+    fn set_rewards_votes_feed(&self, feed: Address) {
+        let e = self.env();
+        e.events()
+            .publish((Symbol::new(e, "set_rewards_votes_feed"),), (feed,))
+    }
+
+    fn record_votes_snapshot(&self, epoch: u64, token_sets: u32) {
+        // topics
+        // [
+        //   "record_votes_snapshot": Symbol, // event identifier
+        //   epoch: u64, // snapshot epoch
+        // ]
+        //
+        // body
+        // [
+        //   token_sets: u32, // number of voted token sets
+        // ]
+        let e = self.env();
+        e.events().publish(
+            (Symbol::new(e, "record_votes_snapshot"), epoch),
+            (token_sets,),
+        )
+    }
+
+    fn roll_rewards_epoch(&self, epoch: u64, reward_tps: u128, expired_at: u64) {
+        let e = self.env();
+        e.events().publish(
+            (Symbol::new(e, "roll_rewards_epoch"), epoch),
+            (reward_tps, expired_at),
+        )
+    }
+
+    fn rewards_epoch_completed(&self, epoch: u64, token_sets: u32) {
+        // topics
+        // [
+        //   "rewards_epoch_completed": Symbol, // event identifier
+        //   epoch: u64, // votes snapshot epoch, 0 if configured by the rewards admin
+        // ]
+        //
+        // body
+        // [
+        //   token_sets: u32, // number of token sets configured
+        // ]
+        let e = self.env();
+        e.events().publish(
+            (Symbol::new(e, "rewards_epoch_completed"), epoch),
+            (token_sets,),
+        )
+    }
 }
@@ -1,5 +1,7 @@
 #![no_std]
 
+mod boost;
+mod concentrated_fee_tiers;
 mod constants;
 mod contract;
 pub mod errors;
@@ -7,7 +9,10 @@ mod events;
 mod liquidity_calculator;
 mod pool_interface;
 mod pool_utils;
+mod reinvest;
+mod reward_history;
 mod rewards;
+mod rewards_epoch;
 mod rewards_gauge;
 mod router_interface;
 mod storage;
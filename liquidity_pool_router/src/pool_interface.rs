@@ -1,3 +1,6 @@
+use crate::reinvest::ReinvestSwapsChains;
+use crate::storage::{ConcentratedFeeTier, VotesSnapshot};
+use rewards::storage::{RewardClaimRecord, RewardEpochSnapshot};
 use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Val, Vec, U256};
 
 pub trait LiquidityPoolInterfaceTrait {
@@ -164,6 +167,22 @@ pub trait RewardsInterfaceTrait {
         pool_index: BytesN<32>,
     ) -> u128;
 
+    // Vote-driven rewards: the votes feed records vote weights per token set as epoch snapshots,
+    // anyone can roll the rewards config to the latest snapshot in pages of token sets.
+    // Reward tps and expiration are always set by the rewards admin via `config_global_rewards`.
+    fn set_rewards_votes_feed(e: Env, admin: Address, feed: Address);
+    fn get_rewards_votes_feed(e: Env) -> Option<Address>;
+    fn record_votes_snapshot(e: Env, feed: Address, tokens_votes: Vec<(Vec<Address>, u32)>) -> u64;
+    fn get_votes_snapshot(e: Env) -> Option<VotesSnapshot>;
+    fn get_rewards_epoch(e: Env) -> u64;
+
+    // Permissionless rollover of the current rewards config: activates a newer votes snapshot, then
+    // fills liquidity and configures pools for `batch_size` token sets starting at `cursor`.
+    // Progress is stored; a cursor other than the stored one is a no-op. Returns the next cursor,
+    // emits `rewards_epoch_completed` at the end. See `get_votes_snapshot` for the pending snapshot.
+    fn advance_rewards_epoch(e: Env, cursor: u32, batch_size: u32) -> u32;
+    fn get_rewards_epoch_cursor(e: Env) -> u32;
+
     // Get rewards status for the pool,
     // including amount available for the user
     fn get_rewards_info(
@@ -202,6 +221,63 @@ pub trait RewardsInterfaceTrait {
     // Claim reward as a user.
     // returns amount of tokens rewarded to the user
     fn claim(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>) -> u128;
+
+    // Claim reward of the user to the recipient. The caller is either the user or the user's
+    // delegated claimer in the pool and has to authorize the call. Unlike claim, the signer is
+    // passed explicitly: the pool only sees the router as invoker and has to know whose
+    // authorization to require.
+    // returns amount of tokens rewarded
+    fn claim_to(
+        e: Env,
+        caller: Address,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        recipient: Address,
+    ) -> u128;
+
+    // Claim reward and deposit it back into the same pool in one call.
+    // swaps_chains: (token_in, swaps) pairs converting the claimed reward token, or a gauge reward
+    //  token, into a pool token. Claimed amount of each token_in is split evenly between its chains;
+    //  gauge rewards are claimed only if a chain starts with them.
+    // min_shares is the only slippage guard, intermediate swaps accept any output.
+    // returns deposited amounts and minted shares
+    fn claim_and_reinvest(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        swaps_chains: ReinvestSwapsChains,
+        min_shares: u128,
+    ) -> (Vec<u128>, u128);
+
+    // Get a page of reward snapshots of each pool, keyed by pool address.
+    // pools: (tokens, pool_index) pairs. Pools without reward history return an empty page.
+    fn get_pools_reward_snapshots(
+        e: Env,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+        offset: u32,
+        limit: u32,
+    ) -> Map<Address, Vec<RewardEpochSnapshot>>;
+
+    // Get a page of the user's reward claims in each pool, keyed by pool address.
+    // pools: (tokens, pool_index) pairs. Pools without reward history return an empty page.
+    fn get_user_reward_claims(
+        e: Env,
+        user: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+        offset: u32,
+        limit: u32,
+    ) -> Map<Address, Vec<RewardClaimRecord>>;
+
+    // Sync the user's working balance with the current boost in each pool. Permissionless.
+    // pools: (tokens, pool_index) pairs. Fails if any pool fails, e.g. lacks boost updates support.
+    // returns new working balance of the user keyed by pool address
+    fn update_boost_for_pools(
+        e: Env,
+        user: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+    ) -> Map<Address, u128>;
 }
 
 pub trait PoolsManagementTrait {
@@ -225,15 +301,45 @@ pub trait PoolsManagementTrait {
     ) -> (BytesN<32>, Address);
 
     // Initialize concentrated pool with custom arguments.
-    // fee must be one of: 10 (0.1%), 30 (0.3%), 100 (1.0%).
-    // tick_spacing is derived automatically from the fee tier.
+    // fee must be registered in the fee tier registry (10, 30 and 100 by default).
+    // tick_spacing is taken from the fee tier.
+    // Optional sqrt_price_x96 sets the starting price of a new pool in the same call,
+    // so the first deposits can be one-sided.
     fn init_concentrated_pool(
         e: Env,
         user: Address,
         tokens: Vec<Address>,
         fee: u32,
+        sqrt_price_x96: Option<U256>,
     ) -> (BytesN<32>, Address);
 
+    // Set the starting price of a concentrated pool before its first deposit, so the first
+    // deposits can be one-sided. Only the pool creator, only once.
+    fn init_concentrated_pool_price(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        sqrt_price_x96: U256,
+    );
+
+    // Concentrated fee tier registry. Adding a tier is a two-step timelocked action:
+    // commit now, apply after ADMIN_ACTIONS_DELAY. Admin only.
+    fn commit_concentrated_fee_tier(
+        e: Env,
+        admin: Address,
+        fee: u32,
+        tick_spacing: i32,
+        max_distance: u32,
+    );
+    fn apply_concentrated_fee_tier(e: Env, admin: Address);
+    fn revert_concentrated_fee_tier(e: Env, admin: Address);
+
+    // Registered tiers keyed by fee, the pending tier and the time it can be applied (0 if none).
+    fn get_concentrated_fee_tiers(e: Env) -> Map<u32, ConcentratedFeeTier>;
+    fn get_future_concentrated_fee_tier(e: Env) -> Option<ConcentratedFeeTier>;
+    fn get_conc_fee_tier_deadline(e: Env) -> u64;
+
     // Get pools for given pair
     fn get_pools(e: Env, tokens: Vec<Address>) -> Map<BytesN<32>, Address>;
 
@@ -274,13 +380,20 @@ pub trait PoolsManagementTrait {
     fn pool_gauge_get_min_daily_amount(e: Env) -> u128;
     fn pool_gauge_get_min_duration(e: Env) -> u64;
 
+    // Set the price feed used to value gauge rewards in the token when no swaps chain proof given.
+    fn pool_gauge_set_price_feed(e: Env, admin: Address, token: Address, feed: Option<Address>);
+
+    // Get the price feed configured for the gauge reward token.
+    fn pool_gauge_get_price_feed(e: Env, token: Address) -> Option<Address>;
+
     // Switches the rewards gauge for a specific pool token.
     fn pool_gauge_switch_token(e: Env, admin: Address, token: Address, enabled: bool);
 
     // Checks if the rewards gauge is enabled for a specific pool token.
     fn pool_gauge_token_enabled(e: Env, token: Address) -> bool;
 
-    // Schedule an extra LP reward for the pool.
+    // Schedule an extra LP reward for the pool. Returns the gauge address and the scheduled config id.
+    #[allow(clippy::too_many_arguments)]
     fn pool_gauge_schedule_reward(
         e: Env,
         distributor: Address,
@@ -291,7 +404,31 @@ pub trait PoolsManagementTrait {
         start_at: Option<u64>,
         duration: u64,
         swaps_chain_proof: Vec<(Vec<Address>, BytesN<32>, Address)>,
-    ) -> Address;
+    ) -> (Address, u32);
+
+    // Cancel the gauge reward scheduled by the distributor. Remaining amount is refunded.
+    fn pool_gauge_cancel_reward(
+        e: Env,
+        distributor: Address,
+        pool_tokens: Vec<Address>,
+        pool_hash: BytesN<32>,
+        reward_token: Address,
+        config_id: u32,
+    ) -> u128;
+
+    // Extend the gauge reward scheduled by the distributor.
+    #[allow(clippy::too_many_arguments)]
+    fn pool_gauge_extend_reward(
+        e: Env,
+        distributor: Address,
+        pool_tokens: Vec<Address>,
+        pool_hash: BytesN<32>,
+        reward_token: Address,
+        config_id: u32,
+        extra_duration: u64,
+        extra_tps: u128,
+        swaps_chain_proof: Vec<(Vec<Address>, BytesN<32>, Address)>,
+    ) -> u128;
 }
 
 pub trait PoolPlaneInterface {
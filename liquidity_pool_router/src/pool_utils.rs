@@ -1,22 +1,26 @@
+use crate::concentrated_fee_tiers::get_concentrated_fee_tier;
 use crate::errors::LiquidityPoolRouterError;
 use crate::events::{Events, LiquidityPoolRouterEvents};
 use crate::liquidity_calculator::LiquidityCalculatorClient;
 use crate::rewards::get_rewards_manager;
 use crate::storage::{
-    add_pool, add_tokens_set, get_concentrated_pool_hash, get_constant_product_pool_hash,
+    add_pool, add_tokens_set, get_concentrated_pool_hash, get_constant_product_pool_hash, get_pool,
     get_pool_next_counter, get_pool_plane, get_pools_plain, get_protocol_fee_fraction,
-    get_stableswap_pool_hash, get_token_hash, LiquidityPoolType,
+    get_stableswap_pool_hash, get_token_hash, ConcentratedFeeTier, LiquidityPoolType,
 };
 use access_control::access::AccessControl;
 use access_control::management::{MultipleAddressesManagementTrait, SingleAddressManagementTrait};
 use access_control::role::Role;
 use liquidity_pool_config_storage::operations::get_config_storage;
+use rewards::concentrated_weight::max_distance_for_fee;
 use rewards::storage::{BoostFeedStorageTrait, BoostTokenStorageTrait, RewardTokenStorageTrait};
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
 use soroban_sdk::token::Client as SorobanTokenClient;
 use soroban_sdk::{
-    panic_with_error, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol,
-    Val, Vec, U256,
+    panic_with_error, symbol_short, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Map,
+    Symbol, Val, Vec, U256,
 };
+use utils::storage_errors::StorageError;
 
 pub fn get_standard_pool_salt(e: &Env, fee_fraction: &u32) -> BytesN<32> {
     let mut salt = Bytes::new(e);
@@ -37,7 +41,9 @@ pub fn get_stableswap_pool_salt(e: &Env) -> BytesN<32> {
     e.crypto().sha256(&salt).to_bytes()
 }
 
-pub fn get_concentrated_pool_salt(e: &Env, fee: &u32, tick_spacing: &i32) -> BytesN<32> {
+// Tick spacing is resolved from the fee tier registry, so the salt identifies the tier.
+pub fn get_concentrated_pool_salt(e: &Env, fee: &u32) -> BytesN<32> {
+    let tick_spacing = get_concentrated_fee_tier(e, *fee).tick_spacing;
     let mut salt = Bytes::new(e);
     salt.append(&symbol_short!("cl").to_xdr(e));
     salt.append(&symbol_short!("0x00").to_xdr(e));
@@ -143,12 +149,13 @@ pub fn deploy_stableswap_pool(
 pub fn deploy_concentrated_pool(
     e: &Env,
     tokens: &Vec<Address>,
-    fee: u32,
-    tick_spacing: i32,
+    tier: &ConcentratedFeeTier,
 ) -> (BytesN<32>, Address) {
+    let fee = tier.fee;
+    let tick_spacing = tier.tick_spacing;
     let tokens_salt = get_tokens_salt(e, tokens);
     let liquidity_pool_wasm_hash = get_concentrated_pool_hash(e);
-    let subpool_salt = get_concentrated_pool_salt(e, &fee, &tick_spacing);
+    let subpool_salt = get_concentrated_pool_salt(e, &fee);
 
     let pool_contract_id = e
         .deployer()
@@ -160,6 +167,20 @@ pub fn deploy_concentrated_pool(
         .deploy_v2(liquidity_pool_wasm_hash, ());
 
     init_concentrated_pool(e, tokens, &pool_contract_id, fee, tick_spacing);
+    // Pools derive the rewards decay distance from the fee; only custom values are pushed.
+    if tier.max_distance != max_distance_for_fee(fee) {
+        e.invoke_contract::<()>(
+            &pool_contract_id,
+            &Symbol::new(e, "set_rewards_max_distance"),
+            Vec::from_array(
+                e,
+                [
+                    e.current_contract_address().to_val(),
+                    tier.max_distance.into_val(e),
+                ],
+            ),
+        );
+    }
 
     add_tokens_set(e, tokens);
     add_pool(
@@ -398,6 +419,21 @@ fn init_concentrated_pool(
     );
 }
 
+// Sets the starting price of an empty concentrated pool, the router is authorized by the pool.
+pub fn initialize_concentrated_pool_price(e: &Env, pool: &Address, sqrt_price_x96: &U256) {
+    e.invoke_contract::<()>(
+        pool,
+        &Symbol::new(e, "initialize_price"),
+        Vec::from_array(
+            e,
+            [
+                e.current_contract_address().to_val(),
+                sqrt_price_x96.into_val(e),
+            ],
+        ),
+    );
+}
+
 pub fn assert_tokens_sorted(e: &Env, tokens: &Vec<Address>) {
     for i in 0..tokens.len() - 1 {
         let left = tokens.get_unchecked(i);
@@ -428,6 +464,114 @@ pub fn validate_tokens_contracts(e: &Env, tokens: &Vec<Address>) {
     }
 }
 
+// Swaps `in_amount` of `token_in` taken from the user along the chain and sends the result
+// back to the user. Returns the amount of the last token received.
+pub fn execute_swaps_chain(
+    e: &Env,
+    user: &Address,
+    swaps_chain: &Vec<(Vec<Address>, BytesN<32>, Address)>,
+    token_in: &Address,
+    in_amount: u128,
+    out_min: u128,
+) -> u128 {
+    let mut last_token_out: Option<Address> = None;
+    let mut last_swap_result = 0;
+
+    if swaps_chain.is_empty() {
+        panic_with_error!(e, LiquidityPoolRouterError::PathIsEmpty);
+    }
+
+    SorobanTokenClient::new(e, token_in).transfer(
+        user,
+        e.current_contract_address(),
+        &(in_amount as i128),
+    );
+
+    for i in 0..swaps_chain.len() {
+        let (tokens, pool_index, token_out) = swaps_chain.get(i).unwrap();
+        assert_tokens_sorted(e, &tokens);
+
+        let pool_id = get_pool(e, &tokens, pool_index);
+
+        let mut out_min_local = 0;
+        let (token_in_local, in_amount_local) = if i == 0 {
+            (token_in.clone(), in_amount)
+        } else {
+            let token = match last_token_out {
+                Some(v) => v,
+                None => panic_with_error!(e, StorageError::ValueNotInitialized),
+            };
+            (token, last_swap_result)
+        };
+
+        if i == swaps_chain.len() - 1 {
+            out_min_local = out_min;
+        }
+
+        e.authorize_as_current_contract(vec![
+            e,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token_in_local.clone(),
+                    fn_name: Symbol::new(e, "transfer"),
+                    args: (
+                        e.current_contract_address(),
+                        pool_id.clone(),
+                        in_amount_local as i128,
+                    )
+                        .into_val(e),
+                },
+                sub_invocations: vec![e],
+            }),
+        ]);
+
+        last_swap_result = e.invoke_contract(
+            &pool_id,
+            &symbol_short!("swap"),
+            Vec::from_array(
+                e,
+                [
+                    e.current_contract_address().into_val(e),
+                    tokens
+                        .first_index_of(token_in_local.clone())
+                        .unwrap()
+                        .into_val(e),
+                    tokens
+                        .first_index_of(token_out.clone())
+                        .unwrap()
+                        .into_val(e),
+                    in_amount_local.into_val(e),
+                    out_min_local.into_val(e),
+                ],
+            ),
+        );
+
+        Events::new(e).swap(
+            tokens,
+            user.clone(),
+            pool_id,
+            token_in_local.clone(),
+            token_out.clone(),
+            in_amount_local,
+            last_swap_result,
+        );
+
+        last_token_out = Some(token_out);
+    }
+
+    let token_out_address = match last_token_out {
+        Some(v) => v,
+        None => panic_with_error!(e, StorageError::ValueNotInitialized),
+    };
+    SorobanTokenClient::new(e, &token_out_address).transfer(
+        &e.current_contract_address(),
+        user,
+        &(last_swap_result as i128),
+    );
+
+    last_swap_result
+}
+
 pub fn get_total_liquidity(
     e: &Env,
     tokens: &Vec<Address>,
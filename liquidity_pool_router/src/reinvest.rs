@@ -0,0 +1,136 @@
+use crate::errors::LiquidityPoolRouterError;
+use crate::events::{Events, LiquidityPoolRouterEvents};
+use crate::pool_utils::{assert_tokens_sorted, execute_swaps_chain};
+use crate::rewards::get_rewards_manager;
+use crate::storage::get_pool;
+use rewards::storage::RewardTokenStorageTrait;
+use soroban_sdk::token::Client as SorobanTokenClient;
+use soroban_sdk::{
+    panic_with_error, symbol_short, Address, BytesN, Env, IntoVal, Map, Symbol, Vec,
+};
+
+// (token_in, swaps) pairs, each swap is a tuple of pool tokens, pool index hash and the token to obtain.
+pub(crate) type ReinvestSwapsChains = Vec<(Address, Vec<(Vec<Address>, BytesN<32>, Address)>)>;
+
+// Claims the pool reward and deposits it back into the same pool.
+// Each swaps chain is (token_in, swaps): the claimed amount of token_in is split evenly between
+// the chains starting with it and swapped into a pool token; empty swaps deposit token_in as is.
+// Gauge rewards are claimed only if some chain starts with a token other than the reward token.
+// Tokens are moved on the user's balance, so whatever the pool doesn't take stays with the user.
+// Only the balance increase caused by the claims is reinvested.
+pub(crate) fn claim_and_reinvest(
+    e: &Env,
+    user: &Address,
+    tokens: &Vec<Address>,
+    pool_index: BytesN<32>,
+    swaps_chains: &ReinvestSwapsChains,
+    min_shares: u128,
+) -> (Vec<u128>, u128) {
+    assert_tokens_sorted(e, tokens);
+    let pool_id = get_pool(e, tokens, pool_index);
+
+    let reward_token = get_rewards_manager(e).storage().get_reward_token();
+    let mut chains_count: Map<Address, u32> = Map::new(e);
+    for (token_in, _) in swaps_chains.iter() {
+        chains_count.set(
+            token_in.clone(),
+            chains_count.get(token_in).unwrap_or(0) + 1,
+        );
+    }
+    let mut balances_before: Map<Address, i128> = Map::new(e);
+    for token in chains_count.keys() {
+        balances_before.set(
+            token.clone(),
+            SorobanTokenClient::new(e, &token).balance(user),
+        );
+    }
+
+    let reward: u128 = e.invoke_contract(
+        &pool_id,
+        &symbol_short!("claim"),
+        Vec::from_array(e, [user.into_val(e)]),
+    );
+    Events::new(e).claim(
+        tokens.clone(),
+        user.clone(),
+        pool_id.clone(),
+        reward_token.clone(),
+        reward,
+    );
+    if chains_count
+        .keys()
+        .iter()
+        .any(|token| token != reward_token)
+    {
+        let _: Map<Address, u128> = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(e, "gauges_claim"),
+            Vec::from_array(e, [user.into_val(e)]),
+        );
+    }
+
+    // Reinvest what actually arrived to the user: with vesting configured on the pool,
+    // the claimed reward is only scheduled and nothing is transferred yet.
+    let mut claimed: Map<Address, u128> = Map::new(e);
+    for (token, balance_before) in balances_before.iter() {
+        let received = SorobanTokenClient::new(e, &token).balance(user) - balance_before;
+        claimed.set(token, received.max(0) as u128);
+    }
+
+    let mut desired_amounts = Vec::new(e);
+    for _ in 0..tokens.len() {
+        desired_amounts.push_back(0_u128);
+    }
+    let mut chains_left = chains_count;
+    for (token_in, swaps) in swaps_chains.iter() {
+        // the last chain of the token takes the rounding remainder
+        let left = chains_left.get_unchecked(token_in.clone());
+        let available = claimed.get(token_in.clone()).unwrap_or(0);
+        let in_amount = available / left as u128;
+        chains_left.set(token_in.clone(), left - 1);
+        claimed.set(token_in.clone(), available - in_amount);
+
+        let token_out = match swaps.last() {
+            Some((_, _, token_out)) => token_out,
+            None => token_in.clone(),
+        };
+        let token_idx = match tokens.first_index_of(token_out) {
+            Some(v) => v,
+            None => panic_with_error!(e, LiquidityPoolRouterError::TokenNotInPool),
+        };
+        if in_amount == 0 {
+            continue;
+        }
+
+        let out_amount = if swaps.is_empty() {
+            in_amount
+        } else {
+            execute_swaps_chain(e, user, &swaps, &token_in, in_amount, 0)
+        };
+        desired_amounts.set(
+            token_idx,
+            desired_amounts.get_unchecked(token_idx) + out_amount,
+        );
+    }
+
+    let (amounts, share_amount): (Vec<u128>, u128) = e.invoke_contract(
+        &pool_id,
+        &symbol_short!("deposit"),
+        Vec::from_array(
+            e,
+            [
+                user.into_val(e),
+                desired_amounts.into_val(e),
+                min_shares.into_val(e),
+            ],
+        ),
+    );
+    Events::new(e).deposit(
+        tokens.clone(),
+        user.clone(),
+        pool_id,
+        amounts.clone(),
+        share_amount,
+    );
+    (amounts, share_amount)
+}
@@ -0,0 +1,54 @@
+use crate::pool_utils::assert_tokens_sorted;
+use crate::storage::get_pool;
+use rewards::storage::{RewardClaimRecord, RewardEpochSnapshot};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, InvokeError, Map, Symbol, Vec};
+
+// Reward history views aggregated over several pools. Pools deployed before the history was
+// introduced have no records and are reported with an empty page.
+
+pub(crate) fn get_pools_reward_snapshots(
+    e: &Env,
+    pools: &Vec<(Vec<Address>, BytesN<32>)>,
+    offset: u32,
+    limit: u32,
+) -> Map<Address, Vec<RewardEpochSnapshot>> {
+    let mut result = Map::new(e);
+    for (tokens, pool_index) in pools.iter() {
+        assert_tokens_sorted(e, &tokens);
+        let pool_id = get_pool(e, &tokens, pool_index);
+        let page = match e.try_invoke_contract::<Vec<RewardEpochSnapshot>, InvokeError>(
+            &pool_id,
+            &Symbol::new(e, "get_reward_snapshots"),
+            Vec::from_array(e, [offset.into_val(e), limit.into_val(e)]),
+        ) {
+            Ok(Ok(page)) => page,
+            _ => Vec::new(e),
+        };
+        result.set(pool_id, page);
+    }
+    result
+}
+
+pub(crate) fn get_user_reward_claims(
+    e: &Env,
+    user: &Address,
+    pools: &Vec<(Vec<Address>, BytesN<32>)>,
+    offset: u32,
+    limit: u32,
+) -> Map<Address, Vec<RewardClaimRecord>> {
+    let mut result = Map::new(e);
+    for (tokens, pool_index) in pools.iter() {
+        assert_tokens_sorted(e, &tokens);
+        let pool_id = get_pool(e, &tokens, pool_index);
+        let page = match e.try_invoke_contract::<Vec<RewardClaimRecord>, InvokeError>(
+            &pool_id,
+            &Symbol::new(e, "get_user_reward_claims"),
+            Vec::from_array(e, [user.to_val(), offset.into_val(e), limit.into_val(e)]),
+        ) {
+            Ok(Ok(page)) => page,
+            _ => Vec::new(e),
+        };
+        result.set(pool_id, page);
+    }
+    result
+}
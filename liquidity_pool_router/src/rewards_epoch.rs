@@ -0,0 +1,247 @@
+use crate::errors::LiquidityPoolRouterError;
+use crate::events::{Events, LiquidityPoolRouterEvents};
+use crate::pool_utils::{assert_tokens_sorted, get_tokens_salt, get_total_liquidity};
+use crate::storage::{
+    get_liquidity_calculator, get_pool, get_reward_tokens, get_reward_tokens_detailed,
+    get_reward_tokens_safe, get_rewards_config, get_rewards_epoch, get_rewards_epoch_cursor,
+    get_votes_snapshot, has_rewards_epoch_cursor, remove_rewards_epoch_cursor, set_reward_tokens,
+    set_reward_tokens_detailed, set_rewards_config, set_rewards_epoch, set_rewards_epoch_cursor,
+    set_votes_snapshot, GlobalRewardsConfig, LiquidityPoolRewardInfo, VotesSnapshot,
+};
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, IntoVal, Map, Symbol, Val, Vec, U256};
+
+// Global rewards configuration shared by the admin flow (config_global_rewards, fill_liquidity,
+// config_pool_rewards) and the vote-driven epoch roll.
+
+fn build_reward_tokens(
+    e: &Env,
+    tokens_votes: &Vec<(Vec<Address>, u32)>,
+) -> Map<Vec<Address>, LiquidityPoolRewardInfo> {
+    let mut tokens_with_liquidity = Map::new(e);
+    for (tokens, voting_share) in tokens_votes.iter() {
+        assert_tokens_sorted(e, &tokens);
+
+        tokens_with_liquidity.set(
+            tokens,
+            LiquidityPoolRewardInfo {
+                voting_share,
+                processed: false,
+                total_liquidity: U256::from_u32(e, 0),
+            },
+        );
+    }
+    let mut sum = 0;
+    for (_, reward_info) in tokens_with_liquidity.iter() {
+        sum += reward_info.voting_share;
+    }
+    if sum > 1_0000000 {
+        panic_with_error!(e, LiquidityPoolRouterError::VotingShareExceedsMax);
+    }
+    tokens_with_liquidity
+}
+
+pub(crate) fn set_global_rewards(
+    e: &Env,
+    reward_tps: u128,
+    expired_at: u64,
+    tokens_votes: &Vec<(Vec<Address>, u32)>,
+) {
+    set_reward_tokens(e, &build_reward_tokens(e, tokens_votes));
+    remove_rewards_epoch_cursor(e);
+    set_rewards_config(
+        e,
+        &GlobalRewardsConfig {
+            tps: reward_tps,
+            expired_at,
+        },
+    )
+}
+
+pub(crate) fn fill_tokens_liquidity(e: &Env, tokens: &Vec<Address>) {
+    assert_tokens_sorted(e, tokens);
+    let tokens_salt = get_tokens_salt(e, tokens);
+    let calculator = get_liquidity_calculator(e);
+    let (pools, total_liquidity) = get_total_liquidity(e, tokens, calculator);
+
+    let mut pools_with_processed_info = Map::new(e);
+    for (key, value) in pools {
+        pools_with_processed_info.set(key, (value, false));
+    }
+
+    let mut tokens_with_liquidity = get_reward_tokens(e);
+    let mut token_data = match tokens_with_liquidity.get(tokens.clone()) {
+        Some(v) => v,
+        None => panic_with_error!(e, LiquidityPoolRouterError::TokensAreNotForReward),
+    };
+    if token_data.processed {
+        panic_with_error!(e, LiquidityPoolRouterError::LiquidityAlreadyFilled);
+    }
+    token_data.processed = true;
+    token_data.total_liquidity = total_liquidity;
+    tokens_with_liquidity.set(tokens.clone(), token_data);
+    set_reward_tokens(e, &tokens_with_liquidity);
+    set_reward_tokens_detailed(e, tokens_salt, &pools_with_processed_info);
+}
+
+pub(crate) fn configure_pool_rewards(
+    e: &Env,
+    tokens: &Vec<Address>,
+    pool_index: &BytesN<32>,
+) -> u128 {
+    assert_tokens_sorted(e, tokens);
+    let pool_id = get_pool(e, tokens, pool_index.clone());
+
+    let rewards_config = get_rewards_config(e);
+    let tokens_salt = get_tokens_salt(e, tokens);
+    let mut tokens_detailed = get_reward_tokens_detailed(e, tokens_salt.clone());
+    let tokens_reward = get_reward_tokens(e);
+    let tokens_reward_info = tokens_reward.get(tokens.clone());
+
+    let (pool_liquidity, pool_configured) = if tokens_reward_info.is_some() {
+        tokens_detailed
+            .get(pool_index.clone())
+            .unwrap_or((U256::from_u32(e, 0), false))
+    } else {
+        (U256::from_u32(e, 0), false)
+    };
+
+    if pool_configured {
+        panic_with_error!(e, LiquidityPoolRouterError::RewardsAlreadyConfigured);
+    }
+
+    let reward_info = match tokens_reward_info {
+        Some(v) => v,
+        // if tokens not found in current config, deactivate them
+        None => LiquidityPoolRewardInfo {
+            voting_share: 0,
+            processed: true,
+            total_liquidity: U256::from_u32(e, 0),
+        },
+    };
+
+    if !reward_info.processed {
+        panic_with_error!(e, LiquidityPoolRouterError::LiquidityNotFilled);
+    }
+    // it's safe to convert tps to u128 since it cannot be bigger than total tps which is u128
+    let pool_tps = if pool_liquidity > U256::from_u32(e, 0) {
+        U256::from_u128(e, rewards_config.tps)
+            .mul(&U256::from_u32(e, reward_info.voting_share))
+            .mul(&pool_liquidity)
+            .div(&reward_info.total_liquidity)
+            .div(&U256::from_u32(e, 1_0000000))
+            .to_u128()
+            .unwrap()
+    } else {
+        0
+    };
+
+    e.invoke_contract::<Val>(
+        &pool_id,
+        &Symbol::new(e, "set_rewards_config"),
+        Vec::from_array(
+            e,
+            [
+                e.current_contract_address().to_val(),
+                rewards_config.expired_at.into_val(e),
+                pool_tps.into_val(e),
+            ],
+        ),
+    );
+
+    if pool_tps > 0 {
+        // mark pool as configured to avoid reentrancy
+        tokens_detailed.set(pool_index.clone(), (pool_liquidity, true));
+        set_reward_tokens_detailed(e, tokens_salt, &tokens_detailed);
+    }
+
+    Events::new(e).config_rewards(tokens.clone(), pool_id, pool_tps, rewards_config.expired_at);
+
+    pool_tps
+}
+
+// Votes snapshots. The votes feed records vote weights per token set; anyone can then roll
+// the rewards config to the new snapshot in pages with `advance_rewards_epoch`.
+// Reward tps and expiration stay with the rewards admin, see `config_global_rewards`.
+
+pub(crate) fn record_votes_snapshot(e: &Env, tokens_votes: Vec<(Vec<Address>, u32)>) -> u64 {
+    // validate the same way config_global_rewards does, before anything is activated
+    build_reward_tokens(e, &tokens_votes);
+
+    let epoch = match get_votes_snapshot(e) {
+        Some(snapshot) => snapshot.epoch + 1,
+        None => get_rewards_epoch(e) + 1,
+    };
+    set_votes_snapshot(
+        e,
+        &VotesSnapshot {
+            epoch,
+            tokens_votes,
+        },
+    );
+    epoch
+}
+
+// Activates the snapshot if it is newer than the configured epoch, keeping the configured
+// reward tps and expiration. Token sets that were rewarded in the previous epoch but are missing from the snapshot are
+// kept with zero voting share, so their pools get deactivated by the same rollover.
+fn activate_votes_snapshot(e: &Env) {
+    let snapshot = match get_votes_snapshot(e) {
+        Some(v) => v,
+        None => return,
+    };
+    if snapshot.epoch <= get_rewards_epoch(e) {
+        return;
+    }
+
+    let mut tokens_votes = snapshot.tokens_votes.clone();
+    let voted = build_reward_tokens(e, &tokens_votes);
+    if let Some(previous) = get_reward_tokens_safe(e) {
+        for (tokens, _) in previous.iter() {
+            if !voted.contains_key(tokens.clone()) {
+                tokens_votes.push_back((tokens, 0));
+            }
+        }
+    }
+    let config = get_rewards_config(e);
+    set_global_rewards(e, config.tps, config.expired_at, &tokens_votes);
+    set_rewards_epoch(e, &snapshot.epoch);
+    Events::new(e).roll_rewards_epoch(snapshot.epoch, config.tps, config.expired_at);
+}
+
+// Fills liquidity and configures every pool of the reward token sets in
+// [cursor, cursor + batch_size), in `get_tokens_for_reward` order.
+// Progress is stored, so a call with a cursor other than the stored one does nothing and
+// returns the stored cursor: repeated or concurrent calls never process a token set twice.
+// Returns the cursor to continue from; it equals the number of token sets once done.
+pub(crate) fn advance_rewards_epoch(e: &Env, cursor: u32, batch_size: u32) -> u32 {
+    activate_votes_snapshot(e);
+
+    let progress = get_rewards_epoch_cursor(e);
+    if cursor != progress {
+        return progress;
+    }
+    // a new config has no stored cursor yet, so an empty one completes on the first call
+    let started = has_rewards_epoch_cursor(e);
+
+    let tokens_list = get_reward_tokens(e).keys();
+    let end = tokens_list.len().min(cursor.saturating_add(batch_size));
+    for i in cursor..end {
+        let tokens = tokens_list.get_unchecked(i);
+        if !get_reward_tokens(e).get_unchecked(tokens.clone()).processed {
+            fill_tokens_liquidity(e, &tokens);
+        }
+
+        let tokens_detailed = get_reward_tokens_detailed(e, get_tokens_salt(e, &tokens));
+        for (pool_index, (_, configured)) in tokens_detailed.iter() {
+            if !configured {
+                configure_pool_rewards(e, &tokens, &pool_index);
+            }
+        }
+    }
+    set_rewards_epoch_cursor(e, end);
+
+    if end == tokens_list.len() && (end > cursor || !started) {
+        Events::new(e).rewards_epoch_completed(get_rewards_epoch(e), end);
+    }
+    end
+}
@@ -1,15 +1,25 @@
 use crate::errors::LiquidityPoolRouterError;
 use crate::pool_utils::assert_tokens_sorted;
 use crate::rewards::get_rewards_manager;
+use crate::storage::{get_gauge_price_feed, get_gauge_rewards_enabled_for};
 use crate::storage::{get_pool, DataKey};
 use liquidity_pool_config_storage as config_storage;
 use rewards::storage::RewardTokenStorageTrait;
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{
-    panic_with_error, Address, Bytes, BytesN, Env, IntoVal, Symbol, TryFromVal, Vec,
+    contracttype, panic_with_error, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, TryFromVal,
+    Vec,
 };
 use utils::storage_errors::StorageError;
 
+// Reward config as returned by the rewards gauge.
+#[contracttype]
+pub struct GaugeRewardConfig {
+    pub start_at: u64,
+    pub tps: u128,
+    pub expired_at: u64,
+}
+
 pub(crate) fn gauge_set_reward_per_day_threshold(
     e: &Env,
     admin: &Address,
@@ -66,6 +76,41 @@ pub(crate) fn deploy_rewards_gauge(e: &Env, pool: Address, reward_token: Address
     contract_id
 }
 
+// Finds the pool and its existing gauge for the reward token.
+pub(crate) fn get_pool_gauge(
+    e: &Env,
+    pool_tokens: &Vec<Address>,
+    pool_hash: BytesN<32>,
+    reward_token: &Address,
+) -> (Address, Address) {
+    let pool = get_pool(e, pool_tokens, pool_hash);
+
+    for token in pool_tokens.iter() {
+        if !get_gauge_rewards_enabled_for(e, token) {
+            panic_with_error!(e, LiquidityPoolRouterError::GaugeRewardsDisabledForPool);
+        }
+    }
+
+    let gauges_map: Map<Address, Address> =
+        e.invoke_contract(&pool, &Symbol::new(e, "get_gauges"), Vec::new(e));
+    match gauges_map.get(reward_token.clone()) {
+        Some(gauge) => (pool, gauge),
+        None => panic_with_error!(e, LiquidityPoolRouterError::GaugeNotFound),
+    }
+}
+
+pub(crate) fn get_gauge_scheduled_reward(
+    e: &Env,
+    gauge: &Address,
+    config_id: u32,
+) -> GaugeRewardConfig {
+    e.invoke_contract(
+        gauge,
+        &Symbol::new(e, "get_scheduled_reward"),
+        Vec::from_array(e, [config_id.into_val(e)]),
+    )
+}
+
 pub(crate) fn calculate_equivalent_reward(
     e: &Env,
     token_in: &Address,
@@ -83,6 +128,22 @@ pub(crate) fn calculate_equivalent_reward(
     }
 
     if swaps_chain.len() == 0 {
+        // swaps chain is optional for tokens valued by the price feed approved by admin.
+        // fn get_price_equivalent(e: Env, token: Address, quote_token: Address, amount: u128) -> u128
+        if let Some(feed) = get_gauge_price_feed(e, token_in) {
+            return e.invoke_contract(
+                &feed,
+                &Symbol::new(e, "get_price_equivalent"),
+                Vec::from_array(
+                    e,
+                    [
+                        token_in.into_val(e),
+                        reward_token.into_val(e),
+                        in_amount.into_val(e),
+                    ],
+                ),
+            );
+        }
         panic_with_error!(&e, LiquidityPoolRouterError::PathIsEmpty);
     }
 
@@ -37,6 +37,25 @@ pub struct GlobalRewardsConfig {
     pub expired_at: u64,
 }
 
+// Concentrated pool fee tier: tick spacing used for new pools and the distance (in ticks)
+// at which out-of-range positions stop earning rewards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConcentratedFeeTier {
+    pub fee: u32,
+    pub tick_spacing: i32,
+    pub max_distance: u32,
+}
+
+// Vote weights per token set recorded by the votes feed. Activated by `advance_rewards_epoch`
+// once its epoch is newer than the active one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotesSnapshot {
+    pub epoch: u64,
+    pub tokens_votes: Vec<(Vec<Address>, u32)>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LiquidityPoolRewardInfo {
@@ -68,11 +87,20 @@ pub(crate) enum DataKey {
     LiquidityCalculator,
     ProtocolFeeFraction,
     GaugeRewardsEnabled(Address),
+    GaugePriceFeed(Address), // persistent: price feed to value gauge reward token
+    ConcentratedFeeTiers,    // config storage: Map<u32, ConcentratedFeeTier>
+    FutureFeeTiers,          // ConcentratedFeeTier committed to the registry, pending timelock
+    FutureFeeTiersDeadline,  // timestamp after which FutureFeeTiers can be applied
+    ConcentratedPoolCreator(Address), // concentrated pool -> address that deployed it
+    RewardsVotesFeed,        // address allowed to record votes snapshots
+    VotesSnapshot,           // persistent: latest VotesSnapshot
+    RewardsEpoch,            // epoch of the snapshot rewards are configured from
 
     // Temporary storage
     RewardsConfig,                          // Global reward config
     RewardTokensList,                       // Tokens for reward
     RewardTokensPoolsLiquidity(BytesN<32>), // Per pool liquidity
+    RewardsEpochCursor,                     // Token sets processed by advance_rewards_epoch
 }
 
 #[contracterror]
@@ -155,6 +183,38 @@ generate_instance_storage_getter_and_setter_with_default!(
     0
 );
 
+generate_instance_storage_getter_and_setter_with_default!(
+    rewards_epoch,
+    DataKey::RewardsEpoch,
+    u64,
+    0
+);
+
+pub fn get_rewards_votes_feed(e: &Env) -> Option<Address> {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::RewardsVotesFeed)
+}
+
+pub fn set_rewards_votes_feed(e: &Env, feed: &Address) {
+    bump_instance(e);
+    e.storage().instance().set(&DataKey::RewardsVotesFeed, feed);
+}
+
+pub fn get_votes_snapshot(e: &Env) -> Option<VotesSnapshot> {
+    let key = DataKey::VotesSnapshot;
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_votes_snapshot(e: &Env, value: &VotesSnapshot) {
+    let key = DataKey::VotesSnapshot;
+    e.storage().persistent().set(&key, value);
+    bump_persistent(e, &key);
+}
+
 pub fn get_rewards_config(e: &Env) -> GlobalRewardsConfig {
     match e.storage().temporary().get(&DataKey::RewardsConfig) {
         Some(v) => {
@@ -185,12 +245,46 @@ pub fn get_reward_tokens(e: &Env) -> Map<Vec<Address>, LiquidityPoolRewardInfo>
     }
 }
 
+pub fn get_reward_tokens_safe(e: &Env) -> Option<Map<Vec<Address>, LiquidityPoolRewardInfo>> {
+    let key = DataKey::RewardTokensList;
+    let value = e.storage().temporary().get(&key);
+    if value.is_some() {
+        bump_temporary(e, &key);
+    }
+    value
+}
+
 pub fn set_reward_tokens(e: &Env, value: &Map<Vec<Address>, LiquidityPoolRewardInfo>) {
     let key = DataKey::RewardTokensList;
     e.storage().temporary().set(&key, value);
     bump_temporary(e, &key);
 }
 
+pub fn get_rewards_epoch_cursor(e: &Env) -> u32 {
+    let key = DataKey::RewardsEpochCursor;
+    match e.storage().temporary().get(&key) {
+        Some(v) => {
+            bump_temporary(e, &key);
+            v
+        }
+        None => 0,
+    }
+}
+
+pub fn set_rewards_epoch_cursor(e: &Env, value: u32) {
+    let key = DataKey::RewardsEpochCursor;
+    e.storage().temporary().set(&key, &value);
+    bump_temporary(e, &key);
+}
+
+pub fn has_rewards_epoch_cursor(e: &Env) -> bool {
+    e.storage().temporary().has(&DataKey::RewardsEpochCursor)
+}
+
+pub fn remove_rewards_epoch_cursor(e: &Env) {
+    e.storage().temporary().remove(&DataKey::RewardsEpochCursor);
+}
+
 pub fn get_reward_tokens_detailed(e: &Env, salt: BytesN<32>) -> Map<BytesN<32>, (U256, bool)> {
     let key = DataKey::RewardTokensPoolsLiquidity(salt);
     match e.storage().temporary().get(&key) {
@@ -360,3 +454,38 @@ pub fn set_gauge_rewards_enabled_for(e: &Env, token: Address, enabled: bool) {
     e.storage().persistent().set(&key, &enabled);
     bump_persistent(e, &key);
 }
+
+pub fn get_gauge_price_feed(e: &Env, token: &Address) -> Option<Address> {
+    let key = DataKey::GaugePriceFeed(token.clone());
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_gauge_price_feed(e: &Env, token: &Address, feed: &Option<Address>) {
+    let key = DataKey::GaugePriceFeed(token.clone());
+    match feed {
+        Some(feed) => {
+            e.storage().persistent().set(&key, feed);
+            bump_persistent(e, &key);
+        }
+        None => e.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_concentrated_pool_creator(e: &Env, pool: Address) -> Option<Address> {
+    let key = DataKey::ConcentratedPoolCreator(pool);
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_concentrated_pool_creator(e: &Env, pool: Address, creator: &Address) {
+    let key = DataKey::ConcentratedPoolCreator(pool);
+    e.storage().persistent().set(&key, creator);
+    bump_persistent(e, &key);
+}
@@ -2,9 +2,11 @@
 extern crate std;
 
 use crate::constants::{CONSTANT_PRODUCT_FEE_AVAILABLE, STABLESWAP_MAX_POOLS};
+use crate::pool_utils::get_tokens_salt;
+use crate::storage::{add_pool, LiquidityPoolType};
 use crate::testutils::{
-    concentrated_pool, create_plane_contract, create_token_contract, rewards_gauge,
-    stableswap_pool, standard_pool, test_token, Setup,
+    concentrated_pool, create_plane_contract, create_price_feed_contract, create_token_contract,
+    rewards_gauge, stableswap_pool, standard_pool, test_token, MockVestingPool, Setup,
 };
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use soroban_sdk::testutils::{
@@ -13,12 +15,12 @@ use soroban_sdk::testutils::{
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
 use soroban_sdk::Env;
 use soroban_sdk::{
-    log, symbol_short, testutils::Address as _, vec, Address, FromVal, IntoVal, Map, Symbol, Val,
-    Vec, U256,
+    log, symbol_short, testutils::Address as _, vec, Address, BytesN, FromVal, IntoVal, Map,
+    Symbol, Val, Vec, U256,
 };
 use utils::test_rebasing_token;
 use utils::test_utils::{
-    assert_approx_eq_abs, assert_approx_eq_abs_u256, install_dummy_wasm, jump,
+    assert_approx_eq_abs, assert_approx_eq_abs_u256, count_events, install_dummy_wasm, jump,
 };
 
 #[test]
@@ -715,7 +717,7 @@ fn test_simple_ongoing_reward() {
         router.init_standard_pool(&user1, &tokens, &30);
     let (stable_pool_hash, stable_pool_address) = router.init_stableswap_pool(&user1, &tokens, &10);
     let (concentrated_pool_hash, concentrated_pool_address) =
-        router.init_concentrated_pool(&user1, &tokens, &10);
+        router.init_concentrated_pool(&user1, &tokens, &10, &None);
 
     let reward_1_tps = 10_5000000_u128;
     let total_reward_1 = reward_1_tps * 60;
@@ -3552,7 +3554,7 @@ fn test_setup_rewards_gauge() {
     );
 
     gauge_token.mint(&distributor, &(1200 * 7 * 24 * 60 * 60 * 2));
-    let rewards_gauge = setup.router.pool_gauge_schedule_reward(
+    let (rewards_gauge, config_id) = setup.router.pool_gauge_schedule_reward(
         &distributor,
         &tokens,
         &pool_hash,
@@ -3588,7 +3590,7 @@ fn test_setup_rewards_gauge() {
                 ),
             ],
         ),
-        rewards_gauge,
+        (rewards_gauge.clone(), config_id + 1),
     );
     assert_eq!(
         pool_client.get_gauges(),
@@ -3600,6 +3602,146 @@ fn test_setup_rewards_gauge() {
     );
 }
 
+#[test]
+#[should_panic(expected = "Error(Contract, #319)")]
+fn test_cancel_gauge_reward_disabled_for_pool() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let user1 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_hash, _pool_address) = setup.router.init_standard_pool(&user1, &tokens, &30);
+    let distributor = Address::generate(&e);
+    let gauge_token = create_token_contract(&e, &distributor);
+
+    setup.router.pool_gauge_cancel_reward(
+        &distributor,
+        &tokens,
+        &pool_hash,
+        &gauge_token.address,
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn test_extend_gauge_reward_without_gauge() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let user1 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_hash, _pool_address) = setup.router.init_standard_pool(&user1, &tokens, &30);
+    let distributor = Address::generate(&e);
+    let gauge_token = create_token_contract(&e, &distributor);
+    setup
+        .router
+        .pool_gauge_switch_token(&setup.admin, &token1.address, &true);
+    setup
+        .router
+        .pool_gauge_switch_token(&setup.admin, &token2.address, &true);
+
+    setup.router.pool_gauge_extend_reward(
+        &distributor,
+        &tokens,
+        &pool_hash,
+        &gauge_token.address,
+        &0,
+        &100,
+        &0,
+        &Vec::new(&e),
+    );
+}
+
+#[test]
+fn test_setup_rewards_gauge_with_price_feed() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let user1 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_hash, pool_address) = setup.router.init_standard_pool(&user1, &tokens, &30);
+    let pool_client = standard_pool::Client::new(&e, &pool_address);
+    let distributor = Address::generate(&e);
+    let gauge_token = create_token_contract(&e, &distributor);
+    gauge_token.mint(&distributor, &(600 * 7 * 24 * 60 * 60));
+    setup
+        .router
+        .pool_gauge_switch_token(&setup.admin, &token1.address, &true);
+    setup
+        .router
+        .pool_gauge_switch_token(&setup.admin, &token2.address, &true);
+
+    // no path to the reward token and no price feed
+    assert!(setup
+        .router
+        .try_pool_gauge_schedule_reward(
+            &distributor,
+            &tokens,
+            &pool_hash,
+            &gauge_token.address,
+            &600,
+            &None,
+            &(7 * 24 * 60 * 60),
+            &Vec::new(&e),
+        )
+        .is_err());
+
+    let price_feed = create_price_feed_contract(&e);
+    setup.router.pool_gauge_set_price_feed(
+        &setup.admin,
+        &gauge_token.address,
+        &Some(price_feed.clone()),
+    );
+    assert_eq!(
+        setup.router.pool_gauge_get_price_feed(&gauge_token.address),
+        Some(price_feed)
+    );
+
+    // 570 * 86400 * 2 = 9_8496000 is below 10_0000000 threshold
+    assert!(setup
+        .router
+        .try_pool_gauge_schedule_reward(
+            &distributor,
+            &tokens,
+            &pool_hash,
+            &gauge_token.address,
+            &570,
+            &None,
+            &(7 * 24 * 60 * 60),
+            &Vec::new(&e),
+        )
+        .is_err());
+
+    // 600 * 86400 * 2 = 10_3680000 per day
+    let (rewards_gauge, _) = setup.router.pool_gauge_schedule_reward(
+        &distributor,
+        &tokens,
+        &pool_hash,
+        &gauge_token.address,
+        &600,
+        &None,
+        &(7 * 24 * 60 * 60),
+        &Vec::new(&e),
+    );
+    assert_eq!(
+        pool_client.get_gauges(),
+        Map::from_array(&e, [(gauge_token.address.clone(), rewards_gauge.clone())])
+    );
+    assert_eq!(gauge_token.balance(&distributor), 0);
+
+    setup
+        .router
+        .pool_gauge_set_price_feed(&setup.admin, &gauge_token.address, &None);
+    assert_eq!(
+        setup.router.pool_gauge_get_price_feed(&gauge_token.address),
+        None
+    );
+}
+
 #[test]
 fn test_setup_rewards_gauge_concentrated_pool() {
     let setup = Setup::default();
@@ -3608,7 +3750,9 @@ fn test_setup_rewards_gauge_concentrated_pool() {
     setup.reward_token.mint(&user1, &10_0000000);
     let [token1, token2, _, _] = setup.tokens;
     let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
-    let (pool_hash, pool_address) = setup.router.init_concentrated_pool(&user1, &tokens, &30);
+    let (pool_hash, pool_address) = setup
+        .router
+        .init_concentrated_pool(&user1, &tokens, &30, &None);
     let gauges_before: Map<Address, Address> =
         e.invoke_contract(&pool_address, &Symbol::new(&e, "get_gauges"), Vec::new(&e));
     assert_eq!(gauges_before, Map::new(&e));
@@ -3628,7 +3772,7 @@ fn test_setup_rewards_gauge_concentrated_pool() {
         .reward_token
         .mint(&distributor, &(total_reward as i128 * 2));
 
-    let rewards_gauge = setup.router.pool_gauge_schedule_reward(
+    let (rewards_gauge, config_id) = setup.router.pool_gauge_schedule_reward(
         &distributor,
         &tokens,
         &pool_hash,
@@ -3649,7 +3793,7 @@ fn test_setup_rewards_gauge_concentrated_pool() {
             &duration,
             &vec![&e],
         ),
-        rewards_gauge,
+        (rewards_gauge.clone(), config_id + 1),
     );
     assert_eq!(
         e.invoke_contract::<Map<Address, Address>>(
@@ -3808,7 +3952,7 @@ fn test_deploy_multiple_rewards_gauges() {
         );
 
         gauge_token.mint(&distributor, &(1200 * 7 * 24 * 60 * 60));
-        let rewards_gauge = setup.router.pool_gauge_schedule_reward(
+        let (rewards_gauge, _) = setup.router.pool_gauge_schedule_reward(
             &distributor,
             &tokens,
             &pool_hash,
@@ -3865,7 +4009,7 @@ fn test_pool_gauge_schedule_reward_with_reward_token_no_proof() {
     let min_duration = setup.router.pool_gauge_get_min_duration();
     // choose tps so that tps * 86400 >= 10 tokens/day
     let tps_ok = 1300_u128; // 1300 * 86400 > 10_0000000
-    let rewards_gauge = setup.router.pool_gauge_schedule_reward(
+    let (rewards_gauge, _) = setup.router.pool_gauge_schedule_reward(
         &distributor,
         &tokens,
         &pool_hash,
@@ -3888,6 +4032,89 @@ fn test_pool_gauge_schedule_reward_with_reward_token_no_proof() {
     );
 }
 
+#[test]
+fn test_pool_gauge_cancel_and_extend_scheduled_reward() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let user1 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_hash, _pool_address) = setup.router.init_standard_pool(&user1, &tokens, &30);
+    setup
+        .router
+        .pool_gauge_switch_token(&setup.admin, &token1.address, &true);
+    setup
+        .router
+        .pool_gauge_switch_token(&setup.admin, &token2.address, &true);
+
+    let distributor = Address::generate(&e);
+    let min_duration = setup.router.pool_gauge_get_min_duration();
+    let tps = 1300_u128;
+    let reward = tps as i128 * min_duration as i128;
+    setup
+        .reward_token
+        .mint(&distributor, &(reward * 2 + 100 * tps as i128));
+
+    let now = e.ledger().timestamp();
+    let (rewards_gauge, active_config_id) = setup.router.pool_gauge_schedule_reward(
+        &distributor,
+        &tokens,
+        &pool_hash,
+        &setup.reward_token.address,
+        &tps,
+        &None,
+        &min_duration,
+        &Vec::new(&e),
+    );
+    let (future_gauge, future_config_id) = setup.router.pool_gauge_schedule_reward(
+        &distributor,
+        &tokens,
+        &pool_hash,
+        &setup.reward_token.address,
+        &tps,
+        &Some(now + 100),
+        &min_duration,
+        &Vec::new(&e),
+    );
+    assert_eq!(future_gauge, rewards_gauge);
+    assert_ne!(future_config_id, active_config_id);
+    assert_eq!(setup.reward_token.balance(&distributor), 100 * tps as i128);
+
+    // not started reward is refunded in full
+    assert_eq!(
+        setup.router.pool_gauge_cancel_reward(
+            &distributor,
+            &tokens,
+            &pool_hash,
+            &setup.reward_token.address,
+            &future_config_id,
+        ),
+        reward as u128
+    );
+    assert_eq!(
+        setup.reward_token.balance(&distributor),
+        reward + 100 * tps as i128
+    );
+
+    // running reward is extended by its own config id
+    assert_eq!(
+        setup.router.pool_gauge_extend_reward(
+            &distributor,
+            &tokens,
+            &pool_hash,
+            &setup.reward_token.address,
+            &active_config_id,
+            &100,
+            &0,
+            &Vec::new(&e),
+        ),
+        100 * tps
+    );
+    assert_eq!(setup.reward_token.balance(&distributor), reward);
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #307)")]
 fn test_pool_gauge_schedule_reward_empty_path_for_non_reward_token() {
@@ -4335,3 +4562,747 @@ fn test_chained_swap_strict_receive_rebasing_token() {
     assert_eq!(rebasing.balance(&router.address), 0);
     assert!(rebasing.balance(&swapper) < balance_before);
 }
+
+#[test]
+fn test_concentrated_fee_tiers_default() {
+    let setup = Setup::default();
+    let tiers = setup.router.get_concentrated_fee_tiers();
+    assert_eq!(tiers.len(), 3);
+    for (fee, tick_spacing) in [(10u32, 20i32), (30, 60), (100, 200)] {
+        let tier = tiers.get(fee).unwrap();
+        assert_eq!(tier.tick_spacing, tick_spacing);
+        assert_eq!(tier.max_distance, fee * 50);
+    }
+    assert_eq!(setup.router.get_future_concentrated_fee_tier(), None);
+    assert_eq!(setup.router.get_conc_fee_tier_deadline(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #302)")]
+fn test_init_concentrated_pool_unregistered_fee() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let user1 = Address::generate(&e);
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    setup
+        .router
+        .init_concentrated_pool(&user1, &tokens, &1, &None);
+}
+
+#[test]
+fn test_concentrated_fee_tier_timelock() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let user1 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    // 0.01% tier for pegged pairs
+    setup
+        .router
+        .commit_concentrated_fee_tier(&setup.admin, &1, &1, &50);
+    let future = setup.router.get_future_concentrated_fee_tier().unwrap();
+    assert_eq!(
+        (future.fee, future.tick_spacing, future.max_distance),
+        (1, 1, 50)
+    );
+    assert_eq!(
+        setup.router.get_conc_fee_tier_deadline(),
+        e.ledger().timestamp() + ADMIN_ACTIONS_DELAY
+    );
+    // Not usable before it's applied.
+    assert!(setup
+        .router
+        .try_init_concentrated_pool(&user1, &tokens, &1, &None)
+        .is_err());
+    assert!(setup
+        .router
+        .try_apply_concentrated_fee_tier(&setup.admin)
+        .is_err());
+
+    jump(&e, ADMIN_ACTIONS_DELAY + 1);
+    setup.router.apply_concentrated_fee_tier(&setup.admin);
+    assert_eq!(setup.router.get_future_concentrated_fee_tier(), None);
+    assert_eq!(setup.router.get_conc_fee_tier_deadline(), 0);
+    let tiers = setup.router.get_concentrated_fee_tiers();
+    assert_eq!(tiers.len(), 4);
+    assert_eq!(tiers.get(1).unwrap().tick_spacing, 1);
+    // Built-in tiers are kept.
+    assert_eq!(tiers.get(30).unwrap().tick_spacing, 60);
+
+    let (pool_hash, pool_address) = setup
+        .router
+        .init_concentrated_pool(&user1, &tokens, &1, &None);
+    let pool = concentrated_pool::Client::new(&e, &pool_address);
+    assert_eq!(pool.get_tick_spacing(), 1);
+    assert_eq!(pool.get_fee_fraction(), 1);
+    assert_eq!(
+        setup.router.get_pools(&tokens).get(pool_hash.clone()),
+        Some(pool_address.clone())
+    );
+    // Same tier resolves to the same pool.
+    assert_eq!(
+        setup
+            .router
+            .init_concentrated_pool(&user1, &tokens, &1, &None),
+        (pool_hash, pool_address)
+    );
+}
+
+#[test]
+fn test_concentrated_fee_tier_revert() {
+    let setup = Setup::default();
+    setup
+        .router
+        .commit_concentrated_fee_tier(&setup.admin, &200, &400, &10_000);
+    // One pending tier at a time.
+    assert!(setup
+        .router
+        .try_commit_concentrated_fee_tier(&setup.admin, &1, &1, &50)
+        .is_err());
+
+    setup.router.revert_concentrated_fee_tier(&setup.admin);
+    assert_eq!(setup.router.get_future_concentrated_fee_tier(), None);
+    assert_eq!(setup.router.get_conc_fee_tier_deadline(), 0);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    assert!(setup
+        .router
+        .try_apply_concentrated_fee_tier(&setup.admin)
+        .is_err());
+    assert_eq!(setup.router.get_concentrated_fee_tiers().len(), 3);
+}
+
+#[test]
+fn test_init_concentrated_pool_price() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let user1 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_hash, pool_address) = setup
+        .router
+        .init_concentrated_pool(&user1, &tokens, &30, &None);
+
+    let sqrt_price_x96 = U256::from_u128(&e, 1u128 << 96);
+    setup
+        .router
+        .init_concentrated_pool_price(&user1, &tokens, &pool_hash, &sqrt_price_x96);
+    assert_eq!(
+        concentrated_pool::Client::new(&e, &pool_address)
+            .get_slot0()
+            .sqrt_price_x96,
+        sqrt_price_x96
+    );
+
+    // the price can be set only once
+    assert_eq!(
+        setup
+            .router
+            .try_init_concentrated_pool_price(&user1, &tokens, &pool_hash, &sqrt_price_x96)
+            .unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(2127))
+    );
+}
+
+#[test]
+fn test_init_concentrated_pool_with_price() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &20_0000000);
+    let [token1, token2, token3, _] = setup.tokens;
+    for token in [&token1, &token2, &token3] {
+        token.mint(&user2, &1000);
+    }
+
+    // price is set in the same call, before anyone is able to deposit
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let sqrt_price_x96 = U256::from_u128(&e, 2u128 << 96);
+    let (_, pool_address) =
+        setup
+            .router
+            .init_concentrated_pool(&user1, &tokens, &30, &Some(sqrt_price_x96.clone()));
+    assert_eq!(
+        concentrated_pool::Client::new(&e, &pool_address)
+            .get_slot0()
+            .sqrt_price_x96,
+        sqrt_price_x96
+    );
+
+    // with two separate calls anyone can front-run the price with a two-sided deposit
+    let tokens = Vec::from_array(&e, [token2.address.clone(), token3.address.clone()]);
+    let (pool_hash, pool_address) = setup
+        .router
+        .init_concentrated_pool(&user1, &tokens, &30, &None);
+    concentrated_pool::Client::new(&e, &pool_address).deposit_position(
+        &user2,
+        &-120,
+        &120,
+        &Vec::from_array(&e, [1000, 1000]),
+        &0,
+    );
+    assert_eq!(
+        setup
+            .router
+            .try_init_concentrated_pool_price(&user1, &tokens, &pool_hash, &sqrt_price_x96)
+            .unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(2127))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_init_concentrated_pool_price_not_creator() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_hash, _) = setup
+        .router
+        .init_concentrated_pool(&user1, &tokens, &30, &None);
+
+    setup.router.init_concentrated_pool_price(
+        &user2,
+        &tokens,
+        &pool_hash,
+        &U256::from_u128(&e, 1u128 << 96),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #326)")]
+fn test_concentrated_fee_tier_already_exists() {
+    let setup = Setup::default();
+    setup
+        .router
+        .commit_concentrated_fee_tier(&setup.admin, &30, &10, &1_500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #325)")]
+fn test_concentrated_fee_tier_bad_tick_spacing() {
+    let setup = Setup::default();
+    setup
+        .router
+        .commit_concentrated_fee_tier(&setup.admin, &5, &0, &250);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #330)")]
+fn test_concentrated_fee_tier_zero_max_distance() {
+    let setup = Setup::default();
+    setup
+        .router
+        .commit_concentrated_fee_tier(&setup.admin, &5, &1, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #330)")]
+fn test_concentrated_fee_tier_max_distance_above_tick_range() {
+    let setup = Setup::default();
+    setup
+        .router
+        .commit_concentrated_fee_tier(&setup.admin, &5, &1, &1_774_545);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Vote-driven rewards epochs
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_advance_rewards_epoch_from_votes_snapshot() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let user1 = Address::generate(&e);
+    let feed = Address::generate(&e);
+    let [token1, token2, token3, token4] = setup.tokens;
+    setup.reward_token.mint(&user1, &10_0000000);
+    for token in [&token1, &token2, &token3, &token4] {
+        token.mint(&user1, &1000_0000000);
+    }
+
+    let tokens_a = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let tokens_b = Vec::from_array(&e, [token3.address.clone(), token4.address.clone()]);
+    let (pool_a, _) = router.init_standard_pool(&user1, &tokens_a, &30);
+    let (pool_b, _) = router.init_standard_pool(&user1, &tokens_b, &30);
+    let amounts = Vec::from_array(&e, [100_0000000u128, 100_0000000u128]);
+    router.deposit(&user1, &tokens_a, &pool_a, &amounts, &0);
+    router.deposit(&user1, &tokens_b, &pool_b, &amounts, &0);
+
+    // tps and expiration come from the rewards admin, votes from the feed
+    let expired_at = e.ledger().timestamp() + 60;
+    router.config_global_rewards(
+        &setup.rewards_admin,
+        &10_0000000,
+        &expired_at,
+        &Vec::new(&e),
+    );
+    router.set_rewards_votes_feed(&setup.admin, &feed);
+    assert_eq!(router.get_rewards_votes_feed(), Some(feed.clone()));
+    let votes = Vec::from_array(
+        &e,
+        [(tokens_a.clone(), 6_000000), (tokens_b.clone(), 4_000000)],
+    );
+    assert_eq!(router.record_votes_snapshot(&feed, &votes), 1);
+    assert_eq!(router.get_votes_snapshot().unwrap().tokens_votes, votes);
+    assert_eq!(router.get_rewards_epoch(), 0);
+
+    // Anyone can roll, one token set per call.
+    assert_eq!(router.advance_rewards_epoch(&0, &1), 1);
+    assert_eq!(router.get_rewards_epoch(), 1);
+    assert_eq!(router.advance_rewards_epoch(&1, &1), 2);
+    let tokens_for_reward = router.get_tokens_for_reward();
+    assert!(tokens_for_reward.get(tokens_a.clone()).unwrap().1);
+    assert!(tokens_for_reward.get(tokens_b.clone()).unwrap().1);
+    assert_eq!(
+        router.get_total_configured_reward(&tokens_a, &pool_a),
+        6_0000000 * 60
+    );
+    assert_eq!(
+        router.get_total_configured_reward(&tokens_b, &pool_b),
+        4_0000000 * 60
+    );
+
+    // Rolling again is a no-op for already configured pools.
+    assert_eq!(router.advance_rewards_epoch(&0, &10), 2);
+    assert_eq!(router.get_rewards_epoch(), 1);
+
+    // Next epoch: the admin sets a new expiration, token set missing from the votes gets nothing.
+    jump(&e, 60);
+    let expired_at = e.ledger().timestamp() + 60;
+    router.config_global_rewards(
+        &setup.rewards_admin,
+        &10_0000000,
+        &expired_at,
+        &Vec::new(&e),
+    );
+    let votes = Vec::from_array(&e, [(tokens_a.clone(), 1_0000000)]);
+    assert_eq!(router.record_votes_snapshot(&feed, &votes), 2);
+    assert_eq!(router.advance_rewards_epoch(&0, &10), 1);
+    assert_eq!(router.get_rewards_epoch(), 2);
+    let tokens_for_reward = router.get_tokens_for_reward();
+    assert!(tokens_for_reward.get(tokens_b.clone()).is_none());
+    assert_eq!(
+        router.get_rewards_config().get(symbol_short!("exp_at")),
+        Some(expired_at as i128)
+    );
+    assert_eq!(
+        router.get_rewards_config().get(symbol_short!("tps")),
+        Some(10_0000000)
+    );
+    jump(&e, 60);
+    assert_eq!(
+        router.get_total_configured_reward(&tokens_a, &pool_a),
+        6_0000000 * 60 + 10_0000000 * 60
+    );
+    assert_eq!(
+        router.get_total_configured_reward(&tokens_b, &pool_b),
+        4_0000000 * 60
+    );
+}
+
+#[test]
+fn test_advance_rewards_epoch() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let user1 = Address::generate(&e);
+    let [token1, token2, token3, token4] = setup.tokens;
+    setup.reward_token.mint(&user1, &10_0000000);
+    for token in [&token1, &token2, &token3, &token4] {
+        token.mint(&user1, &1000_0000000);
+    }
+
+    let tokens_a = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let tokens_b = Vec::from_array(&e, [token3.address.clone(), token4.address.clone()]);
+    let (pool_a, _) = router.init_standard_pool(&user1, &tokens_a, &30);
+    let (pool_b, _) = router.init_standard_pool(&user1, &tokens_b, &30);
+    let (stable_a, _) = router.init_stableswap_pool(&user1, &tokens_a, &10);
+    let amounts = Vec::from_array(&e, [100_0000000u128, 100_0000000u128]);
+    router.deposit(&user1, &tokens_a, &pool_a, &amounts, &0);
+    router.deposit(&user1, &tokens_a, &stable_a, &amounts, &0);
+    router.deposit(&user1, &tokens_b, &pool_b, &amounts, &0);
+
+    router.config_global_rewards(
+        &setup.rewards_admin,
+        &10_0000000,
+        &(e.ledger().timestamp() + 60),
+        &Vec::from_array(
+            &e,
+            [(tokens_a.clone(), 5_000000), (tokens_b.clone(), 5_000000)],
+        ),
+    );
+    assert_eq!(router.get_rewards_epoch_cursor(), 0);
+
+    // Anyone can crank; progress is stored.
+    assert_eq!(router.advance_rewards_epoch(&0, &1), 1);
+    assert_eq!(
+        count_events(&e, &router.address, "rewards_epoch_completed"),
+        0
+    );
+    assert_eq!(router.get_rewards_epoch_cursor(), 1);
+    let configured_a = router.get_total_configured_reward(&tokens_a, &pool_a)
+        + router.get_total_configured_reward(&tokens_a, &stable_a);
+    assert!(configured_a > 0);
+    assert_eq!(router.get_total_configured_reward(&tokens_b, &pool_b), 0);
+
+    // Replaying a processed batch is a no-op.
+    assert_eq!(router.advance_rewards_epoch(&0, &1), 1);
+    assert_eq!(count_events(&e, &router.address, "config_rewards"), 0);
+
+    assert_eq!(router.advance_rewards_epoch(&1, &5), 2);
+    assert_eq!(
+        count_events(&e, &router.address, "rewards_epoch_completed"),
+        1
+    );
+    assert_eq!(
+        router.get_total_configured_reward(&tokens_b, &pool_b),
+        5_0000000 * 60
+    );
+    assert!(router.get_tokens_for_reward().get(tokens_b).unwrap().1);
+
+    // Done: further calls change nothing and the event is not repeated.
+    assert_eq!(router.advance_rewards_epoch(&2, &5), 2);
+    assert_eq!(
+        count_events(&e, &router.address, "rewards_epoch_completed"),
+        0
+    );
+
+    // New config starts over.
+    jump(&e, 30);
+    router.config_global_rewards(
+        &setup.rewards_admin,
+        &10_0000000,
+        &(e.ledger().timestamp() + 60),
+        &Vec::from_array(&e, [(tokens_a.clone(), 1_0000000)]),
+    );
+    assert_eq!(router.get_rewards_epoch_cursor(), 0);
+    assert_eq!(router.advance_rewards_epoch(&0, &5), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_record_votes_snapshot_not_feed() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let feed = Address::generate(&e);
+    setup.router.set_rewards_votes_feed(&setup.admin, &feed);
+    setup
+        .router
+        .record_votes_snapshot(&setup.rewards_admin, &Vec::new(&e));
+}
+
+#[test]
+fn test_votes_snapshot_cannot_set_reward_tps() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let user1 = Address::generate(&e);
+    let feed = Address::generate(&e);
+    let [token1, token2, _, _] = setup.tokens;
+    for token in [&token1, &token2] {
+        token.mint(&user1, &1000_0000000);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool, _) = router.init_standard_pool(&user1, &tokens, &30);
+    let amounts = Vec::from_array(&e, [100_0000000u128, 100_0000000u128]);
+    router.deposit(&user1, &tokens, &pool, &amounts, &0);
+
+    // without an admin configured tps the voted pools get no rewards
+    router.set_rewards_votes_feed(&setup.admin, &feed);
+    router.record_votes_snapshot(&feed, &Vec::from_array(&e, [(tokens.clone(), 1_0000000)]));
+    assert_eq!(router.advance_rewards_epoch(&0, &10), 1);
+    assert_eq!(router.get_rewards_epoch(), 1);
+    assert_eq!(
+        router.get_rewards_config().get(symbol_short!("tps")),
+        Some(0)
+    );
+    jump(&e, 60);
+    assert_eq!(router.get_total_configured_reward(&tokens, &pool), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #311)")]
+fn test_record_votes_snapshot_share_exceeds_max() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let feed = Address::generate(&e);
+    let [token1, token2, token3, _] = setup.tokens;
+    setup.router.set_rewards_votes_feed(&setup.admin, &feed);
+    let votes = Vec::from_array(
+        &e,
+        [
+            (
+                Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]),
+                6_000000,
+            ),
+            (
+                Vec::from_array(&e, [token2.address.clone(), token3.address.clone()]),
+                5_000000,
+            ),
+        ],
+    );
+    setup.router.record_votes_snapshot(&feed, &votes);
+}
+
+#[test]
+fn test_advance_rewards_epoch_without_reward_tokens() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    router.config_global_rewards(
+        &setup.rewards_admin,
+        &10_0000000,
+        &(e.ledger().timestamp() + 60),
+        &Vec::new(&e),
+    );
+
+    // nothing to process, the epoch completes on the first call only
+    assert_eq!(router.advance_rewards_epoch(&0, &5), 0);
+    assert_eq!(
+        count_events(&e, &router.address, "rewards_epoch_completed"),
+        1
+    );
+    assert_eq!(router.advance_rewards_epoch(&0, &5), 0);
+    assert_eq!(
+        count_events(&e, &router.address, "rewards_epoch_completed"),
+        0
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Reward reinvesting
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_claim_and_reinvest() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let user = Address::generate(&e);
+    let lp = Address::generate(&e);
+    // reward token is the first of the sorted setup tokens
+    let [reward_token, token1, token2, _] = setup.tokens;
+    for token in [&token1, &token2, &reward_token] {
+        token.mint(&user, &1000_0000000);
+        token.mint(&lp, &1000_0000000);
+    }
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_index, _) = router.init_standard_pool(&user, &tokens, &30);
+    let amounts = Vec::from_array(&e, [100_0000000u128, 100_0000000u128]);
+    router.deposit(&user, &tokens, &pool_index, &amounts, &0);
+
+    // pools to swap the reward token into both pool tokens
+    let reward_tokens1 =
+        Vec::from_array(&e, [reward_token.address.clone(), token1.address.clone()]);
+    let reward_tokens2 =
+        Vec::from_array(&e, [reward_token.address.clone(), token2.address.clone()]);
+    let (reward_pool1, _) = router.init_standard_pool(&lp, &reward_tokens1, &30);
+    let (reward_pool2, _) = router.init_standard_pool(&lp, &reward_tokens2, &30);
+    router.deposit(&lp, &reward_tokens1, &reward_pool1, &amounts, &0);
+    router.deposit(&lp, &reward_tokens2, &reward_pool2, &amounts, &0);
+
+    router.config_global_rewards(
+        &setup.admin,
+        &1_0000000,
+        &e.ledger().timestamp().saturating_add(60),
+        &Vec::from_array(&e, [(tokens.clone(), 1_0000000)]),
+    );
+    router.fill_liquidity(&setup.admin, &tokens);
+    router.config_pool_rewards(&setup.admin, &tokens, &pool_index);
+    reward_token.mint(&router.address, &60_0000000);
+    router.distribute_outstanding_reward(&setup.admin, &router.address, &tokens, &pool_index);
+    jump(&e, 30);
+
+    let token_share = test_token::Client::new(&e, &router.share_id(&tokens, &pool_index));
+    let shares_before = token_share.balance(&user);
+    let reward_before = reward_token.balance(&user);
+    let swaps_chains = Vec::from_array(
+        &e,
+        [
+            (
+                reward_token.address.clone(),
+                Vec::from_array(&e, [(reward_tokens1, reward_pool1, token1.address.clone())]),
+            ),
+            (
+                reward_token.address.clone(),
+                Vec::from_array(&e, [(reward_tokens2, reward_pool2, token2.address.clone())]),
+            ),
+        ],
+    );
+
+    // single slippage guard on minted shares
+    assert!(router
+        .try_claim_and_reinvest(&user, &tokens, &pool_index, &swaps_chains, &100_0000000)
+        .is_err());
+
+    let (deposited, shares) =
+        router.claim_and_reinvest(&user, &tokens, &pool_index, &swaps_chains, &1);
+    assert!(shares > 0);
+    assert!(deposited.get(0).unwrap() > 0 && deposited.get(1).unwrap() > 0);
+    assert_eq!(token_share.balance(&user), shares_before + shares as i128);
+    // the whole claimed reward was swapped, nothing left on the user balance
+    assert_eq!(reward_token.balance(&user), reward_before);
+    assert_eq!(router.get_user_reward(&user, &tokens, &pool_index), 0);
+}
+
+#[test]
+fn test_claim_and_reinvest_with_vesting() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let user = Address::generate(&e);
+    let [reward_token, token1, token2, _] = setup.tokens;
+    for token in [&token1, &token2, &reward_token] {
+        token.mint(&user, &1000_0000000);
+    }
+
+    let tokens = Vec::from_array(&e, [reward_token.address.clone(), token1.address.clone()]);
+    let pool_index = BytesN::from_array(&e, &[1; 32]);
+    let pool_address = e.register(MockVestingPool {}, ());
+    e.as_contract(&router.address, || {
+        add_pool(
+            &e,
+            get_tokens_salt(&e, &tokens),
+            pool_index.clone(),
+            LiquidityPoolType::ConstantProduct,
+            pool_address.clone(),
+        )
+    });
+
+    // pool to swap the reward token into the second pool token
+    let lp = Address::generate(&e);
+    reward_token.mint(&lp, &1000_0000000);
+    token1.mint(&lp, &1000_0000000);
+    let (swap_pool, _) = router.init_standard_pool(&lp, &tokens, &30);
+    router.deposit(
+        &lp,
+        &tokens,
+        &swap_pool,
+        &Vec::from_array(&e, [100_0000000u128, 100_0000000u128]),
+        &0,
+    );
+    let swaps_chains = Vec::from_array(
+        &e,
+        [
+            (reward_token.address.clone(), Vec::new(&e)),
+            (
+                reward_token.address.clone(),
+                Vec::from_array(&e, [(tokens.clone(), swap_pool, token1.address.clone())]),
+            ),
+        ],
+    );
+
+    // the claimed reward is vesting, tokens already on the user balance are not spent
+    let (deposited, shares) =
+        router.claim_and_reinvest(&user, &tokens, &pool_index, &swaps_chains, &0);
+    assert_eq!(deposited, Vec::from_array(&e, [0, 0]));
+    assert_eq!(shares, 0);
+    assert_eq!(reward_token.balance(&user), 1000_0000000);
+    assert_eq!(token1.balance(&user), 1000_0000000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #328)")]
+fn test_claim_and_reinvest_chain_not_ending_with_pool_token() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let user = Address::generate(&e);
+    let [reward_token, token1, token2, _] = setup.tokens;
+    for token in [&token1, &token2, &reward_token] {
+        token.mint(&user, &1000_0000000);
+    }
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_index, _) = router.init_standard_pool(&user, &tokens, &30);
+    router.deposit(
+        &user,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [100_0000000u128, 100_0000000u128]),
+        &0,
+    );
+
+    // reward token is not a pool token, it can't be deposited without swaps
+    router.claim_and_reinvest(
+        &user,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [(reward_token.address.clone(), Vec::new(&e))]),
+        &0,
+    );
+}
+
+#[test]
+fn test_reward_history_without_pool_support() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let user = Address::generate(&e);
+    let [reward_token, token1, token2, _] = setup.tokens;
+    for token in [&token1, &token2, &reward_token] {
+        token.mint(&user, &1000_0000000);
+    }
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_index, pool_address) = router.init_standard_pool(&user, &tokens, &30);
+    router.deposit(
+        &user,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [100_0000000u128, 100_0000000u128]),
+        &0,
+    );
+
+    // pools deployed before the reward history are reported with empty pages
+    let pools = Vec::from_array(&e, [(tokens, pool_index)]);
+    let snapshots = router.get_pools_reward_snapshots(&pools, &0, &10);
+    assert_eq!(snapshots.len(), 1);
+    assert_eq!(snapshots.get(pool_address.clone()), Some(Vec::new(&e)));
+    let claims = router.get_user_reward_claims(&user, &pools, &0, &10);
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims.get(pool_address), Some(Vec::new(&e)));
+}
+
+#[test]
+fn test_update_boost_for_pools_without_pool_support() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let user = Address::generate(&e);
+    let [reward_token, token1, token2, _] = setup.tokens;
+    for token in [&token1, &token2, &reward_token] {
+        token.mint(&user, &1000_0000000);
+    }
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let (pool_index, _pool_address) = router.init_standard_pool(&user, &tokens, &30);
+    router.deposit(
+        &user,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [100_0000000u128, 100_0000000u128]),
+        &0,
+    );
+
+    // the call is permissionless
+    e.set_auths(&[]);
+    assert_eq!(
+        router.update_boost_for_pools(&user, &Vec::new(&e)),
+        Map::new(&e)
+    );
+
+    // pools deployed before boost updates fail the call instead of being skipped
+    let pools = Vec::from_array(&e, [(tokens, pool_index)]);
+    assert!(router.try_update_boost_for_pools(&user, &pools).is_err());
+}
@@ -374,6 +374,35 @@ fn test_set_hashes() {
     }
 }
 
+#[test]
+fn test_concentrated_fee_tier_permissions() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let user = Address::generate(&setup.env);
+
+    for addr in [
+        user,
+        setup.emergency_admin,
+        setup.rewards_admin,
+        setup.operations_admin.clone(),
+        setup.pause_admin,
+        setup.emergency_pause_admin,
+    ] {
+        assert!(router
+            .try_commit_concentrated_fee_tier(&addr, &1, &1, &50)
+            .is_err());
+    }
+    router.commit_concentrated_fee_tier(&setup.admin, &1, &1, &50);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    assert!(router
+        .try_apply_concentrated_fee_tier(&setup.operations_admin)
+        .is_err());
+    assert!(router
+        .try_revert_concentrated_fee_tier(&setup.operations_admin)
+        .is_err());
+    router.apply_concentrated_fee_tier(&setup.admin);
+}
+
 #[test]
 fn test_set_reward_token() {
     let setup = Setup::default();
@@ -647,6 +676,32 @@ fn test_gauge_enable_token() {
     }
 }
 
+#[test]
+fn test_gauge_set_price_feed() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let user = Address::generate(&setup.env);
+    let feed = Address::generate(&setup.env);
+    let [token1, _, _, _] = setup.tokens;
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.emergency_admin, false),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(
+            router
+                .try_pool_gauge_set_price_feed(&addr, &token1.address, &Some(feed.clone()))
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
 #[test]
 fn test_distribute_rewards() {
     let setup = Setup::default();
@@ -763,3 +818,26 @@ fn test_set_protocol_fee() {
         );
     }
 }
+
+#[test]
+fn test_set_rewards_votes_feed() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let user = Address::generate(&setup.env);
+    let feed = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user.clone(), false),
+        (setup.admin.clone(), true),
+        (setup.emergency_admin, false),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(
+            router.try_set_rewards_votes_feed(&addr, &feed).is_ok(),
+            is_ok
+        );
+    }
+}
@@ -5,7 +5,7 @@ extern crate std;
 use crate::LiquidityPoolRouterClient;
 use liquidity_pool_config_storage::testutils::deploy_config_storage;
 use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Vec};
 
 pub(crate) mod test_token {
     use soroban_sdk::contractimport;
@@ -99,6 +99,51 @@ pub(crate) mod rewards_gauge {
     soroban_sdk::contractimport!(file = "../contracts/soroban_rewards_gauge_contract.wasm");
 }
 
+// Price feed valuing any token at 2x of the quote token.
+#[contract]
+pub(crate) struct MockPriceFeed;
+
+#[contractimpl]
+impl MockPriceFeed {
+    pub fn get_price_equivalent(
+        _e: Env,
+        _token: Address,
+        _quote_token: Address,
+        amount: u128,
+    ) -> u128 {
+        amount * 2
+    }
+}
+
+pub(crate) fn create_price_feed_contract(e: &Env) -> Address {
+    e.register(MockPriceFeed {}, ())
+}
+
+// Pool with rewards vesting: claim reports the reward but only schedules it, nothing is transferred.
+#[contract]
+pub(crate) struct MockVestingPool;
+
+#[contractimpl]
+impl MockVestingPool {
+    pub fn claim(_e: Env, _user: Address) -> u128 {
+        100_0000000
+    }
+
+    pub fn deposit(
+        e: Env,
+        user: Address,
+        desired_amounts: Vec<u128>,
+        _min_shares: u128,
+    ) -> (Vec<u128>, u128) {
+        user.require_auth();
+        let share_amount = desired_amounts.iter().sum();
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "deposited"), &desired_amounts);
+        (desired_amounts, share_amount)
+    }
+}
+
 pub(crate) mod config_storage {
     soroban_sdk::contractimport!(file = "../contracts/soroban_config_storage_contract.wasm");
 }
@@ -49,7 +49,9 @@ use liquidity_pool_events::{Events as PoolEvents, LiquidityPoolEvents};
 use liquidity_pool_validation_errors::LiquidityPoolValidationError;
 use rewards::events::Events as RewardEvents;
 use rewards::storage::{
-    BoostFeedStorageTrait, BoostTokenStorageTrait, PoolRewardsStorageTrait, RewardTokenStorageTrait,
+    BoostConfig, BoostDelegation, BoostFeedStorageTrait, BoostTokenStorageTrait, PoolRewardConfig,
+    PoolRewardsStorageTrait, RewardClaimRecord, RewardEpochSnapshot, RewardTokenStorageTrait,
+    VestingConfig,
 };
 use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::token::Client as SorobanTokenClient;
@@ -838,6 +840,86 @@ impl LiquidityPool {
 
         set_reserves_sync_ledger(e, &current_ledger);
     }
+
+    // Claims the user's reward to the recipient, checkpointing the gauges around the claim.
+    fn _claim_reward(e: &Env, user: &Address, recipient: &Address) -> u128 {
+        if get_is_killed_claim(e) {
+            panic_with_error!(e, LiquidityPoolError::PoolClaimKilled);
+        }
+
+        let rewards = get_rewards_manager(e);
+        let total_shares = get_total_shares(e);
+        let user_shares = get_user_balance_shares(e, user);
+        let mut rewards_manager = rewards.manager();
+        let rewards_storage = rewards.storage();
+        rewards_gauge::operations::checkpoint_user(
+            e,
+            user,
+            rewards_manager.get_working_balance(user, user_shares),
+            rewards_manager.get_working_supply(total_shares),
+        );
+        let reward = rewards_manager.claim_reward_to(user, recipient, total_shares, user_shares);
+
+        let reward_token = rewards_storage.get_reward_token();
+        Self::_assert_reward_reserves(e, &reward_token);
+
+        if recipient == user {
+            RewardEvents::new(e).claim(user.clone(), reward_token, reward);
+        } else {
+            RewardEvents::new(e).claim_to(user.clone(), recipient.clone(), reward_token, reward);
+        }
+
+        // second gauge checkpoint with updated working balance
+        let manager_after = rewards.manager();
+        rewards_gauge::operations::checkpoint_user(
+            e,
+            user,
+            manager_after.get_working_balance(user, user_shares),
+            manager_after.get_working_supply(total_shares),
+        );
+
+        reward
+    }
+
+    // Recalculates the user's working balance, settling rewards and gauges with the previous one.
+    fn _checkpoint_working_balance(e: &Env, user: &Address) {
+        let total_shares = get_total_shares(e);
+        let user_shares = get_user_balance_shares(e, user);
+        let mut rewards_manager = get_rewards_manager(e).manager();
+        rewards_gauge::operations::checkpoint_user(
+            e,
+            user,
+            rewards_manager.get_working_balance(user, user_shares),
+            rewards_manager.get_working_supply(total_shares),
+        );
+        rewards_manager.checkpoint_user(user, total_shares, user_shares);
+        rewards_gauge::operations::checkpoint_user(
+            e,
+            user,
+            rewards_manager.get_working_balance(user, user_shares),
+            rewards_manager.get_working_supply(total_shares),
+        );
+    }
+
+    // Validate reserves after a reward transfer - they should be less than or equal to the balance.
+    fn _assert_reward_reserves(e: &Env, reward_token: &Address) {
+        let tokens = get_tokens(e);
+        let reserves = get_reserves(e);
+        let protocol_fees = get_protocol_fees(e);
+
+        for i in 0..reserves.len() {
+            let token = tokens.get(i).unwrap();
+            if token != *reward_token {
+                continue;
+            }
+
+            let balance =
+                SorobanTokenClient::new(e, &token).balance(&e.current_contract_address()) as u128;
+            if reserves.get_unchecked(i) + protocol_fees.get_unchecked(i) > balance {
+                panic_with_error!(e, LiquidityPoolValidationError::InsufficientBalance);
+            }
+        }
+    }
 }
 
 #[contractimpl]
@@ -2141,6 +2223,147 @@ impl RewardsTrait for LiquidityPool {
         rewards_storage.put_reward_boost_feed(reward_boost_feed);
     }
 
+    // Commits new boost parameters, applicable after the admin actions delay.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `max_boost_bps` - Maximum working balance multiplier, 25000 = 2.5x.
+    fn commit_boost_config(e: Env, admin: Address, max_boost_bps: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        get_rewards_manager(&e)
+            .manager()
+            .commit_boost_config(&BoostConfig { max_boost_bps });
+        RewardEvents::new(&e).commit_boost_config(max_boost_bps);
+    }
+
+    // Applies the committed boost parameters.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    fn apply_boost_config(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        let config = get_rewards_manager(&e).manager().apply_boost_config();
+        RewardEvents::new(&e).apply_boost_config(config.max_boost_bps);
+    }
+
+    // Discards the committed boost parameters.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    fn revert_boost_config(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        get_rewards_manager(&e).manager().revert_boost_config();
+        RewardEvents::new(&e).revert_boost_config();
+    }
+
+    // Returns the boost parameters in use.
+    fn get_boost_config(e: Env) -> BoostConfig {
+        get_rewards_manager(&e).manager().get_boost_config()
+    }
+
+    // Returns the committed boost parameters waiting for the timelock, if any.
+    fn get_future_boost_config(e: Env) -> Option<BoostConfig> {
+        get_rewards_manager(&e).manager().get_future_boost_config()
+    }
+
+    // Switches boost balances to the boost feed lock records, or back to the boost token.
+    // Enable once locks are backfilled; accounts without a record get no boost afterwards.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `enabled` - Whether boost balances come from the feed lock records.
+    fn set_boost_lock_records(e: Env, admin: Address, enabled: bool) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        get_rewards_manager(&e)
+            .manager()
+            .set_boost_lock_records_enabled(enabled);
+        RewardEvents::new(&e).set_boost_lock_records(enabled);
+    }
+
+    // Returns whether boost balances come from the boost feed lock records.
+    fn get_boost_lock_records(e: Env) -> bool {
+        get_rewards_manager(&e)
+            .manager()
+            .get_boost_lock_records_enabled()
+    }
+
+    // Lends part of the owner's boost to the delegatee until `expires_at`.
+    // Working balances of both accounts are checkpointed with the new boost.
+    //
+    // # Arguments
+    //
+    // * `owner` - The account lending its boost.
+    // * `delegatee` - The account receiving the boost, e.g. a hot wallet or a vault.
+    // * `amount_bps` - Part of the owner's boost balance to delegate, 0 revokes the delegation.
+    // * `expires_at` - The timestamp when the delegation ends.
+    fn delegate_boost(
+        e: Env,
+        owner: Address,
+        delegatee: Address,
+        amount_bps: u32,
+        expires_at: u64,
+    ) {
+        owner.require_auth();
+
+        get_rewards_manager(&e)
+            .manager()
+            .set_boost_delegation(&owner, &delegatee, amount_bps, expires_at);
+        Self::_checkpoint_working_balance(&e, &owner);
+        Self::_checkpoint_working_balance(&e, &delegatee);
+        RewardEvents::new(&e).set_boost_delegation(owner, delegatee, amount_bps, expires_at);
+    }
+
+    // Returns active delegations of the owner's boost keyed by delegatee.
+    fn get_boost_delegations(e: Env, owner: Address) -> Map<Address, BoostDelegation> {
+        get_rewards_manager(&e)
+            .manager()
+            .get_boost_delegations(&owner)
+    }
+
+    // Returns the owners with an active boost delegation to the delegatee.
+    fn get_boost_delegators(e: Env, delegatee: Address) -> Vec<Address> {
+        get_rewards_manager(&e)
+            .manager()
+            .get_boost_delegators(&delegatee)
+    }
+
+    // Recalculates the working balance of the user and the accounts sharing boost with it,
+    // so expired or changed delegations are picked up on both sides.
+    // Anyone can call it to sync the core rewards and every gauge with the user's lock changes.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user.
+    //
+    // # Returns
+    //
+    // The new working balance of the user.
+    fn update_boost(e: Env, user: Address) -> u128 {
+        Self::_checkpoint_working_balance(&e, &user);
+        for account in get_rewards_manager(&e)
+            .manager()
+            .get_boost_counterparties(&user)
+            .iter()
+        {
+            Self::_checkpoint_working_balance(&e, &account);
+        }
+        get_rewards_manager(&e)
+            .manager()
+            .get_working_balance(&user, get_user_balance_shares(&e, &user))
+    }
+
     // Sets the rewards configuration.
     //
     // # Arguments
@@ -2291,6 +2514,34 @@ impl RewardsTrait for LiquidityPool {
         result.set(symbol_short!("usr_block"), user_data.last_block as i128);
         result.set(symbol_short!("to_claim"), user_data.to_claim as i128);
 
+        // claimed rewards locked in vesting and the part already releasable with claim_vested
+        let vesting = manager.get_user_vesting(&user);
+        let vesting_config = manager.get_vesting_config();
+        result.set(
+            Symbol::new(&e, "vesting_locked"),
+            vesting.as_ref().map_or(0, |v| v.total) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_claimable"),
+            vesting.as_ref().map_or(0, |v| v.unlocked) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_end"),
+            vesting.map_or(0, |v| v.end_time()) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_cliff"),
+            vesting_config.as_ref().map_or(0, |v| v.cliff) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_duration"),
+            vesting_config.as_ref().map_or(0, |v| v.duration) as i128,
+        );
+        result.set(
+            Symbol::new(&e, "vesting_penalty"),
+            vesting_config.map_or(0, |v| v.early_exit_penalty_bps) as i128,
+        );
+
         // provide updated working balance information. if working_balance_new is bigger
         // than working_balance, it means that user has locked some tokens
         // and needs to checkpoint itself for more rewards
@@ -2464,6 +2715,113 @@ impl RewardsTrait for LiquidityPool {
     //
     // The amount of tokens rewarded to the user as a u128.
     fn claim(e: Env, user: Address) -> u128 {
+        Self::_claim_reward(&e, &user, &user)
+    }
+
+    // Claims the reward of the user and sends it to the recipient.
+    // Authorized by the user, or by the user's delegated claimer if one is set.
+    //
+    // # Arguments
+    //
+    // * `e` - The environment.
+    // * `caller` - The user or the user's delegated claimer.
+    // * `user` - The address of the user.
+    // * `recipient` - The address receiving the reward tokens.
+    //
+    // # Returns
+    //
+    // The amount of tokens rewarded as a u128.
+    fn claim_to(e: Env, caller: Address, user: Address, recipient: Address) -> u128 {
+        get_rewards_manager(&e)
+            .manager()
+            .require_claim_auth(&caller, &user);
+        Self::_claim_reward(&e, &user, &recipient)
+    }
+
+    // Allows the claimer to claim the user's rewards to any recipient.
+    //
+    // # Arguments
+    //
+    // * `e` - The environment.
+    // * `user` - The address of the user.
+    // * `claimer` - The delegated claimer. None revokes the delegation.
+    fn set_reward_claimer(e: Env, user: Address, claimer: Option<Address>) {
+        user.require_auth();
+        get_rewards_manager(&e)
+            .manager()
+            .set_reward_claimer(&user, claimer.clone());
+        RewardEvents::new(&e).set_reward_claimer(user, claimer);
+    }
+
+    fn get_reward_claimer(e: Env, user: Address) -> Option<Address> {
+        get_rewards_manager(&e).manager().get_reward_claimer(&user)
+    }
+
+    // Configures vesting of claimed rewards.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `cliff` - Seconds after a claim before anything is releasable.
+    // * `duration` - Seconds over which a claim unlocks linearly. 0 disables vesting.
+    // * `early_exit_penalty_bps` - Part of the locked amount forfeited by `exit_vesting`, 100 = 1%.
+    fn set_rewards_vesting(
+        e: Env,
+        admin: Address,
+        cliff: u64,
+        duration: u64,
+        early_exit_penalty_bps: u32,
+    ) {
+        admin.require_auth();
+        require_rewards_admin_or_owner(&e, &admin);
+
+        let config = match duration {
+            0 => None,
+            _ => Some(VestingConfig {
+                cliff,
+                duration,
+                early_exit_penalty_bps,
+            }),
+        };
+        get_rewards_manager(&e).manager().set_vesting_config(config);
+        RewardEvents::new(&e).set_rewards_vesting(cliff, duration, early_exit_penalty_bps);
+    }
+
+    // Transfers the vested part of the user's claimed rewards.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user.
+    //
+    // # Returns
+    //
+    // The amount of tokens transferred to the user.
+    fn claim_vested(e: Env, user: Address) -> u128 {
+        if get_is_killed_claim(&e) {
+            panic_with_error!(e, LiquidityPoolError::PoolClaimKilled);
+        }
+
+        let rewards = get_rewards_manager(&e);
+        let amount = rewards.manager().claim_vested(&user);
+
+        let reward_token = rewards.storage().get_reward_token();
+        Self::_assert_reward_reserves(&e, &reward_token);
+        RewardEvents::new(&e).claim_vested(user, reward_token, amount);
+        amount
+    }
+
+    // Transfers the whole vesting balance at once. The locked part is reduced by the
+    // early exit penalty, which is distributed to the other liquidity providers.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user.
+    //
+    // # Returns
+    //
+    // The amount of tokens transferred to the user and the penalty.
+    fn exit_vesting(e: Env, user: Address) -> (u128, u128) {
+        user.require_auth();
         if get_is_killed_claim(&e) {
             panic_with_error!(e, LiquidityPoolError::PoolClaimKilled);
         }
@@ -2472,35 +2830,152 @@ impl RewardsTrait for LiquidityPool {
         let total_shares = get_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         let mut rewards_manager = rewards.manager();
-        let rewards_storage = rewards.storage();
         rewards_gauge::operations::checkpoint_user(
             &e,
             &user,
             rewards_manager.get_working_balance(&user, user_shares),
             rewards_manager.get_working_supply(total_shares),
         );
-        let reward = rewards_manager.claim_reward(&user, total_shares, user_shares);
+        let (amount, penalty) = rewards_manager.exit_vesting(&user, total_shares, user_shares);
 
-        // validate reserves after claim - they should be less than or equal to the balance
-        let tokens = get_tokens(&e);
-        let reward_token = rewards_storage.get_reward_token();
-        let reserves = get_reserves(&e);
-        let protocol_fees = get_protocol_fees(&e);
+        let reward_token = rewards.storage().get_reward_token();
+        Self::_assert_reward_reserves(&e, &reward_token);
+        RewardEvents::new(&e).exit_vesting(user, reward_token, amount, penalty);
+        (amount, penalty)
+    }
 
-        for i in 0..reserves.len() {
-            let token = tokens.get(i).unwrap();
-            if token != reward_token {
-                continue;
-            }
+    // Enables or disables recording of reward history.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `enabled` - Whether pool reward snapshots and user claims are recorded.
+    fn set_rewards_history(e: Env, admin: Address, enabled: bool) {
+        admin.require_auth();
+        require_rewards_admin_or_owner(&e, &admin);
 
-            let balance = SorobanTokenClient::new(&e, &tokens.get(i).unwrap())
-                .balance(&e.current_contract_address()) as u128;
-            if reserves.get_unchecked(i) + protocol_fees.get_unchecked(i) > balance {
-                panic_with_error!(&e, LiquidityPoolValidationError::InsufficientBalance);
-            }
+        get_rewards_manager(&e)
+            .manager()
+            .set_reward_history_enabled(enabled);
+        RewardEvents::new(&e).set_rewards_history(enabled);
+    }
+
+    fn get_reward_snapshots(e: Env, offset: u32, limit: u32) -> Vec<RewardEpochSnapshot> {
+        get_rewards_manager(&e)
+            .manager()
+            .get_reward_epoch_snapshots(offset, limit)
+    }
+
+    fn get_user_reward_claims(
+        e: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<RewardClaimRecord> {
+        get_rewards_manager(&e)
+            .manager()
+            .get_user_reward_claims(&user, offset, limit)
+    }
+
+    // Adds a reward token accounted separately from the primary one.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `token` - The reward token to add.
+    fn add_reward_token(e: Env, admin: Address, token: Address) {
+        admin.require_auth();
+        require_rewards_admin_or_owner(&e, &admin);
+
+        get_rewards_manager(&e).manager().add_reward_token(&token);
+        RewardEvents::new(&e).add_reward_token(token);
+    }
+
+    fn get_reward_tokens(e: Env) -> Vec<Address> {
+        get_rewards_manager(&e).manager().get_reward_tokens()
+    }
+
+    // Configures rewards of one of the pool reward tokens.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `token` - The reward token.
+    // * `expired_at` - The timestamp when the rewards expire.
+    // * `tps` - The amount of tokens distributed per second, 0 stops the rewards.
+    fn set_token_rewards_config(
+        e: Env,
+        admin: Address,
+        token: Address,
+        expired_at: u64,
+        tps: u128,
+    ) {
+        admin.require_auth();
+        require_rewards_admin_or_owner(&e, &admin);
+
+        let total_shares = get_total_shares(&e);
+        get_rewards_manager(&e).manager().set_token_reward_config(
+            &token,
+            total_shares,
+            expired_at,
+            tps,
+        );
+        RewardEvents::new(&e).set_token_rewards_config(token, expired_at, tps);
+    }
+
+    fn get_token_rewards_config(e: Env, token: Address) -> PoolRewardConfig {
+        get_rewards_manager(&e)
+            .manager()
+            .get_token_reward_config(&token)
+    }
+
+    fn get_user_rewards(e: Env, user: Address) -> Map<Address, u128> {
+        let rewards = get_rewards_manager(&e);
+        let total_shares = get_total_shares(&e);
+        let user_shares = get_user_balance_shares(&e, &user);
+        let mut manager = rewards.manager();
+
+        rewards_gauge::operations::checkpoint_user(
+            &e,
+            &user,
+            manager.get_working_balance(&user, user_shares),
+            manager.get_working_supply(total_shares),
+        );
+
+        manager.get_amounts_to_claim(&user, total_shares, user_shares)
+    }
+
+    // Claims the rewards in every reward token as a user.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user.
+    //
+    // # Returns
+    //
+    // The amounts of tokens rewarded to the user by token.
+    fn claim_rewards(e: Env, user: Address) -> Map<Address, u128> {
+        if get_is_killed_claim(&e) {
+            panic_with_error!(e, LiquidityPoolError::PoolClaimKilled);
         }
 
-        RewardEvents::new(&e).claim(user.clone(), reward_token, reward);
+        let rewards = get_rewards_manager(&e);
+        let total_shares = get_total_shares(&e);
+        let user_shares = get_user_balance_shares(&e, &user);
+        let mut rewards_manager = rewards.manager();
+        rewards_gauge::operations::checkpoint_user(
+            &e,
+            &user,
+            rewards_manager.get_working_balance(&user, user_shares),
+            rewards_manager.get_working_supply(total_shares),
+        );
+        let rewards_claimed =
+            rewards_manager.claim_rewards_to(&user, &user, total_shares, user_shares);
+
+        for (reward_token, reward) in rewards_claimed.iter() {
+            Self::_assert_reward_reserves(&e, &reward_token);
+            RewardEvents::new(&e).claim(user.clone(), reward_token, reward);
+        }
 
         // second gauge checkpoint with updated working balance
         let manager_after = rewards.manager();
@@ -2511,7 +2986,7 @@ impl RewardsTrait for LiquidityPool {
             manager_after.get_working_supply(total_shares),
         );
 
-        reward
+        rewards_claimed
     }
 
     fn get_rewards_state(e: Env, user: Address) -> bool {
@@ -2595,7 +3070,7 @@ impl RewardsGaugeInterface for LiquidityPool {
         start_at: Option<u64>,
         duration: u64,
         tps: u128,
-    ) {
+    ) -> u32 {
         router.require_auth();
         distributor.require_auth();
 
@@ -2615,7 +3090,80 @@ impl RewardsGaugeInterface for LiquidityPool {
             duration,
             tps,
             rewards_manager.get_working_supply(total_shares),
-        );
+        )
+    }
+
+    fn gauge_cancel_reward(
+        e: Env,
+        router: Address,
+        distributor: Address,
+        gauge: Address,
+        config_id: u32,
+    ) -> u128 {
+        router.require_auth();
+        distributor.require_auth();
+
+        if router != get_router(&e) {
+            panic_with_error!(e, AccessControlError::Unauthorized)
+        }
+
+        let rewards = get_rewards_manager(&e);
+        let total_shares = get_total_shares(&e);
+        let rewards_manager = rewards.manager();
+
+        rewards_gauge::operations::cancel_rewards_config(
+            &e,
+            gauge,
+            distributor,
+            config_id,
+            rewards_manager.get_working_supply(total_shares),
+        )
+    }
+
+    fn gauge_extend_reward(
+        e: Env,
+        router: Address,
+        distributor: Address,
+        gauge: Address,
+        config_id: u32,
+        extra_duration: u64,
+        extra_tps: u128,
+    ) -> u128 {
+        router.require_auth();
+        distributor.require_auth();
+
+        if router != get_router(&e) {
+            panic_with_error!(e, AccessControlError::Unauthorized)
+        }
+
+        let rewards = get_rewards_manager(&e);
+        let total_shares = get_total_shares(&e);
+        let rewards_manager = rewards.manager();
+
+        rewards_gauge::operations::extend_rewards_config(
+            &e,
+            gauge,
+            distributor,
+            config_id,
+            extra_duration,
+            extra_tps,
+            rewards_manager.get_working_supply(total_shares),
+        )
+    }
+
+    fn gauge_reclaim_unallocated(e: Env, distributor: Address, reward_token: Address) -> u128 {
+        distributor.require_auth();
+
+        let rewards = get_rewards_manager(&e);
+        let total_shares = get_total_shares(&e);
+        let rewards_manager = rewards.manager();
+
+        rewards_gauge::operations::reclaim_unallocated(
+            &e,
+            reward_token,
+            distributor,
+            rewards_manager.get_working_supply(total_shares),
+        )
     }
 
     fn kill_gauges_claim(e: Env, admin: Address) {
@@ -2632,10 +3180,28 @@ impl RewardsGaugeInterface for LiquidityPool {
         rewards_gauge::operations::unkill_claim(&e);
     }
 
+    fn pause_gauge(e: Env, admin: Address, reward_token: Address) {
+        admin.require_auth();
+        require_pause_or_emergency_pause_admin_or_owner(&e, &admin);
+
+        rewards_gauge::operations::pause(&e, reward_token);
+    }
+
+    fn unpause_gauge(e: Env, admin: Address, reward_token: Address) {
+        admin.require_auth();
+        require_pause_admin_or_owner(&e, &admin);
+
+        rewards_gauge::operations::unpause(&e, reward_token);
+    }
+
     fn get_gauges(e: Env) -> Map<Address, Address> {
         rewards_gauge::operations::list(&e)
     }
 
+    fn get_paused_gauges(e: Env) -> Vec<Address> {
+        rewards_gauge::operations::list_paused(&e)
+    }
+
     fn gauges_claim(e: Env, user: Address) -> Map<Address, u128> {
         user.require_auth();
 
@@ -2652,6 +3218,27 @@ impl RewardsGaugeInterface for LiquidityPool {
         )
     }
 
+    fn gauges_claim_to(
+        e: Env,
+        caller: Address,
+        user: Address,
+        recipient: Address,
+    ) -> Map<Address, u128> {
+        let rewards = get_rewards_manager(&e);
+        let total_shares = get_total_shares(&e);
+        let user_shares = get_user_balance_shares(&e, &user);
+        let rewards_manager = rewards.manager();
+        rewards_manager.require_claim_auth(&caller, &user);
+
+        rewards_gauge::operations::claim_to(
+            &e,
+            &user,
+            &recipient,
+            rewards_manager.get_working_balance(&user, user_shares),
+            rewards_manager.get_working_supply(total_shares),
+        )
+    }
+
     fn gauges_get_reward_info(e: Env, user: Address) -> Map<Address, Map<Symbol, i128>> {
         let rewards = get_rewards_manager(&e);
         let total_shares = get_total_shares(&e);
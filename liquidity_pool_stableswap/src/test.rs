@@ -17,7 +17,7 @@ use crate::testutils::{
 use crate::LiquidityPoolClient;
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use liquidity_pool_config_storage::testutils::deploy_config_storage;
-use rewards::storage::{PoolRewardsStorageTrait, UserRewardsStorageTrait};
+use rewards::storage::{BoostConfig, PoolRewardsStorageTrait, UserRewardsStorageTrait};
 use soroban_sdk::token::{
     StellarAssetClient as SorobanTokenAdminClient, TokenClient as SorobanTokenClient,
 };
@@ -7074,3 +7074,167 @@ fn test_estimate_working_balance_lower_share_value_with_boost() {
             .unwrap() as u128,
     );
 }
+
+#[test]
+fn test_boost_config() {
+    let setup = Setup::default();
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+
+    let token1_admin = get_token_admin_client(&env, &setup.token1.address);
+    let token2_admin = get_token_admin_client(&env, &setup.token2.address);
+    let boost_admin = get_token_admin_client(&env, &setup.reward_boost_token.address);
+
+    for user in [&user1, &user2] {
+        token1_admin.mint(user, &1_000_0000000);
+        token2_admin.mint(user, &1_000_0000000);
+    }
+
+    liq_pool.deposit(
+        &user1,
+        &Vec::from_array(&env, [100_0000000, 100_0000000]),
+        &0,
+    );
+    liq_pool.deposit(&user2, &Vec::from_array(&env, [20_0000000, 20_0000000]), &0);
+
+    // user2 holds half of the locked supply, so its working balance is capped by the max boost
+    boost_admin.mint(&user2, &10_000_0000000);
+    setup
+        .reward_boost_feed
+        .set_total_supply(&setup.operations_admin, &20_000_0000000);
+    let user2_shares = liq_pool.get_user_shares(&user2);
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user2, &user2_shares).0,
+        user2_shares * 5 / 2
+    );
+
+    liq_pool.commit_boost_config(&setup.admin, &15_000);
+    assert_eq!(
+        liq_pool.try_apply_boost_config(&setup.admin).unwrap_err(),
+        Ok(Error::from_contract_error(2908))
+    );
+    jump(&env, ADMIN_ACTIONS_DELAY + 1);
+    liq_pool.apply_boost_config(&setup.admin);
+    assert_eq!(
+        liq_pool.get_boost_config(),
+        BoostConfig {
+            max_boost_bps: 15_000
+        }
+    );
+    assert_eq!(
+        liq_pool.estimate_working_balance(&user2, &user2_shares).0,
+        user2_shares * 3 / 2
+    );
+}
+
+#[test]
+fn test_rewards_vesting() {
+    let setup = Setup::default();
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let token1_admin_client = SorobanTokenAdminClient::new(&env, &setup.token1.address);
+    let token2_admin_client = SorobanTokenAdminClient::new(&env, &setup.token2.address);
+    for user in [&user1, &user2] {
+        token1_admin_client.mint(user, &1_000_0000000);
+        token2_admin_client.mint(user, &1_000_0000000);
+    }
+
+    let reward_tps = 10_5000000_u128;
+    configure_rewards(
+        &env,
+        &liq_pool,
+        &setup.token_reward,
+        &setup.admin,
+        100,
+        reward_tps,
+    );
+    liq_pool.set_rewards_vesting(&setup.admin, &10, &100, &5000);
+
+    liq_pool.deposit(&user1, &Vec::from_array(&env, [500, 500]), &0);
+    liq_pool.deposit(&user2, &Vec::from_array(&env, [500, 500]), &0);
+    jump(&env, 10);
+
+    // claimed reward is locked in vesting
+    let reward1 = liq_pool.claim(&user1);
+    assert_eq!(reward1, reward_tps * 10 / 2);
+    assert_eq!(setup.token_reward.balance(&user1), 0);
+    let info = liq_pool.get_rewards_info(&user1);
+    assert_eq!(
+        info.get(Symbol::new(&env, "vesting_locked")).unwrap(),
+        reward1 as i128
+    );
+
+    jump(&env, 5);
+    assert_eq!(liq_pool.claim_vested(&user1), 0);
+    jump(&env, 45);
+    assert_eq!(liq_pool.claim_vested(&user1), reward1 / 2);
+
+    // early exit penalty goes to the other LP
+    let reward2_before = liq_pool.get_user_reward(&user2);
+    let locked = reward1 - reward1 / 2;
+    assert_eq!(
+        liq_pool.exit_vesting(&user1),
+        (locked - locked / 2, locked / 2)
+    );
+    assert_eq!(
+        setup.token_reward.balance(&user1) as u128,
+        reward1 / 2 + locked - locked / 2
+    );
+    assert_eq!(
+        liq_pool.get_user_reward(&user2),
+        reward2_before + locked / 2
+    );
+}
+
+#[test]
+fn test_claim_to_with_delegated_claimer() {
+    let setup = Setup::default();
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = Address::generate(&env);
+    let claimer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    SorobanTokenAdminClient::new(&env, &setup.token1.address).mint(&user, &1_000_0000000);
+    SorobanTokenAdminClient::new(&env, &setup.token2.address).mint(&user, &1_000_0000000);
+
+    let reward_tps = 10_5000000_u128;
+    configure_rewards(
+        &env,
+        &liq_pool,
+        &setup.token_reward,
+        &setup.admin,
+        100,
+        reward_tps,
+    );
+    liq_pool.deposit(&user, &Vec::from_array(&env, [500, 500]), &0);
+    jump(&env, 10);
+
+    liq_pool.set_reward_claimer(&user, &Some(claimer.clone()));
+    assert_eq!(
+        liq_pool.claim_to(&claimer, &user, &recipient),
+        reward_tps * 10
+    );
+    assert_eq!(env.auths()[0].0, claimer);
+    assert_eq!(
+        setup.token_reward.balance(&recipient) as u128,
+        reward_tps * 10
+    );
+    assert_eq!(setup.token_reward.balance(&user), 0);
+
+    // the owner claims on its own while the delegation is active
+    jump(&env, 10);
+    assert_eq!(liq_pool.claim_to(&user, &user, &recipient), reward_tps * 10);
+    assert_eq!(env.auths()[0].0, user);
+
+    // revoked claimer can't claim anymore
+    liq_pool.set_reward_claimer(&user, &None);
+    jump(&env, 10);
+    assert!(liq_pool.try_claim_to(&claimer, &user, &recipient).is_err());
+    assert_eq!(liq_pool.claim_to(&user, &user, &recipient), reward_tps * 10);
+    assert_eq!(env.auths()[0].0, user);
+}
@@ -548,6 +548,106 @@ fn test_set_rewards_config() {
     }
 }
 
+#[test]
+fn test_set_rewards_vesting() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.rewards_admin, true),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(
+            pool.try_set_rewards_vesting(&addr, &10, &100, &5000)
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_set_rewards_history() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.rewards_admin, true),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(pool.try_set_rewards_history(&addr, &true).is_ok(), is_ok);
+    }
+}
+
+#[test]
+fn test_reward_tokens_permissions() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.rewards_admin, true),
+        (setup.operations_admin.clone(), false),
+        (setup.pause_admin.clone(), false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        let token = Address::generate(&setup.env);
+        assert_eq!(pool.try_add_reward_token(&addr, &token).is_ok(), is_ok);
+        if is_ok {
+            assert!(pool
+                .try_set_token_rewards_config(
+                    &addr,
+                    &token,
+                    &setup.env.ledger().timestamp().saturating_add(10),
+                    &1
+                )
+                .is_ok());
+        }
+    }
+    let token = pool.get_reward_tokens().get_unchecked(1);
+    for addr in [setup.operations_admin, setup.pause_admin] {
+        assert!(pool
+            .try_set_token_rewards_config(
+                &addr,
+                &token,
+                &setup.env.ledger().timestamp().saturating_add(20),
+                &1
+            )
+            .is_err());
+    }
+}
+
+#[test]
+fn test_boost_config_permissions() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+        (setup.admin, true),
+    ] {
+        assert_eq!(pool.try_commit_boost_config(&addr, &30_000).is_ok(), is_ok);
+        assert_eq!(pool.try_revert_boost_config(&addr).is_ok(), is_ok);
+        assert_eq!(pool.try_set_boost_lock_records(&addr, &true).is_ok(), is_ok);
+    }
+}
+
 #[test]
 fn test_ramp_a() {
     let setup = Setup::default();
@@ -1,5 +1,8 @@
 use crate::errors::FeedError;
 use crate::interface::AdminInterfaceTrait;
+use crate::locks::{
+    balance_of_at, get_lock, total_supply_at, update_lock, LockedBalance, MAX_LOCK_TIME, WEEK,
+};
 use access_control::access::{AccessControl, AccessControlTrait};
 use access_control::emergency::{get_emergency_mode, set_emergency_mode};
 use access_control::errors::AccessControlError;
@@ -70,6 +73,52 @@ impl LockerFeed {
             .get(&DataKey::TotalSupply)
             .unwrap_or(0)
     }
+
+    // Mirrors the user's lock from the locker, replacing the previous record.
+    // The unlock time is rounded down to whole weeks; amount = 0 removes the lock.
+    //
+    // # Arguments
+    //
+    // * `operations_admin` - The address of the operations admin.
+    // * `user` - The owner of the lock.
+    // * `amount` - The locked amount.
+    // * `unlock_time` - The timestamp when the lock expires, at most MAX_LOCK_TIME ahead.
+    pub fn set_lock(
+        e: Env,
+        operations_admin: Address,
+        user: Address,
+        amount: u128,
+        unlock_time: u64,
+    ) {
+        operations_admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&operations_admin, &Role::OperationsAdmin);
+
+        let end = match amount {
+            0 => 0,
+            _ => unlock_time / WEEK * WEEK,
+        };
+        if end > e.ledger().timestamp() + MAX_LOCK_TIME {
+            panic_with_error!(&e, FeedError::InvalidLockTime);
+        }
+        update_lock(&e, &user, &LockedBalance { amount, end });
+    }
+
+    // Returns the lock record of the user.
+    pub fn get_lock(e: Env, user: Address) -> LockedBalance {
+        get_lock(&e, &user)
+    }
+
+    // Returns the voting power of the user at the timestamp.
+    // It decays linearly from the locked amount for a MAX_LOCK_TIME lock to zero at unlock time.
+    pub fn balance_of_at(e: Env, user: Address, timestamp: u64) -> u128 {
+        balance_of_at(&e, &user, timestamp)
+    }
+
+    // Returns the sum of voting power of all locks at the timestamp.
+    pub fn total_supply_at(e: Env, timestamp: u64) -> u128 {
+        total_supply_at(&e, timestamp)
+    }
 }
 
 #[contractimpl]
@@ -5,4 +5,5 @@ use soroban_sdk::contracterror;
 #[repr(u32)]
 pub enum FeedError {
     AlreadyInitialized = 201,
+    InvalidLockTime = 202,
 }
@@ -3,6 +3,8 @@
 mod contract;
 mod errors;
 mod interface;
+mod locks;
+mod test;
 mod test_permissions;
 mod testutils;
 
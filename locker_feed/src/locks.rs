@@ -0,0 +1,260 @@
+use soroban_sdk::{contracttype, Address, Env, Map};
+use utils::bump::{bump_instance, bump_persistent};
+
+// Vote-escrow lock records mirrored from the locker.
+// Voting power of a lock decays linearly from `amount` for a MAX_LOCK_TIME lock to zero at `end`.
+// Biases and slopes are kept multiplied by MAX_LOCK_TIME, so the decay is exact and
+// the power is `bias / MAX_LOCK_TIME`. Scheduled slope changes are aligned to weeks,
+// as total supply is rolled forward week by week. They are stored in yearly chunks to keep
+// the number of ledger entries read by a walk small.
+
+pub const WEEK: u64 = 7 * 86400;
+pub const MAX_LOCK_TIME: u64 = 208 * WEEK; // ~4 years
+
+// upper bound for the number of weeks walked at once
+const MAX_WEEKS_WALK: u32 = 255;
+
+const SLOPE_CHANGES_CHUNK: u64 = 52 * WEEK;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LockedBalance {
+    pub amount: u128,
+    pub end: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Point {
+    pub bias: i128,
+    pub slope: i128,
+    pub ts: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Epoch,
+    Point(u32),
+    SlopeChanges(u64),
+    Lock(Address),
+    UserEpoch(Address),
+    UserPoint(Address, u32),
+}
+
+fn get_epoch(e: &Env) -> u32 {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::Epoch).unwrap_or(0)
+}
+
+fn set_epoch(e: &Env, epoch: u32) {
+    bump_instance(e);
+    e.storage().instance().set(&DataKey::Epoch, &epoch);
+}
+
+fn get_point(e: &Env, epoch: u32) -> Point {
+    let key = DataKey::Point(epoch);
+    bump_persistent(e, &key);
+    e.storage().persistent().get(&key).unwrap()
+}
+
+fn set_point(e: &Env, epoch: u32, point: &Point) {
+    let key = DataKey::Point(epoch);
+    e.storage().persistent().set(&key, point);
+    bump_persistent(e, &key);
+}
+
+fn get_slope_changes(e: &Env, chunk: u64) -> Map<u64, i128> {
+    let key = DataKey::SlopeChanges(chunk);
+    match e.storage().persistent().get(&key) {
+        Some(v) => {
+            bump_persistent(e, &key);
+            v
+        }
+        None => Map::new(e),
+    }
+}
+
+fn add_slope_change(e: &Env, ts: u64, diff: i128) {
+    let chunk = ts / SLOPE_CHANGES_CHUNK;
+    let mut changes = get_slope_changes(e, chunk);
+    changes.set(ts, changes.get(ts).unwrap_or(0) + diff);
+    let key = DataKey::SlopeChanges(chunk);
+    e.storage().persistent().set(&key, &changes);
+    bump_persistent(e, &key);
+}
+
+pub fn get_lock(e: &Env, user: &Address) -> LockedBalance {
+    let key = DataKey::Lock(user.clone());
+    match e.storage().persistent().get(&key) {
+        Some(v) => {
+            bump_persistent(e, &key);
+            v
+        }
+        None => LockedBalance { amount: 0, end: 0 },
+    }
+}
+
+fn set_lock(e: &Env, user: &Address, lock: &LockedBalance) {
+    let key = DataKey::Lock(user.clone());
+    e.storage().persistent().set(&key, lock);
+    bump_persistent(e, &key);
+}
+
+fn get_user_epoch(e: &Env, user: &Address) -> u32 {
+    let key = DataKey::UserEpoch(user.clone());
+    match e.storage().persistent().get(&key) {
+        Some(v) => {
+            bump_persistent(e, &key);
+            v
+        }
+        None => 0,
+    }
+}
+
+fn set_user_epoch(e: &Env, user: &Address, epoch: u32) {
+    let key = DataKey::UserEpoch(user.clone());
+    e.storage().persistent().set(&key, &epoch);
+    bump_persistent(e, &key);
+}
+
+fn get_user_point(e: &Env, user: &Address, epoch: u32) -> Point {
+    let key = DataKey::UserPoint(user.clone(), epoch);
+    bump_persistent(e, &key);
+    e.storage().persistent().get(&key).unwrap()
+}
+
+fn set_user_point(e: &Env, user: &Address, epoch: u32, point: &Point) {
+    let key = DataKey::UserPoint(user.clone(), epoch);
+    e.storage().persistent().set(&key, point);
+    bump_persistent(e, &key);
+}
+
+fn lock_point(lock: &LockedBalance, ts: u64) -> Point {
+    if lock.end <= ts {
+        return Point {
+            bias: 0,
+            slope: 0,
+            ts,
+        };
+    }
+    let slope = lock.amount as i128;
+    Point {
+        bias: slope * (lock.end - ts) as i128,
+        slope,
+        ts,
+    }
+}
+
+// Moves the point forward to `ts`, applying the slope changes scheduled on the way.
+fn roll_point(e: &Env, mut point: Point, ts: u64) -> Point {
+    let mut chunk = point.ts / SLOPE_CHANGES_CHUNK;
+    let mut changes = get_slope_changes(e, chunk);
+    let mut t_i = point.ts / WEEK * WEEK;
+    for _ in 0..MAX_WEEKS_WALK {
+        t_i += WEEK;
+        let mut d_slope = 0;
+        if t_i > ts {
+            t_i = ts;
+        } else {
+            if t_i / SLOPE_CHANGES_CHUNK != chunk {
+                chunk = t_i / SLOPE_CHANGES_CHUNK;
+                changes = get_slope_changes(e, chunk);
+            }
+            d_slope = changes.get(t_i).unwrap_or(0);
+        }
+        point.bias -= point.slope * (t_i - point.ts) as i128;
+        point.slope -= d_slope;
+        point.ts = t_i;
+        if point.bias < 0 {
+            point.bias = 0;
+        }
+        if point.slope < 0 {
+            point.slope = 0;
+        }
+        if t_i == ts {
+            break;
+        }
+    }
+    point
+}
+
+// Latest epoch in 1..=max_epoch whose point was recorded at or before `ts`, 0 if none.
+fn find_epoch<F>(max_epoch: u32, ts: u64, point_ts: F) -> u32
+where
+    F: Fn(u32) -> u64,
+{
+    let mut min = 0;
+    let mut max = max_epoch;
+    while min < max {
+        let mid = (min + max).div_ceil(2);
+        if point_ts(mid) <= ts {
+            min = mid;
+        } else {
+            max = mid - 1;
+        }
+    }
+    min
+}
+
+// Replaces the user's lock record and checkpoints both the user and the total supply.
+pub fn update_lock(e: &Env, user: &Address, new_lock: &LockedBalance) {
+    let now = e.ledger().timestamp();
+    let old_lock = get_lock(e, user);
+    let old_point = lock_point(&old_lock, now);
+    let new_point = lock_point(new_lock, now);
+
+    // total supply
+    let epoch = get_epoch(e);
+    let last_point = match epoch {
+        0 => Point {
+            bias: 0,
+            slope: 0,
+            ts: now,
+        },
+        _ => get_point(e, epoch),
+    };
+    let mut point = roll_point(e, last_point, now);
+    point.bias += new_point.bias - old_point.bias;
+    point.slope += new_point.slope - old_point.slope;
+    set_point(e, epoch + 1, &point);
+    set_epoch(e, epoch + 1);
+
+    // scheduled slope changes
+    if old_lock.end > now {
+        add_slope_change(e, old_lock.end, -old_point.slope);
+    }
+    if new_lock.end > now {
+        add_slope_change(e, new_lock.end, new_point.slope);
+    }
+
+    // user history
+    let user_epoch = get_user_epoch(e, user) + 1;
+    set_user_point(e, user, user_epoch, &new_point);
+    set_user_epoch(e, user, user_epoch);
+    set_lock(e, user, new_lock);
+}
+
+pub fn balance_of_at(e: &Env, user: &Address, ts: u64) -> u128 {
+    let epoch = find_epoch(get_user_epoch(e, user), ts, |i| {
+        get_user_point(e, user, i).ts
+    });
+    if epoch == 0 {
+        return 0;
+    }
+    let point = get_user_point(e, user, epoch);
+    let bias = point.bias - point.slope * (ts - point.ts) as i128;
+    if bias <= 0 {
+        return 0;
+    }
+    bias as u128 / MAX_LOCK_TIME as u128
+}
+
+pub fn total_supply_at(e: &Env, ts: u64) -> u128 {
+    let epoch = find_epoch(get_epoch(e), ts, |i| get_point(e, i).ts);
+    if epoch == 0 {
+        return 0;
+    }
+    let point = roll_point(e, get_point(e, epoch), ts);
+    point.bias as u128 / MAX_LOCK_TIME as u128
+}
@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+use crate::locks::{LockedBalance, MAX_LOCK_TIME, WEEK};
+use crate::testutils::{jump, Setup};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Error};
+
+#[test]
+fn test_lock_decay() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let feed = setup.contract;
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    let now = 10 * WEEK;
+    jump(&e, now);
+    feed.set_lock(
+        &setup.operations_admin,
+        &user1,
+        &1000_0000000,
+        &(now + MAX_LOCK_TIME),
+    );
+    feed.set_lock(
+        &setup.operations_admin,
+        &user2,
+        &1000_0000000,
+        &(now + 52 * WEEK),
+    );
+
+    // the full power is given for the longest lock only
+    assert_eq!(feed.balance_of_at(&user1, &now), 1000_0000000);
+    assert_eq!(feed.balance_of_at(&user2, &now), 250_0000000);
+    assert_eq!(feed.total_supply_at(&now), 1250_0000000);
+
+    let halfway = now + 26 * WEEK;
+    assert_eq!(feed.balance_of_at(&user1, &halfway), 875_0000000);
+    assert_eq!(feed.balance_of_at(&user2, &halfway), 125_0000000);
+    assert_eq!(feed.total_supply_at(&halfway), 1000_0000000);
+
+    // expired lock has no power left
+    let expiry = now + 52 * WEEK;
+    assert_eq!(feed.balance_of_at(&user2, &expiry), 0);
+    assert_eq!(feed.total_supply_at(&expiry), 750_0000000);
+    assert_eq!(feed.total_supply_at(&(now + 100 * WEEK)), 519_2307692);
+    assert_eq!(feed.total_supply_at(&(now + MAX_LOCK_TIME)), 0);
+
+    // nothing before the first lock
+    assert_eq!(feed.balance_of_at(&user1, &(now - 1)), 0);
+    assert_eq!(feed.total_supply_at(&(now - 1)), 0);
+}
+
+#[test]
+fn test_lock_update_keeps_history() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let feed = setup.contract;
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    let start = 10 * WEEK;
+    jump(&e, start);
+    feed.set_lock(
+        &setup.operations_admin,
+        &user1,
+        &1000_0000000,
+        &(start + 104 * WEEK),
+    );
+    feed.set_lock(
+        &setup.operations_admin,
+        &user2,
+        &1000_0000000,
+        &(start + 52 * WEEK),
+    );
+
+    // user1 relocks for the max time, user2 withdraws
+    jump(&e, 26 * WEEK);
+    let now = start + 26 * WEEK;
+    feed.set_lock(
+        &setup.operations_admin,
+        &user1,
+        &2000_0000000,
+        &(now + MAX_LOCK_TIME),
+    );
+    feed.set_lock(&setup.operations_admin, &user2, &0, &0);
+    assert_eq!(feed.get_lock(&user2), LockedBalance { amount: 0, end: 0 });
+
+    assert_eq!(feed.balance_of_at(&user1, &start), 500_0000000);
+    assert_eq!(feed.balance_of_at(&user2, &start), 250_0000000);
+    assert_eq!(feed.total_supply_at(&start), 750_0000000);
+
+    assert_eq!(feed.balance_of_at(&user1, &now), 2000_0000000);
+    assert_eq!(feed.balance_of_at(&user2, &now), 0);
+    assert_eq!(feed.total_supply_at(&now), 2000_0000000);
+
+    // the replaced lock no longer decays the total supply at its old unlock time
+    let later = start + 104 * WEEK;
+    assert_eq!(feed.balance_of_at(&user1, &later), 1250_0000000);
+    assert_eq!(feed.total_supply_at(&later), 1250_0000000);
+}
+
+#[test]
+fn test_lock_unlock_time() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let feed = setup.contract;
+    let user = Address::generate(&e);
+
+    jump(&e, 10 * WEEK + 1000);
+    let now = 10 * WEEK + 1000;
+
+    // unlock time is rounded down to whole weeks
+    feed.set_lock(
+        &setup.operations_admin,
+        &user,
+        &1000_0000000,
+        &(now + 2 * WEEK),
+    );
+    assert_eq!(
+        feed.get_lock(&user),
+        LockedBalance {
+            amount: 1000_0000000,
+            end: 12 * WEEK,
+        }
+    );
+    assert_eq!(
+        feed.balance_of_at(&user, &now),
+        1000_0000000 * (2 * WEEK - 1000) as u128 / MAX_LOCK_TIME as u128
+    );
+    assert_eq!(feed.total_supply_at(&now), feed.balance_of_at(&user, &now));
+
+    assert_eq!(
+        feed.try_set_lock(
+            &setup.operations_admin,
+            &user,
+            &1000_0000000,
+            &(now + MAX_LOCK_TIME + WEEK),
+        )
+        .unwrap_err(),
+        Ok(Error::from_contract_error(202))
+    );
+}
@@ -365,3 +365,32 @@ fn test_set_emergency_mode_emergency_admin() {
         .try_set_emergency_mode(&setup.emergency_admin, &false)
         .is_ok());
 }
+
+// lock records
+#[test]
+fn test_set_lock_third_party_user() {
+    let setup = Setup::default();
+    let contract = setup.contract;
+    let user = Address::generate(&setup.env);
+    assert!(contract.try_set_lock(&user, &user, &1000, &0).is_err());
+}
+
+#[test]
+fn test_set_lock_admin() {
+    let setup = Setup::default();
+    let contract = setup.contract;
+    let user = Address::generate(&setup.env);
+    assert!(contract
+        .try_set_lock(&setup.admin, &user, &1000, &0)
+        .is_err());
+}
+
+#[test]
+fn test_set_lock_operations_admin() {
+    let setup = Setup::default();
+    let contract = setup.contract;
+    let user = Address::generate(&setup.env);
+    assert!(contract
+        .try_set_lock(&setup.operations_admin, &user, &1000, &0)
+        .is_ok());
+}
@@ -2,7 +2,8 @@
 //
 // Positions that are in-range (overlapping current price) receive full reward weight.
 // Out-of-range positions receive a quadratically decaying multiplier that reaches
-// zero at `max_distance_for_fee(fee)` ticks from the nearest range boundary.
+// zero at `max_distance` ticks from the nearest range boundary. Each fee tier in the
+// router's registry carries its own max distance; built-in tiers use `max_distance_for_fee`.
 //
 // ## Why distance weighting?
 //
@@ -49,10 +50,19 @@ pub const MIN_MULTIPLIER_BPS: u32 = 0;
 // fee=10 → 500 ticks (~5.1% price), fee=30 → 1500 (~16.2%), fee=100 → 5000 (~64.9%).
 pub const FEE_TO_DISTANCE_MULTIPLIER: u32 = 50;
 
+// Upper bound for a fee tier max distance: the whole tick range, beyond it decay never ends.
+pub const MAX_DISTANCE_LIMIT: u32 = 1_774_544;
+
+// Default max distance for a fee tier, used when the tier doesn't set its own.
 pub fn max_distance_for_fee(fee: u32) -> u32 {
     fee * FEE_TO_DISTANCE_MULTIPLIER
 }
 
+// Max distance accepted for a fee tier. Zero would cut off every out-of-range position.
+pub fn is_valid_max_distance(max_distance: u32) -> bool {
+    max_distance > 0 && max_distance <= MAX_DISTANCE_LIMIT
+}
+
 // Tick distance from current price to the nearest edge of [tick_lower, tick_upper).
 // Returns 0 if current price is inside the range.
 // Uses half-open interval matching active-liquidity semantics: tick_current == tick_upper
@@ -92,16 +102,16 @@ pub fn distance_multiplier_bps(distance_ticks: u32, max_distance_ticks: u32) ->
     MIN_MULTIPLIER_BPS + scaled as u32
 }
 
-// Reward multiplier for a position given current tick and pool fee.
-// Combines tick_distance_from_range + distance_multiplier_bps + max_distance_for_fee.
+// Reward multiplier for a position given current tick and the pool's fee tier max distance.
+// Combines tick_distance_from_range + distance_multiplier_bps.
 pub fn position_multiplier_bps(
     tick_current: i32,
     tick_lower: i32,
     tick_upper: i32,
-    fee: u32,
+    max_distance: u32,
 ) -> u32 {
     let distance = tick_distance_from_range(tick_current, tick_lower, tick_upper);
-    distance_multiplier_bps(distance, max_distance_for_fee(fee))
+    distance_multiplier_bps(distance, max_distance)
 }
 
 // Scale an amount by a multiplier in basis points. Capped at BPS_DENOMINATOR (100%).
@@ -116,7 +126,10 @@ mod tests {
 
     #[test]
     fn test_in_range_has_full_weight() {
-        assert_eq!(position_multiplier_bps(100, 90, 110, 30), BPS_DENOMINATOR);
+        assert_eq!(
+            position_multiplier_bps(100, 90, 110, max_distance_for_fee(30)),
+            BPS_DENOMINATOR
+        );
     }
 
     #[test]
@@ -135,9 +148,12 @@ mod tests {
     #[test]
     fn test_stablecoin_penalizes_far_positions() {
         // Stablecoin pool (fee=10): position 903 ticks away exceeds max_distance=500 → 0
-        assert_eq!(position_multiplier_bps(0, 903, 1003, 10), 0);
+        assert_eq!(
+            position_multiplier_bps(0, 903, 1003, max_distance_for_fee(10)),
+            0
+        );
         // Same position in volatile pool (fee=100): max_distance=5000 → still rewarded
-        assert!(position_multiplier_bps(0, 903, 1003, 100) > 0);
+        assert!(position_multiplier_bps(0, 903, 1003, max_distance_for_fee(100)) > 0);
     }
 
     #[test]
@@ -163,7 +179,7 @@ mod tests {
     #[test]
     fn test_upper_boundary_not_full_weight() {
         // Position at tick_current == tick_upper should NOT get full rewards
-        let mult = position_multiplier_bps(110, 90, 110, 30);
+        let mult = position_multiplier_bps(110, 90, 110, max_distance_for_fee(30));
         assert!(
             mult < BPS_DENOMINATOR,
             "boundary position should not get full weight"
@@ -1 +1,19 @@
 pub(crate) const REWARD_PRECISION: u128 = 1_000_000_000_000_000_0000000;
+
+// Curve-style boost: up to 2.5x
+pub(crate) const DEFAULT_MAX_BOOST_BPS: u32 = 25_000;
+
+// upper limit of the configurable max boost: 3x
+pub(crate) const MAX_BOOST_BPS_LIMIT: u32 = 30_000;
+
+// limit of delegations per owner and per delegatee, each one costs a boost balance lookup
+pub(crate) const MAX_BOOST_DELEGATIONS: u32 = 5;
+
+// limit of tranches in a vesting schedule, every one is rebased on each claim
+pub(crate) const MAX_VESTING_TRANCHES: u32 = 16;
+
+// max number of records returned by a reward history page
+pub(crate) const MAX_HISTORY_PAGE_SIZE: u32 = 50;
+
+// limit of reward tokens besides the primary one, each one is accounted on every checkpoint
+pub(crate) const MAX_EXTRA_REWARD_TOKENS: u32 = 3;
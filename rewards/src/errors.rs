@@ -6,4 +6,12 @@ use soroban_sdk::contracterror;
 pub enum RewardsError {
     PastTimeNotAllowed = 701,
     SameRewardsConfig = 702,
+    InvalidVestingConfig = 703,
+    InvalidBoostConfig = 704,
+    InvalidBoostDelegation = 705,
+    TooManyBoostDelegations = 706,
+    RewardTokenAlreadyAdded = 707,
+    TooManyRewardTokens = 708,
+    RewardTokenNotFound = 709,
+    VestingRecipientNotAllowed = 711,
 }
@@ -40,6 +40,42 @@ impl Events {
         );
     }
 
+    pub fn claim_to(&self, user: Address, recipient: Address, reward_token: Address, amount: u128) {
+        // topics
+        // [
+        //   "claim_reward_to": Symbol, // event identifier
+        //   reward_token: Address,     // Address of token claimed
+        //   user: Address,             // address of account whose rewards are claimed
+        // ]
+        // body
+        // [
+        //   recipient: Address,        // address the reward tokens are sent to
+        //   amount: i128,              // amount of reward tokens claimed
+        // ]
+
+        let e = self.env();
+        e.events().publish(
+            (Symbol::new(e, "claim_reward_to"), reward_token, user),
+            (recipient, amount as i128),
+        );
+    }
+
+    pub fn set_reward_claimer(&self, user: Address, claimer: Option<Address>) {
+        // topics
+        // [
+        //   "set_reward_claimer": Symbol,      // event identifier
+        //   user: Address,                     // address of account delegating its claims
+        // ]
+        // body
+        // [
+        //   claimer: Option<Address>,          // delegated claimer, None if revoked
+        // ]
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_reward_claimer"), user),
+            (claimer,),
+        )
+    }
+
     pub fn set_rewards_state(&self, user: Address, state: bool) {
         // topics
         // [
@@ -55,4 +91,178 @@ impl Events {
             (state,),
         )
     }
+
+    pub fn set_boost_delegation(
+        &self,
+        owner: Address,
+        delegatee: Address,
+        amount_bps: u32,
+        expires_at: u64,
+    ) {
+        // topics
+        // [
+        //   "set_boost_delegation": Symbol,    // event identifier
+        //   owner: Address,                    // account lending its boost
+        //   delegatee: Address,                // account receiving the boost
+        // ]
+        // body
+        // [
+        //   amount_bps: u32,                   // delegated part of the boost, 0 if revoked
+        //   expires_at: u64,                   // timestamp when the delegation ends
+        // ]
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "set_boost_delegation"),
+                owner,
+                delegatee,
+            ),
+            (amount_bps, expires_at),
+        )
+    }
+
+    pub fn commit_boost_config(&self, max_boost_bps: u32) {
+        // topics
+        // [
+        //   "commit_boost_config": Symbol,     // event identifier
+        // ]
+        // body
+        // [
+        //   max_boost_bps: u32,                // maximum working balance multiplier, 10000 = 1x
+        // ]
+        self.env().events().publish(
+            (Symbol::new(self.env(), "commit_boost_config"),),
+            (max_boost_bps,),
+        )
+    }
+
+    pub fn apply_boost_config(&self, max_boost_bps: u32) {
+        // topics
+        // [
+        //   "apply_boost_config": Symbol,      // event identifier
+        // ]
+        // body
+        // [
+        //   max_boost_bps: u32,                // maximum working balance multiplier, 10000 = 1x
+        // ]
+        self.env().events().publish(
+            (Symbol::new(self.env(), "apply_boost_config"),),
+            (max_boost_bps,),
+        )
+    }
+
+    pub fn revert_boost_config(&self) {
+        // topics
+        // [
+        //   "revert_boost_config": Symbol,     // event identifier
+        // ]
+        // body
+        // [ ]
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "revert_boost_config"),), ())
+    }
+
+    pub fn set_boost_lock_records(&self, enabled: bool) {
+        // topics
+        // [
+        //   "set_boost_lock_records": Symbol,  // event identifier
+        // ]
+        // body
+        // [
+        //   enabled: bool,                     // whether boost balances come from feed lock records
+        // ]
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_boost_lock_records"),),
+            (enabled,),
+        )
+    }
+
+    pub fn add_reward_token(&self, token: Address) {
+        // topics
+        // [
+        //   "add_reward_token": Symbol,        // event identifier
+        //   token: Address,                    // extra reward token
+        // ]
+        // body
+        // []
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "add_reward_token"), token), ())
+    }
+
+    pub fn set_token_rewards_config(&self, token: Address, expired_at: u64, tps: u128) {
+        // topics
+        // [
+        //   "set_token_rewards_config": Symbol, // event identifier
+        //   token: Address,                     // reward token configured
+        // ]
+        // body
+        // [
+        //   expired_at: u64,                    // end of the reward period
+        //   tps: u128,                          // reward tokens per second
+        // ]
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_token_rewards_config"), token),
+            (expired_at, tps),
+        )
+    }
+
+    pub fn set_rewards_history(&self, enabled: bool) {
+        // topics
+        // [
+        //   "set_rewards_history": Symbol,     // event identifier
+        // ]
+        // body
+        // [
+        //   enabled: bool,                     // whether epoch snapshots and claims are recorded
+        // ]
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_rewards_history"),),
+            (enabled,),
+        )
+    }
+
+    pub fn set_rewards_vesting(&self, cliff: u64, duration: u64, early_exit_penalty_bps: u32) {
+        // topics
+        // [
+        //   "set_rewards_vesting": Symbol,     // event identifier
+        // ]
+        // body
+        // [
+        //   cliff: u64,                        // seconds before anything is releasable
+        //   duration: u64,                     // linear unlock duration in seconds, 0 if disabled
+        //   early_exit_penalty_bps: u32,       // share of the locked amount kept on early exit
+        // ]
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_rewards_vesting"),),
+            (cliff, duration, early_exit_penalty_bps),
+        )
+    }
+
+    pub fn claim_vested(&self, user: Address, reward_token: Address, amount: u128) {
+        let e = self.env();
+        e.events().publish(
+            (Symbol::new(e, "claim_vested"), reward_token, user),
+            (amount as i128,),
+        );
+    }
+
+    pub fn exit_vesting(&self, user: Address, reward_token: Address, amount: u128, penalty: u128) {
+        // topics
+        // [
+        //   "exit_vesting": Symbol,            // event identifier
+        //   reward_token: Address,             // Address of token claimed
+        //   user: Address,                     // address of the vesting schedule owner
+        // ]
+        // body
+        // [
+        //   amount: i128,                      // amount of reward tokens transferred
+        //   penalty: i128,                     // amount distributed to the other LPs
+        // ]
+        let e = self.env();
+        e.events().publish(
+            (Symbol::new(e, "exit_vesting"), reward_token, user),
+            (amount as i128, penalty as i128),
+        );
+    }
 }
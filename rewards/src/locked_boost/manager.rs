@@ -1,9 +1,15 @@
+use crate::concentrated_weight::BPS_DENOMINATOR;
+use crate::constants::{DEFAULT_MAX_BOOST_BPS, MAX_BOOST_DELEGATIONS};
+use crate::errors::RewardsError;
 use crate::locked_boost::boost_feed::RewardBoostFeedClient;
 use crate::manager::ManagerPlugin;
-use crate::storage::{BoostFeedStorageTrait, BoostTokenStorageTrait, Storage};
+use crate::storage::{
+    BoostConfig, BoostConfigStorageTrait, BoostDelegation, BoostDelegationStorageTrait,
+    BoostFeedStorageTrait, BoostTokenStorageTrait, Storage,
+};
 use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::token::TokenClient as SorobanTokenClient;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{panic_with_error, Address, Env, IntoVal, Map, Symbol, Val, Vec};
 
 pub(crate) struct BoostManagerPlugin {
     env: Env,
@@ -18,7 +24,33 @@ impl BoostManagerPlugin {
     // Basic getters for boost balances
     // ------------------------------------
 
-    pub fn get_user_boost_balance(&self, storage: &Storage, user: &Address) -> u128 {
+    // Feeds with vote-escrow lock records expose time-decaying balances. The admin enables them
+    // once the feed is upgraded and the locks are backfilled, until then the boost token balance
+    // and the feed total supply are used.
+    fn get_feed_value_at(&self, storage: &Storage, func: &str, args: Vec<Val>) -> Option<u128> {
+        if !storage.get_boost_lock_records_enabled() || !storage.has_reward_boost_feed() {
+            return None;
+        }
+        Some(self.env.invoke_contract(
+            &storage.get_reward_boost_feed(),
+            &Symbol::new(&self.env, func),
+            args,
+        ))
+    }
+
+    // Boost balance of the account itself, before delegations.
+    pub fn get_locked_balance(&self, storage: &Storage, user: &Address) -> u128 {
+        let args = Vec::from_array(
+            &self.env,
+            [
+                user.to_val(),
+                self.env.ledger().timestamp().into_val(&self.env),
+            ],
+        );
+        if let Some(balance) = self.get_feed_value_at(storage, "balance_of_at", args) {
+            return balance;
+        }
+
         if storage.has_reward_boost_token() {
             match SorobanTokenClient::new(&self.env, &storage.get_reward_boost_token())
                 .try_balance(user)
@@ -32,13 +64,173 @@ impl BoostManagerPlugin {
         }
     }
 
+    // Own boost balance minus the delegated part, plus active delegations received.
+    pub fn get_user_boost_balance(&self, storage: &Storage, user: &Address) -> u128 {
+        let denominator = BPS_DENOMINATOR as u128;
+        let delegated_bps = self.get_delegated_bps(storage, user, None);
+        let mut balance = match delegated_bps {
+            0 => self.get_locked_balance(storage, user),
+            _ => self.get_locked_balance(storage, user).fixed_mul_floor(
+                &self.env,
+                &((BPS_DENOMINATOR - delegated_bps) as u128),
+                &denominator,
+            ),
+        };
+        for owner in storage.get_boost_delegators(user).iter() {
+            if let Some(delegation) = self.get_active_delegation(storage, &owner, user) {
+                balance += self.get_locked_balance(storage, &owner).fixed_mul_floor(
+                    &self.env,
+                    &(delegation.amount_bps as u128),
+                    &denominator,
+                );
+            }
+        }
+        balance
+    }
+
     pub fn get_total_locked(&self, storage: &Storage) -> u128 {
+        let args = Vec::from_array(
+            &self.env,
+            [self.env.ledger().timestamp().into_val(&self.env)],
+        );
+        if let Some(total) = self.get_feed_value_at(storage, "total_supply_at", args) {
+            return total;
+        }
+
         if storage.has_reward_boost_feed() {
             RewardBoostFeedClient::new(&self.env, &storage.get_reward_boost_feed()).total_supply()
         } else {
             0
         }
     }
+
+    // ------------------------------------
+    // Boost delegation
+    // ------------------------------------
+
+    fn is_active(&self, delegation: &BoostDelegation) -> bool {
+        delegation.expires_at > self.env.ledger().timestamp()
+    }
+
+    fn get_active_delegation(
+        &self,
+        storage: &Storage,
+        owner: &Address,
+        delegatee: &Address,
+    ) -> Option<BoostDelegation> {
+        storage
+            .get_boost_delegations(owner)
+            .get(delegatee.clone())
+            .filter(|delegation| self.is_active(delegation))
+    }
+
+    // Share of the owner's boost delegated away, ignoring the delegation to `except` if given.
+    fn get_delegated_bps(
+        &self,
+        storage: &Storage,
+        owner: &Address,
+        except: Option<&Address>,
+    ) -> u32 {
+        let mut result = 0;
+        for (delegatee, delegation) in storage.get_boost_delegations(owner).iter() {
+            if Some(&delegatee) != except && self.is_active(&delegation) {
+                result += delegation.amount_bps;
+            }
+        }
+        result
+    }
+
+    pub fn get_boost_delegations(
+        &self,
+        storage: &Storage,
+        owner: &Address,
+    ) -> Map<Address, BoostDelegation> {
+        let mut result = Map::new(&self.env);
+        for (delegatee, delegation) in storage.get_boost_delegations(owner).iter() {
+            if self.is_active(&delegation) {
+                result.set(delegatee, delegation);
+            }
+        }
+        result
+    }
+
+    pub fn get_boost_delegators(&self, storage: &Storage, delegatee: &Address) -> Vec<Address> {
+        let mut result = Vec::new(&self.env);
+        for owner in storage.get_boost_delegators(delegatee).iter() {
+            if self
+                .get_active_delegation(storage, &owner, delegatee)
+                .is_some()
+            {
+                result.push_back(owner);
+            }
+        }
+        result
+    }
+
+    // Delegatees and delegators of the user, including delegations that expired but are still
+    // stored, since working balances checkpointed before the expiry still carry them.
+    pub fn get_boost_counterparties(&self, storage: &Storage, user: &Address) -> Vec<Address> {
+        let mut result = storage.get_boost_delegations(user).keys();
+        for owner in storage.get_boost_delegators(user).iter() {
+            if !result.contains(&owner) {
+                result.push_back(owner);
+            }
+        }
+        result
+    }
+
+    // Replaces the owner's delegation to the delegatee; amount_bps = 0 removes it.
+    // Expired delegations of both accounts are dropped on the way.
+    pub fn set_boost_delegation(
+        &self,
+        storage: &Storage,
+        owner: &Address,
+        delegatee: &Address,
+        amount_bps: u32,
+        expires_at: u64,
+    ) {
+        if owner == delegatee {
+            panic_with_error!(&self.env, RewardsError::InvalidBoostDelegation);
+        }
+
+        let mut delegations = self.get_boost_delegations(storage, owner);
+        let mut delegators = self.get_boost_delegators(storage, delegatee);
+        if amount_bps == 0 {
+            delegations.remove(delegatee.clone());
+            if let Some(idx) = delegators.first_index_of(owner) {
+                delegators.remove(idx);
+            }
+        } else {
+            if expires_at <= self.env.ledger().timestamp()
+                || self.get_delegated_bps(storage, owner, Some(delegatee)) + amount_bps
+                    > BPS_DENOMINATOR
+            {
+                panic_with_error!(&self.env, RewardsError::InvalidBoostDelegation);
+            }
+            delegations.set(
+                delegatee.clone(),
+                BoostDelegation {
+                    amount_bps,
+                    expires_at,
+                },
+            );
+            if !delegators.contains(owner) {
+                delegators.push_back(owner.clone());
+            }
+            if delegations.len() > MAX_BOOST_DELEGATIONS || delegators.len() > MAX_BOOST_DELEGATIONS
+            {
+                panic_with_error!(&self.env, RewardsError::TooManyBoostDelegations);
+            }
+        }
+        storage.set_boost_delegations(owner, &delegations);
+        storage.set_boost_delegators(delegatee, &delegators);
+    }
+
+    pub fn get_boost_config(&self, storage: &Storage) -> BoostConfig {
+        storage.get_boost_config().unwrap_or(BoostConfig {
+            max_boost_bps: DEFAULT_MAX_BOOST_BPS,
+        })
+    }
 }
 
 impl ManagerPlugin for BoostManagerPlugin {
@@ -49,16 +241,28 @@ impl ManagerPlugin for BoostManagerPlugin {
         share_balance: u128,
         total_share: u128,
     ) -> u128 {
-        // b_u = 2.5 * min(0.4 * b_u + 0.6 * S * w_i / W, b_u)
+        // b_u = max_boost * min((1 - lock_weight) * b_u + lock_weight * S * w_i / W, b_u)
+        // with lock_weight = 1 - 1 / max_boost, so shares without a lock are kept as is:
+        // b_u = min(b_u + (max_boost - 1) * S * w_i / W, max_boost * b_u)
+        let config = self.get_boost_config(storage);
         let lock_balance = self.get_user_boost_balance(storage, &user);
         let total_locked = self.get_total_locked(storage);
 
         let mut adjusted_balance = share_balance;
         if total_locked > 0 {
-            adjusted_balance +=
-                3 * lock_balance.fixed_mul_floor(&self.env, &total_share, &total_locked) / 2;
+            adjusted_balance += lock_balance
+                .fixed_mul_floor(&self.env, &total_share, &total_locked)
+                .fixed_mul_floor(
+                    &self.env,
+                    &((config.max_boost_bps - BPS_DENOMINATOR) as u128),
+                    &(BPS_DENOMINATOR as u128),
+                );
         }
-        let max_effective_balance = share_balance * 5 / 2;
+        let max_effective_balance = share_balance.fixed_mul_floor(
+            &self.env,
+            &(config.max_boost_bps as u128),
+            &(BPS_DENOMINATOR as u128),
+        );
 
         // min(adjusted_balance, max_effective_balance)
         if adjusted_balance > max_effective_balance {
@@ -1,16 +1,27 @@
-use crate::concentrated_weight::{apply_multiplier, position_multiplier_bps};
-use crate::constants::REWARD_PRECISION;
+use crate::concentrated_weight::{apply_multiplier, position_multiplier_bps, BPS_DENOMINATOR};
+use crate::constants::{
+    MAX_BOOST_BPS_LIMIT, MAX_EXTRA_REWARD_TOKENS, MAX_HISTORY_PAGE_SIZE, MAX_VESTING_TRANCHES,
+    REWARD_PRECISION,
+};
 use crate::errors::RewardsError;
 use crate::locked_boost::manager::BoostManagerPlugin;
 use crate::opt_out::manager::OptOutManagerPlugin;
 use crate::storage::{
-    PoolRewardConfig, PoolRewardData, PoolRewardsStorageTrait, RewardInvDataStorageTrait,
-    RewardTokenStorageTrait, Storage, UserRewardData, UserRewardsStorageTrait,
+    BoostConfig, BoostConfigStorageTrait, BoostDelegation, DataKey, ExtraRewardTokensStorageTrait,
+    PoolRewardConfig, PoolRewardData, PoolRewardsStorageTrait, RewardClaimRecord,
+    RewardClaimerStorageTrait, RewardEpochSnapshot, RewardHistoryStorageTrait,
+    RewardInvDataStorageTrait, RewardTokenStorageTrait, Storage, UserRewardData,
+    UserRewardsStorageTrait, VestingConfig, VestingSchedule, VestingStorageTrait, VestingTranche,
     WorkingBalancesStorageTrait,
 };
 use crate::RewardsConfig;
+use access_control::errors::AccessControlError;
+use access_control::transfer::{
+    apply_delayed_action, commit_delayed_action, get_delayed_action_deadline, revert_delayed_action,
+};
 use soroban_fixed_point_math::SorobanFixedPoint;
-use soroban_sdk::{panic_with_error, token::TokenClient as Client, Address, Env, Vec, U256};
+use soroban_sdk::{panic_with_error, token::TokenClient as Client, Address, Env, Map, Vec, U256};
+use utils::storage_errors::StorageError;
 
 // `Manager` orchestrates the reward logic, pulling data and methods from `Storage`.
 // It relies on Storage sub-traits to handle actual storage I/O.
@@ -88,6 +99,101 @@ impl Manager {
         self.boost_manager_plugin.get_total_locked(&self.storage)
     }
 
+    // ------------------------------------
+    // Boost delegation
+    // ------------------------------------
+
+    // Active delegations of the owner's boost keyed by delegatee.
+    pub fn get_boost_delegations(&self, owner: &Address) -> Map<Address, BoostDelegation> {
+        self.boost_manager_plugin
+            .get_boost_delegations(&self.storage, owner)
+    }
+
+    // Owners with an active delegation to the delegatee.
+    pub fn get_boost_delegators(&self, delegatee: &Address) -> Vec<Address> {
+        self.boost_manager_plugin
+            .get_boost_delegators(&self.storage, delegatee)
+    }
+
+    // Accounts whose boost depends on the user's, to be checkpointed along with the user.
+    pub fn get_boost_counterparties(&self, user: &Address) -> Vec<Address> {
+        self.boost_manager_plugin
+            .get_boost_counterparties(&self.storage, user)
+    }
+
+    // Lends `amount_bps` of the owner's boost balance to the delegatee until `expires_at`.
+    // Working balances of both accounts should be checkpointed afterwards.
+    pub fn set_boost_delegation(
+        &self,
+        owner: &Address,
+        delegatee: &Address,
+        amount_bps: u32,
+        expires_at: u64,
+    ) {
+        self.boost_manager_plugin.set_boost_delegation(
+            &self.storage,
+            owner,
+            delegatee,
+            amount_bps,
+            expires_at,
+        );
+    }
+
+    // ------------------------------------
+    // Boost parameters
+    // ------------------------------------
+
+    pub fn get_boost_config(&self) -> BoostConfig {
+        self.boost_manager_plugin.get_boost_config(&self.storage)
+    }
+
+    // Committed boost parameters waiting for the timelock, if any.
+    pub fn get_future_boost_config(&self) -> Option<BoostConfig> {
+        match self.get_boost_config_deadline() {
+            0 => None,
+            _ => self.storage.get_future_boost_config(),
+        }
+    }
+
+    pub fn get_boost_config_deadline(&self) -> u64 {
+        get_delayed_action_deadline(&self.env, &DataKey::BoostConfigDeadline)
+    }
+
+    // Schedules new boost parameters, applicable after ADMIN_ACTIONS_DELAY.
+    // Max boost is kept within [1x, MAX_BOOST_BPS_LIMIT].
+    pub fn commit_boost_config(&self, config: &BoostConfig) {
+        if config.max_boost_bps < BPS_DENOMINATOR || config.max_boost_bps > MAX_BOOST_BPS_LIMIT {
+            panic_with_error!(&self.env, RewardsError::InvalidBoostConfig);
+        }
+        commit_delayed_action(&self.env, &DataKey::BoostConfigDeadline);
+        self.storage.set_future_boost_config(config);
+    }
+
+    // Activates the committed parameters. Working balances pick them up on the next checkpoint.
+    pub fn apply_boost_config(&self) -> BoostConfig {
+        apply_delayed_action(&self.env, &DataKey::BoostConfigDeadline);
+        let config = match self.storage.get_future_boost_config() {
+            Some(v) => v,
+            None => panic_with_error!(&self.env, StorageError::ValueNotInitialized),
+        };
+        self.storage.set_boost_config(&config);
+        config
+    }
+
+    pub fn revert_boost_config(&self) {
+        revert_delayed_action(&self.env, &DataKey::BoostConfigDeadline);
+    }
+
+    pub fn get_boost_lock_records_enabled(&self) -> bool {
+        self.storage.get_boost_lock_records_enabled()
+    }
+
+    // Switches boost balances to the feed lock records. Meant to be turned on once locks are backfilled,
+    // since accounts without a record have no boost balance afterwards.
+    pub fn set_boost_lock_records_enabled(&self, value: bool) {
+        self.storage.set_boost_lock_records_enabled(value);
+    }
+
     // ------------------------------------
     // Effective balance logic
     // ------------------------------------
@@ -160,7 +266,59 @@ impl Manager {
     // # Panics
     //
     // This method will panic if the expiration time is in the past or if the tokens per second is zero and the configuration has already expired.
-    pub fn set_reward_config(&mut self, total_shares: u128, mut expired_at: u64, tps: u128) {
+    pub fn set_reward_config(&mut self, total_shares: u128, expired_at: u64, tps: u128) {
+        let pool_data = match self.update_reward_config(total_shares, expired_at, tps) {
+            Some(v) => v,
+            None => return,
+        };
+
+        if self.storage.get_reward_history_enabled() {
+            let config = self.storage.get_pool_reward_config();
+            self.storage
+                .push_reward_epoch_snapshot(&RewardEpochSnapshot {
+                    timestamp: self.env.ledger().timestamp(),
+                    accumulated: pool_data.accumulated,
+                    claimed: pool_data.claimed,
+                    tps,
+                    expired_at: config.expired_at,
+                });
+        }
+    }
+
+    // Same as `set_reward_config`, for any of the pool reward tokens.
+    pub fn set_token_reward_config(
+        &mut self,
+        token: &Address,
+        total_shares: u128,
+        expired_at: u64,
+        tps: u128,
+    ) {
+        if *token == self.storage.get_reward_token() {
+            self.set_reward_config(total_shares, expired_at, tps);
+            return;
+        }
+        self.assert_extra_reward_token(token);
+        self.with_reward_token(token, |manager| {
+            manager.update_reward_config(total_shares, expired_at, tps);
+        });
+    }
+
+    pub fn get_token_reward_config(&mut self, token: &Address) -> PoolRewardConfig {
+        if *token == self.storage.get_reward_token() {
+            return self.storage.get_pool_reward_config();
+        }
+        self.assert_extra_reward_token(token);
+        self.with_reward_token(token, |manager| manager.storage.get_pool_reward_config())
+    }
+
+    // Brings the pool data up-to-date and activates the new config.
+    // Returns the pool data at the start of the config, None if there was nothing to change.
+    fn update_reward_config(
+        &mut self,
+        total_shares: u128,
+        mut expired_at: u64,
+        tps: u128,
+    ) -> Option<PoolRewardData> {
         let now = self.env.ledger().timestamp();
         let old_config = self.storage.get_pool_reward_config();
         // if we stop rewards manually by setting tps to zero,
@@ -178,18 +336,100 @@ impl Manager {
 
         if old_config.expired_at < now && tps == 0 {
             // Already expired, no need to override
-            return;
+            return None;
         }
 
         let working_supply = self.get_working_supply(total_shares);
 
         // Bring pool data up-to-date
         self.update_rewards_data(working_supply);
-        self.snapshot_rewards_data(working_supply);
+        let pool_data = self.snapshot_rewards_data(working_supply);
 
         let config = PoolRewardConfig { tps, expired_at };
 
         self.storage.set_pool_reward_config(&config);
+        Some(pool_data)
+    }
+
+    // ------------------------------------
+    // Extra reward tokens
+    // ------------------------------------
+
+    // Reward tokens of the pool, the primary one first.
+    pub fn get_reward_tokens(&self) -> Vec<Address> {
+        let mut tokens = Vec::from_array(&self.env, [self.storage.get_reward_token()]);
+        tokens.append(&self.storage.get_extra_reward_tokens());
+        tokens
+    }
+
+    // Adds a reward token accounted separately from the primary one.
+    // It yields nothing until configured with `set_token_reward_config`.
+    pub fn add_reward_token(&self, token: &Address) {
+        if self.get_reward_tokens().contains(token) {
+            panic_with_error!(&self.env, RewardsError::RewardTokenAlreadyAdded);
+        }
+        let mut tokens = self.storage.get_extra_reward_tokens();
+        if tokens.len() >= MAX_EXTRA_REWARD_TOKENS {
+            panic_with_error!(&self.env, RewardsError::TooManyRewardTokens);
+        }
+        tokens.push_back(token.clone());
+        self.storage.set_extra_reward_tokens(&tokens);
+    }
+
+    fn assert_extra_reward_token(&self, token: &Address) {
+        if !self.storage.get_extra_reward_tokens().contains(token) {
+            panic_with_error!(&self.env, RewardsError::RewardTokenNotFound);
+        }
+    }
+
+    // Runs `f` against the reward data of the extra token. Working balances are shared.
+    fn with_reward_token<R>(&mut self, token: &Address, f: impl FnOnce(&mut Self) -> R) -> R {
+        let storage = core::mem::replace(
+            &mut self.storage,
+            Storage::new_for_reward_token(&self.env, token),
+        );
+        let result = f(self);
+        self.storage = storage;
+        result
+    }
+
+    // Accrues the extra token reward of the user with the working balance it was earned with.
+    fn checkpoint_extra_token_user(
+        &mut self,
+        user: &Address,
+        working_balance: u128,
+        working_supply: u128,
+    ) {
+        // users holding shares when the token was added have no data for it yet. their working
+        // balance hasn't changed since, as any change checkpoints every token, so they accrue
+        // from the first block of the token
+        if self.storage.get_user_reward_data(user).is_none() {
+            self.storage.set_user_reward_data(
+                user,
+                &UserRewardData {
+                    pool_accumulated: 0,
+                    to_claim: 0,
+                    last_block: 0,
+                },
+            );
+        }
+        let pool_data = self.update_rewards_data(working_supply);
+        self.update_user_reward(&pool_data, user, working_balance);
+    }
+
+    // Pays out the accrued extra token reward of an already checkpointed user.
+    fn pay_out_extra_token_reward(&mut self, user: &Address, recipient: &Address) -> u128 {
+        let mut user_data = match self.storage.get_user_reward_data(user) {
+            Some(v) => v,
+            None => return 0,
+        };
+        let amount = user_data.to_claim;
+        if amount > 0 {
+            user_data.to_claim = 0;
+            self.storage.set_user_reward_data(user, &user_data);
+            self.pay_out(recipient, amount);
+        }
+        amount
     }
 
     // ------------------------------------
@@ -381,11 +621,26 @@ impl Manager {
     }
 
     // Actually claims the user's reward and transfers tokens.
+    // If vesting is configured, the reward is added to the user's vesting schedule instead
+    // and released later with `claim_vested`.
     pub fn claim_reward(
         &mut self,
         user: &Address,
         total_shares: u128,
         user_balance_shares: u128,
+    ) -> u128 {
+        self.claim_reward_to(user, user, total_shares, user_balance_shares)
+    }
+
+    // Same as `claim_reward`, but transfers the tokens to `recipient`.
+    // With vesting enabled the rewards can only vest in the user's own schedule,
+    // so any other recipient is rejected.
+    pub fn claim_reward_to(
+        &mut self,
+        user: &Address,
+        recipient: &Address,
+        total_shares: u128,
+        user_balance_shares: u128,
     ) -> u128 {
         // update pool data & calculate reward
         let UserRewardData {
@@ -394,18 +649,23 @@ impl Manager {
             to_claim: reward_amount,
         } = self.checkpoint_user(user, total_shares, user_balance_shares);
 
-        // Increase total claimed in the pool
-        let mut pool_data = self.storage.get_pool_reward_data();
-        pool_data.claimed += reward_amount;
-        self.storage.set_pool_reward_data(&pool_data);
-
-        // Transfer tokens
-        let reward_token = self.storage.get_reward_token();
-        Client::new(&self.env, &reward_token).transfer(
-            &self.env.current_contract_address(),
-            user,
-            &(reward_amount as i128),
-        );
+        match self.storage.get_vesting_config() {
+            Some(_) if recipient != user => {
+                panic_with_error!(&self.env, RewardsError::VestingRecipientNotAllowed)
+            }
+            Some(config) => self.add_to_vesting(user, reward_amount, config),
+            None => self.pay_out(recipient, reward_amount),
+        }
+        if reward_amount > 0 && self.storage.get_reward_history_enabled() {
+            self.storage.push_user_reward_claim(
+                user,
+                &RewardClaimRecord {
+                    timestamp: self.env.ledger().timestamp(),
+                    amount: reward_amount,
+                    recipient: recipient.clone(),
+                },
+            );
+        }
 
         // Reset user reward
         let new_data = UserRewardData {
@@ -418,6 +678,354 @@ impl Manager {
         reward_amount
     }
 
+    // Amounts of every reward token the user is eligible to claim.
+    pub fn get_amounts_to_claim(
+        &mut self,
+        user: &Address,
+        total_shares: u128,
+        user_balance_shares: u128,
+    ) -> Map<Address, u128> {
+        let to_claim = self.get_amount_to_claim(user, total_shares, user_balance_shares);
+        let mut result = Map::from_array(&self.env, [(self.storage.get_reward_token(), to_claim)]);
+        for token in self.storage.get_extra_reward_tokens() {
+            let to_claim = self.with_reward_token(&token, |manager| {
+                match manager.storage.get_user_reward_data(user) {
+                    Some(v) => v.to_claim,
+                    None => 0,
+                }
+            });
+            result.set(token, to_claim);
+        }
+        result
+    }
+
+    // Claims the user's reward in every reward token and transfers it to `recipient`.
+    // The primary token is claimed as with `claim_reward_to`, extra tokens are never vested.
+    pub fn claim_rewards_to(
+        &mut self,
+        user: &Address,
+        recipient: &Address,
+        total_shares: u128,
+        user_balance_shares: u128,
+    ) -> Map<Address, u128> {
+        let reward = self.claim_reward_to(user, recipient, total_shares, user_balance_shares);
+        let mut result = Map::from_array(&self.env, [(self.storage.get_reward_token(), reward)]);
+        for token in self.storage.get_extra_reward_tokens() {
+            let amount = self.with_reward_token(&token, |manager| {
+                manager.pay_out_extra_token_reward(user, recipient)
+            });
+            result.set(token, amount);
+        }
+        result
+    }
+
+    // ------------------------------------
+    // Claim delegation
+    // ------------------------------------
+
+    pub fn get_reward_claimer(&self, user: &Address) -> Option<Address> {
+        self.storage.get_reward_claimer(user)
+    }
+
+    // Allows `claimer` to claim the user's rewards to any recipient; `None` revokes it.
+    pub fn set_reward_claimer(&self, user: &Address, claimer: Option<Address>) {
+        self.storage.set_reward_claimer(user, &claimer);
+    }
+
+    // Claims of the user are authorized either by the user or by the delegated claimer.
+    pub fn require_claim_auth(&self, caller: &Address, user: &Address) {
+        caller.require_auth();
+        if caller != user && self.storage.get_reward_claimer(user).as_ref() != Some(caller) {
+            panic_with_error!(&self.env, AccessControlError::Unauthorized);
+        }
+    }
+
+    // ------------------------------------
+    // Reward history
+    // ------------------------------------
+
+    pub fn get_reward_history_enabled(&self) -> bool {
+        self.storage.get_reward_history_enabled()
+    }
+
+    // Turns recording of epoch snapshots and user claims on or off. Recorded history is kept.
+    pub fn set_reward_history_enabled(&self, value: bool) {
+        self.storage.set_reward_history_enabled(value);
+    }
+
+    // Page of pool reward epoch snapshots, oldest first.
+    pub fn get_reward_epoch_snapshots(&self, offset: u32, limit: u32) -> Vec<RewardEpochSnapshot> {
+        let end = self
+            .storage
+            .get_reward_epoch_snapshots_count()
+            .min(offset.saturating_add(limit.min(MAX_HISTORY_PAGE_SIZE)));
+        let mut result = Vec::new(&self.env);
+        for i in offset..end {
+            result.push_back(self.storage.get_reward_epoch_snapshot(i));
+        }
+        result
+    }
+
+    // Page of the user's reward claims, oldest first.
+    pub fn get_user_reward_claims(
+        &self,
+        user: &Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<RewardClaimRecord> {
+        let end = self
+            .storage
+            .get_user_reward_claims_count(user)
+            .min(offset.saturating_add(limit.min(MAX_HISTORY_PAGE_SIZE)));
+        let mut result = Vec::new(&self.env);
+        for i in offset..end {
+            result.push_back(self.storage.get_user_reward_claim(user, i));
+        }
+        result
+    }
+
+    // ------------------------------------
+    // Reward vesting
+    // ------------------------------------
+
+    pub fn get_vesting_config(&self) -> Option<VestingConfig> {
+        self.storage.get_vesting_config()
+    }
+
+    // Sets vesting parameters for future claims; `None` pays rewards out on claim again.
+    // Existing schedules keep the parameters they were created with.
+    pub fn set_vesting_config(&self, config: Option<VestingConfig>) {
+        if let Some(ref v) = config {
+            if v.duration == 0 || v.cliff > v.duration || v.early_exit_penalty_bps > BPS_DENOMINATOR
+            {
+                panic_with_error!(&self.env, RewardsError::InvalidVestingConfig);
+            }
+        }
+        self.storage.set_vesting_config(&config);
+    }
+
+    // Returns the user's schedule with everything vested so far moved to `unlocked`.
+    pub fn get_user_vesting(&self, user: &Address) -> Option<VestingSchedule> {
+        self.storage
+            .get_user_vesting(user)
+            .map(|schedule| self.rebase_vesting(schedule))
+    }
+
+    // Releases the vested part of the schedule. Returns the amount transferred.
+    pub fn claim_vested(&mut self, user: &Address) -> u128 {
+        let mut schedule = match self.get_user_vesting(user) {
+            Some(v) => v,
+            None => return 0,
+        };
+        let amount = schedule.unlocked;
+        schedule.unlocked = 0;
+        self.set_user_vesting(user, schedule);
+        self.pay_out(user, amount);
+        amount
+    }
+
+    // Releases the whole schedule at once. The still locked part is reduced by the early exit
+    // penalty, which is distributed to the other LPs as a one-off reward block.
+    // Returns the amount transferred and the penalty.
+    pub fn exit_vesting(
+        &mut self,
+        user: &Address,
+        total_shares: u128,
+        user_balance_shares: u128,
+    ) -> (u128, u128) {
+        let schedule = match self.get_user_vesting(user) {
+            Some(v) => v,
+            None => return (0, 0),
+        };
+        // every tranche is penalized by the parameters it was created with
+        let mut penalty = 0;
+        for tranche in schedule.tranches.iter() {
+            penalty += U256::from_u128(&self.env, tranche.amount)
+                .fixed_mul_floor(
+                    &self.env,
+                    &U256::from_u32(&self.env, tranche.config.early_exit_penalty_bps),
+                    &U256::from_u32(&self.env, BPS_DENOMINATOR),
+                )
+                .to_u128()
+                .unwrap();
+        }
+        let amount = schedule.unlocked + schedule.total - penalty;
+        self.storage.set_user_vesting(user, &None);
+
+        // bring the user up to date, so the penalty block is not credited to them
+        let user_data = self.checkpoint_user(user, total_shares, user_balance_shares);
+        let others_supply =
+            self.storage.get_working_supply() - self.storage.get_working_balance(user);
+
+        // the whole schedule leaves the vesting balance; the penalty re-enters as accumulated
+        let mut pool_data = self.storage.get_pool_reward_data();
+        pool_data.claimed += schedule.unlocked + schedule.total;
+        if penalty > 0 && others_supply > 0 {
+            let pool_data = self.create_new_rewards_data(
+                penalty,
+                others_supply,
+                PoolRewardData {
+                    block: pool_data.block + 1,
+                    accumulated: pool_data.accumulated + penalty,
+                    claimed: pool_data.claimed,
+                    last_time: pool_data.last_time,
+                },
+            );
+            self.create_new_user_data(user, &pool_data, user_data.to_claim);
+        } else {
+            // nobody to distribute to: the penalty becomes unused reward
+            self.storage.set_pool_reward_data(&pool_data);
+        }
+
+        self.transfer_reward(user, amount);
+        (amount, penalty)
+    }
+
+    fn add_to_vesting(&mut self, user: &Address, amount: u128, config: VestingConfig) {
+        if amount == 0 {
+            return;
+        }
+        let now = self.env.ledger().timestamp();
+        let mut schedule = match self.get_user_vesting(user) {
+            Some(v) => v,
+            None => VestingSchedule {
+                total: 0,
+                unlocked: 0,
+                tranches: Vec::new(&self.env),
+            },
+        };
+        // earlier tranches keep their schedule; claims within the same ledger share a tranche.
+        // Once the schedule is full, the claim is folded into the last tranche, which ends at
+        // the amount-weighted end of both. This bounds the schedule size, as claims need no
+        // user auth, while repeated claims can't postpone the rewards already vesting.
+        schedule.total += amount;
+        let last_idx = schedule.tranches.len().saturating_sub(1);
+        match schedule.tranches.last() {
+            Some(mut tranche) if tranche.start == now && tranche.config == config => {
+                tranche.amount += amount;
+                schedule.tranches.set(last_idx, tranche);
+            }
+            Some(tranche) if schedule.tranches.len() >= MAX_VESTING_TRANCHES => {
+                let merged = self.merge_vesting_tranche(tranche, amount, config);
+                schedule.tranches.set(last_idx, merged);
+            }
+            _ => schedule.tranches.push_back(VestingTranche {
+                amount,
+                start: now,
+                config,
+            }),
+        }
+        self.set_user_vesting(user, schedule);
+    }
+
+    // Adds `amount` vesting from now by `config` to the tranche. The cliff and the end of the
+    // result are the amount-weighted average of both, so the tranche keeps its remaining schedule
+    // for its own amount.
+    fn merge_vesting_tranche(
+        &self,
+        tranche: VestingTranche,
+        amount: u128,
+        config: VestingConfig,
+    ) -> VestingTranche {
+        let now = self.env.ledger().timestamp();
+        let total = tranche.amount + amount;
+        let weighted = |tranche_time: u64, new_time: u64| -> u64 {
+            U256::from_u128(&self.env, tranche_time as u128)
+                .mul(&U256::from_u128(&self.env, tranche.amount))
+                .add(
+                    &U256::from_u128(&self.env, new_time as u128)
+                        .mul(&U256::from_u128(&self.env, amount)),
+                )
+                .div(&U256::from_u128(&self.env, total))
+                .to_u128()
+                .unwrap() as u64
+        };
+        let cliff_end = weighted(tranche.start + tranche.config.cliff, now + config.cliff);
+        let end = weighted(
+            tranche.start + tranche.config.duration,
+            now + config.duration,
+        );
+        VestingTranche {
+            amount: total,
+            start: now,
+            config: VestingConfig {
+                cliff: cliff_end.saturating_sub(now),
+                duration: end.saturating_sub(now),
+                early_exit_penalty_bps: config.early_exit_penalty_bps,
+            },
+        }
+    }
+
+    // Moves the part of every tranche vested since its `start` to `unlocked`.
+    // Fully vested tranches are dropped.
+    fn rebase_vesting(&self, mut schedule: VestingSchedule) -> VestingSchedule {
+        let mut tranches = Vec::new(&self.env);
+        for tranche in schedule.tranches.iter() {
+            let (tranche, vested) = self.rebase_vesting_tranche(tranche);
+            schedule.unlocked += vested;
+            schedule.total -= vested;
+            if tranche.amount > 0 {
+                tranches.push_back(tranche);
+            }
+        }
+        schedule.tranches = tranches;
+        schedule
+    }
+
+    // Moves the part vested since `start` out of the tranche and restarts the linear unlock of
+    // the remainder from now over the remaining duration, which keeps the vesting rate unchanged.
+    // Returns the rebased tranche and the vested amount.
+    fn rebase_vesting_tranche(&self, mut tranche: VestingTranche) -> (VestingTranche, u128) {
+        let now = self.env.ledger().timestamp();
+        let elapsed = now - tranche.start;
+        if elapsed < tranche.config.cliff || elapsed == 0 {
+            return (tranche, 0);
+        }
+
+        let vested = if elapsed >= tranche.config.duration {
+            tranche.amount
+        } else {
+            U256::from_u128(&self.env, tranche.amount)
+                .fixed_mul_floor(
+                    &self.env,
+                    &U256::from_u128(&self.env, elapsed as u128),
+                    &U256::from_u128(&self.env, tranche.config.duration as u128),
+                )
+                .to_u128()
+                .unwrap()
+        };
+        tranche.amount -= vested;
+        tranche.start = now;
+        tranche.config.duration = tranche.config.duration.saturating_sub(elapsed);
+        tranche.config.cliff = 0;
+        (tranche, vested)
+    }
+
+    fn set_user_vesting(&self, user: &Address, schedule: VestingSchedule) {
+        if schedule.total == 0 && schedule.unlocked == 0 {
+            self.storage.set_user_vesting(user, &None);
+        } else {
+            self.storage.set_user_vesting(user, &Some(schedule));
+        }
+    }
+
+    // Transfers reward tokens and accounts them as claimed.
+    fn pay_out(&mut self, user: &Address, amount: u128) {
+        let mut pool_data = self.storage.get_pool_reward_data();
+        pool_data.claimed += amount;
+        self.storage.set_pool_reward_data(&pool_data);
+        self.transfer_reward(user, amount);
+    }
+
+    fn transfer_reward(&self, user: &Address, amount: u128) {
+        let reward_token = self.storage.get_reward_token();
+        Client::new(&self.env, &reward_token).transfer(
+            &self.env.current_contract_address(),
+            user,
+            &(amount as i128),
+        );
+    }
+
     // Forces an update of the user's reward data based on the new working balance.
     pub fn checkpoint_user(
         &mut self,
@@ -428,6 +1036,12 @@ impl Manager {
         let (working_balance, new_working_supply) =
             self.update_working_balance(user, total_shares, user_balance_shares);
 
+        for token in self.storage.get_extra_reward_tokens() {
+            self.with_reward_token(&token, |manager| {
+                manager.checkpoint_extra_token_user(user, working_balance, new_working_supply)
+            });
+        }
+
         let pool_data = self.update_rewards_data(new_working_supply);
         let user_data = self.update_user_reward(&pool_data, user, working_balance);
 
@@ -33,9 +33,86 @@ pub struct UserRewardData {
     pub last_block: u64,
 }
 
+// Vesting parameters for claimed rewards. When absent, rewards are paid out on claim.
+// Nothing is releasable before `cliff`; the amount vests linearly over `duration` from the start.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VestingConfig {
+    pub cliff: u64,
+    pub duration: u64,
+    pub early_exit_penalty_bps: u32,
+}
+
+// Amount claimed at `start`, vesting by its own parameters independently of later claims.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VestingTranche {
+    pub amount: u128,
+    pub start: u64,
+    pub config: VestingConfig,
+}
+
+// Per-user vesting schedule. Every claim adds a tranche, up to `MAX_VESTING_TRANCHES`; the
+// later ones are folded into the last tranche at their amount-weighted end. The part vested so far moves from the
+// tranches to `unlocked`. `total` is the amount still locked in all tranches.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VestingSchedule {
+    pub total: u128,
+    pub unlocked: u128,
+    pub tranches: Vec<VestingTranche>,
+}
+
+impl VestingSchedule {
+    // Time the last tranche is fully vested.
+    pub fn end_time(&self) -> u64 {
+        let mut result = 0;
+        for tranche in self.tranches.iter() {
+            result = result.max(tranche.start + tranche.config.duration);
+        }
+        result
+    }
+}
+
+// Locked token boost parameters: effective balance = min(b + (max_boost - 1) * S * l / L,
+// max_boost * b), so shares without a lock keep 1x. The default 2.5x is the Curve-style constant.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BoostConfig {
+    pub max_boost_bps: u32,
+}
+
+// Part of the owner's boost balance lent to another account until `expires_at`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BoostDelegation {
+    pub amount_bps: u32,
+    pub expires_at: u64,
+}
+
+// Pool reward totals at the start of a reward epoch, recorded on every reward config change.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RewardEpochSnapshot {
+    pub timestamp: u64,
+    pub accumulated: u128,
+    pub claimed: u128,
+    pub tps: u128,
+    pub expired_at: u64,
+}
+
+// Reward claimed by the user, vested or paid to `recipient`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RewardClaimRecord {
+    pub timestamp: u64,
+    pub amount: u128,
+    pub recipient: Address,
+}
+
 #[derive(Clone)]
 #[contracttype]
-enum DataKey {
+pub(crate) enum DataKey {
     // Pool-level data
     PoolRewardConfig,
     PoolRewardData,
@@ -60,6 +137,37 @@ enum DataKey {
     // Excluded shares from rewards
     ExcludedShares,
     UserRewardsState(Address),
+
+    // Reward vesting
+    VestingConfig,
+    UserVesting(Address),
+
+    // Claim delegation
+    RewardClaimer(Address),
+
+    // Boost parameters
+    BoostConfig,
+    FutureBoostConfig,
+    BoostConfigDeadline,
+    BoostLockRecordsEnabled,
+
+    // Boost delegation
+    BoostDelegations(Address),
+    BoostDelegators(Address),
+
+    // Reward history
+    RewardHistoryEnabled,
+    RewardEpochSnapshotsCount,
+    RewardEpochSnapshot(u32),
+    UserRewardClaimsCount(Address),
+    UserRewardClaim(Address, u32),
+
+    // Extra reward tokens, accounted separately from the primary one
+    ExtraRewardTokens,
+    TokenPoolRewardConfig(Address),
+    TokenPoolRewardData(Address),
+    TokenUserRewardData(Address, Address),
+    TokenRewardInvData(Address, u32, u64),
 }
 
 // ------------------------------------
@@ -68,9 +176,12 @@ enum DataKey {
 
 // Storage struct contains the environment and a local cache (`inv_cache`)
 // to avoid repeated loading for reward invariants.
+// Storage scoped to an extra reward token (`reward_token`) keeps pool, user and invariant
+// reward data of that token under separate keys; everything else is shared.
 pub struct Storage {
     env: Env,
     inv_cache: Map<DataKey, Vec<u128>>,
+    reward_token: Option<Address>,
 }
 
 impl Storage {
@@ -78,6 +189,36 @@ impl Storage {
         Storage {
             env: e.clone(),
             inv_cache: Map::new(e),
+            reward_token: None,
+        }
+    }
+
+    pub(crate) fn new_for_reward_token(e: &Env, token: &Address) -> Storage {
+        Storage {
+            env: e.clone(),
+            inv_cache: Map::new(e),
+            reward_token: Some(token.clone()),
+        }
+    }
+
+    fn pool_reward_config_key(&self) -> DataKey {
+        match &self.reward_token {
+            Some(token) => DataKey::TokenPoolRewardConfig(token.clone()),
+            None => DataKey::PoolRewardConfig,
+        }
+    }
+
+    fn pool_reward_data_key(&self) -> DataKey {
+        match &self.reward_token {
+            Some(token) => DataKey::TokenPoolRewardData(token.clone()),
+            None => DataKey::PoolRewardData,
+        }
+    }
+
+    fn user_reward_data_key(&self, user: &Address) -> DataKey {
+        match &self.reward_token {
+            Some(token) => DataKey::TokenUserRewardData(token.clone(), user.clone()),
+            None => DataKey::UserRewardData(user.clone()),
         }
     }
 }
@@ -235,7 +376,7 @@ impl PoolRewardsStorageTrait for Storage {
             .env
             .storage()
             .instance()
-            .get(&DataKey::PoolRewardConfig)
+            .get(&self.pool_reward_config_key())
         {
             Some(v) => v,
             None => PoolRewardConfig {
@@ -250,12 +391,17 @@ impl PoolRewardsStorageTrait for Storage {
         self.env
             .storage()
             .instance()
-            .set(&DataKey::PoolRewardConfig, config);
+            .set(&self.pool_reward_config_key(), config);
     }
 
     fn get_pool_reward_data(&self) -> PoolRewardData {
         bump_instance(&self.env);
-        match self.env.storage().instance().get(&DataKey::PoolRewardData) {
+        match self
+            .env
+            .storage()
+            .instance()
+            .get(&self.pool_reward_data_key())
+        {
             Some(v) => v,
             None => PoolRewardData {
                 block: 0,
@@ -271,7 +417,7 @@ impl PoolRewardsStorageTrait for Storage {
         self.env
             .storage()
             .instance()
-            .set(&DataKey::PoolRewardData, data);
+            .set(&self.pool_reward_data_key(), data);
     }
 }
 
@@ -286,7 +432,7 @@ pub trait UserRewardsStorageTrait {
 
 impl UserRewardsStorageTrait for Storage {
     fn get_user_reward_data(&self, user: &Address) -> Option<UserRewardData> {
-        let key = DataKey::UserRewardData(user.clone());
+        let key = self.user_reward_data_key(user);
         let data: Option<UserRewardData> = self.env.storage().persistent().get(&key);
         if data.is_some() {
             bump_persistent(&self.env, &key);
@@ -295,7 +441,7 @@ impl UserRewardsStorageTrait for Storage {
     }
 
     fn set_user_reward_data(&self, user: &Address, config: &UserRewardData) {
-        let key = DataKey::UserRewardData(user.clone());
+        let key = self.user_reward_data_key(user);
         self.env.storage().persistent().set(&key, config);
         bump_persistent(&self.env, &key);
     }
@@ -312,7 +458,10 @@ pub trait RewardInvDataStorageTrait {
 
 impl RewardInvDataStorageTrait for Storage {
     fn get_reward_inv_data(&mut self, pow: u32, page_number: u64) -> Vec<u128> {
-        let key = DataKey::RewardInvDataV2(pow, page_number);
+        let key = match &self.reward_token {
+            Some(token) => DataKey::TokenRewardInvData(token.clone(), pow, page_number),
+            None => DataKey::RewardInvDataV2(pow, page_number),
+        };
         if let Some(cached) = self.inv_cache.get(key.clone()) {
             return cached;
         }
@@ -322,6 +471,7 @@ impl RewardInvDataStorageTrait for Storage {
                 bump_persistent(&self.env, &key);
                 v
             }
+            None if self.reward_token.is_some() => return Vec::new(&self.env),
             None => {
                 // fallback to legacy key
                 let key_old = DataKey::RewardInvData(pow, page_number);
@@ -346,7 +496,10 @@ impl RewardInvDataStorageTrait for Storage {
     }
 
     fn set_reward_inv_data(&mut self, pow: u32, page_number: u64, value: Vec<u128>) {
-        let key = DataKey::RewardInvDataV2(pow, page_number);
+        let key = match &self.reward_token {
+            Some(token) => DataKey::TokenRewardInvData(token.clone(), pow, page_number),
+            None => DataKey::RewardInvDataV2(pow, page_number),
+        };
         self.inv_cache.set(key.clone(), value.clone());
         self.env.storage().persistent().set(&key, &value);
         bump_persistent(&self.env, &key);
@@ -365,6 +518,9 @@ pub trait RewardTokenStorageTrait {
 
 impl RewardTokenStorageTrait for Storage {
     fn get_reward_token(&self) -> Address {
+        if let Some(token) = &self.reward_token {
+            return token.clone();
+        }
         bump_instance(&self.env);
         match self.env.storage().instance().get(&DataKey::RewardToken) {
             Some(v) => v,
@@ -386,6 +542,344 @@ impl RewardTokenStorageTrait for Storage {
     }
 }
 
+// ------------------------------------
+// Sub-trait: Extra Reward Tokens
+// ------------------------------------
+
+pub trait ExtraRewardTokensStorageTrait {
+    fn get_extra_reward_tokens(&self) -> Vec<Address>;
+    fn set_extra_reward_tokens(&self, tokens: &Vec<Address>);
+}
+
+impl ExtraRewardTokensStorageTrait for Storage {
+    fn get_extra_reward_tokens(&self) -> Vec<Address> {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::ExtraRewardTokens)
+            .unwrap_or(Vec::new(&self.env))
+    }
+
+    fn set_extra_reward_tokens(&self, tokens: &Vec<Address>) {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::ExtraRewardTokens, tokens);
+    }
+}
+
+// ------------------------------------
+// Sub-trait: Reward Vesting
+// ------------------------------------
+
+pub trait VestingStorageTrait {
+    fn get_vesting_config(&self) -> Option<VestingConfig>;
+    fn set_vesting_config(&self, config: &Option<VestingConfig>);
+
+    fn get_user_vesting(&self, user: &Address) -> Option<VestingSchedule>;
+    fn set_user_vesting(&self, user: &Address, schedule: &Option<VestingSchedule>);
+}
+
+impl VestingStorageTrait for Storage {
+    fn get_vesting_config(&self) -> Option<VestingConfig> {
+        bump_instance(&self.env);
+        self.env.storage().instance().get(&DataKey::VestingConfig)
+    }
+
+    fn set_vesting_config(&self, config: &Option<VestingConfig>) {
+        bump_instance(&self.env);
+        match config {
+            Some(v) => self
+                .env
+                .storage()
+                .instance()
+                .set(&DataKey::VestingConfig, v),
+            None => self
+                .env
+                .storage()
+                .instance()
+                .remove(&DataKey::VestingConfig),
+        }
+    }
+
+    fn get_user_vesting(&self, user: &Address) -> Option<VestingSchedule> {
+        let key = DataKey::UserVesting(user.clone());
+        let data: Option<VestingSchedule> = self.env.storage().persistent().get(&key);
+        if data.is_some() {
+            bump_persistent(&self.env, &key);
+        }
+        data
+    }
+
+    fn set_user_vesting(&self, user: &Address, schedule: &Option<VestingSchedule>) {
+        let key = DataKey::UserVesting(user.clone());
+        match schedule {
+            Some(v) => {
+                self.env.storage().persistent().set(&key, v);
+                bump_persistent(&self.env, &key);
+            }
+            None => self.env.storage().persistent().remove(&key),
+        }
+    }
+}
+
+// ------------------------------------
+// Sub-trait: Boost Parameters
+// ------------------------------------
+
+pub trait BoostConfigStorageTrait {
+    fn get_boost_config(&self) -> Option<BoostConfig>;
+    fn set_boost_config(&self, config: &BoostConfig);
+
+    fn get_future_boost_config(&self) -> Option<BoostConfig>;
+    fn set_future_boost_config(&self, config: &BoostConfig);
+
+    fn get_boost_lock_records_enabled(&self) -> bool;
+    fn set_boost_lock_records_enabled(&self, value: bool);
+}
+
+impl BoostConfigStorageTrait for Storage {
+    fn get_boost_config(&self) -> Option<BoostConfig> {
+        bump_instance(&self.env);
+        self.env.storage().instance().get(&DataKey::BoostConfig)
+    }
+
+    fn set_boost_config(&self, config: &BoostConfig) {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::BoostConfig, config);
+    }
+
+    fn get_future_boost_config(&self) -> Option<BoostConfig> {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::FutureBoostConfig)
+    }
+
+    fn set_future_boost_config(&self, config: &BoostConfig) {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::FutureBoostConfig, config);
+    }
+
+    fn get_boost_lock_records_enabled(&self) -> bool {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::BoostLockRecordsEnabled)
+            .unwrap_or(false)
+    }
+
+    fn set_boost_lock_records_enabled(&self, value: bool) {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::BoostLockRecordsEnabled, &value);
+    }
+}
+
+// ------------------------------------
+// Sub-trait: Claim Delegation
+// ------------------------------------
+
+pub trait RewardClaimerStorageTrait {
+    fn get_reward_claimer(&self, user: &Address) -> Option<Address>;
+    fn set_reward_claimer(&self, user: &Address, claimer: &Option<Address>);
+}
+
+impl RewardClaimerStorageTrait for Storage {
+    fn get_reward_claimer(&self, user: &Address) -> Option<Address> {
+        let key = DataKey::RewardClaimer(user.clone());
+        let claimer: Option<Address> = self.env.storage().persistent().get(&key);
+        if claimer.is_some() {
+            bump_persistent(&self.env, &key);
+        }
+        claimer
+    }
+
+    fn set_reward_claimer(&self, user: &Address, claimer: &Option<Address>) {
+        let key = DataKey::RewardClaimer(user.clone());
+        match claimer {
+            Some(v) => {
+                self.env.storage().persistent().set(&key, v);
+                bump_persistent(&self.env, &key);
+            }
+            None => self.env.storage().persistent().remove(&key),
+        }
+    }
+}
+
+// ------------------------------------
+// Sub-trait: Boost Delegation
+// ------------------------------------
+
+pub trait BoostDelegationStorageTrait {
+    // outgoing delegations of the owner keyed by delegatee
+    fn get_boost_delegations(&self, owner: &Address) -> Map<Address, BoostDelegation>;
+    fn set_boost_delegations(&self, owner: &Address, delegations: &Map<Address, BoostDelegation>);
+
+    // owners delegating to the delegatee
+    fn get_boost_delegators(&self, delegatee: &Address) -> Vec<Address>;
+    fn set_boost_delegators(&self, delegatee: &Address, delegators: &Vec<Address>);
+}
+
+impl BoostDelegationStorageTrait for Storage {
+    fn get_boost_delegations(&self, owner: &Address) -> Map<Address, BoostDelegation> {
+        let key = DataKey::BoostDelegations(owner.clone());
+        match self.env.storage().persistent().get(&key) {
+            Some(v) => {
+                bump_persistent(&self.env, &key);
+                v
+            }
+            None => Map::new(&self.env),
+        }
+    }
+
+    fn set_boost_delegations(&self, owner: &Address, delegations: &Map<Address, BoostDelegation>) {
+        let key = DataKey::BoostDelegations(owner.clone());
+        if delegations.is_empty() {
+            self.env.storage().persistent().remove(&key);
+        } else {
+            self.env.storage().persistent().set(&key, delegations);
+            bump_persistent(&self.env, &key);
+        }
+    }
+
+    fn get_boost_delegators(&self, delegatee: &Address) -> Vec<Address> {
+        let key = DataKey::BoostDelegators(delegatee.clone());
+        match self.env.storage().persistent().get(&key) {
+            Some(v) => {
+                bump_persistent(&self.env, &key);
+                v
+            }
+            None => Vec::new(&self.env),
+        }
+    }
+
+    fn set_boost_delegators(&self, delegatee: &Address, delegators: &Vec<Address>) {
+        let key = DataKey::BoostDelegators(delegatee.clone());
+        if delegators.is_empty() {
+            self.env.storage().persistent().remove(&key);
+        } else {
+            self.env.storage().persistent().set(&key, delegators);
+            bump_persistent(&self.env, &key);
+        }
+    }
+}
+
+// ------------------------------------
+// Sub-trait: Reward History
+// ------------------------------------
+
+pub trait RewardHistoryStorageTrait {
+    fn get_reward_history_enabled(&self) -> bool;
+    fn set_reward_history_enabled(&self, value: bool);
+
+    fn get_reward_epoch_snapshots_count(&self) -> u32;
+    fn get_reward_epoch_snapshot(&self, index: u32) -> RewardEpochSnapshot;
+    fn push_reward_epoch_snapshot(&self, snapshot: &RewardEpochSnapshot);
+
+    fn get_user_reward_claims_count(&self, user: &Address) -> u32;
+    fn get_user_reward_claim(&self, user: &Address, index: u32) -> RewardClaimRecord;
+    fn push_user_reward_claim(&self, user: &Address, record: &RewardClaimRecord);
+}
+
+impl RewardHistoryStorageTrait for Storage {
+    fn get_reward_history_enabled(&self) -> bool {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::RewardHistoryEnabled)
+            .unwrap_or(false)
+    }
+
+    fn set_reward_history_enabled(&self, value: bool) {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::RewardHistoryEnabled, &value);
+    }
+
+    fn get_reward_epoch_snapshots_count(&self) -> u32 {
+        bump_instance(&self.env);
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::RewardEpochSnapshotsCount)
+            .unwrap_or(0)
+    }
+
+    fn get_reward_epoch_snapshot(&self, index: u32) -> RewardEpochSnapshot {
+        let key = DataKey::RewardEpochSnapshot(index);
+        match self.env.storage().persistent().get(&key) {
+            Some(v) => {
+                bump_persistent(&self.env, &key);
+                v
+            }
+            None => panic_with_error!(self.env, StorageError::ValueMissing),
+        }
+    }
+
+    fn push_reward_epoch_snapshot(&self, snapshot: &RewardEpochSnapshot) {
+        let index = self.get_reward_epoch_snapshots_count();
+        let key = DataKey::RewardEpochSnapshot(index);
+        self.env.storage().persistent().set(&key, snapshot);
+        bump_persistent(&self.env, &key);
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::RewardEpochSnapshotsCount, &(index + 1));
+    }
+
+    fn get_user_reward_claims_count(&self, user: &Address) -> u32 {
+        let key = DataKey::UserRewardClaimsCount(user.clone());
+        match self.env.storage().persistent().get(&key) {
+            Some(v) => {
+                bump_persistent(&self.env, &key);
+                v
+            }
+            None => 0,
+        }
+    }
+
+    fn get_user_reward_claim(&self, user: &Address, index: u32) -> RewardClaimRecord {
+        let key = DataKey::UserRewardClaim(user.clone(), index);
+        match self.env.storage().persistent().get(&key) {
+            Some(v) => {
+                bump_persistent(&self.env, &key);
+                v
+            }
+            None => panic_with_error!(self.env, StorageError::ValueMissing),
+        }
+    }
+
+    fn push_user_reward_claim(&self, user: &Address, record: &RewardClaimRecord) {
+        let index = self.get_user_reward_claims_count(user);
+        let key = DataKey::UserRewardClaim(user.clone(), index);
+        self.env.storage().persistent().set(&key, record);
+        bump_persistent(&self.env, &key);
+
+        let count_key = DataKey::UserRewardClaimsCount(user.clone());
+        self.env
+            .storage()
+            .persistent()
+            .set(&count_key, &(index + 1));
+        bump_persistent(&self.env, &count_key);
+    }
+}
+
 // Excluded shares for big liquidity providers to exclude themselves from receiving rewards
 impl Storage {
     // excluded shares shouldn't be counted for rewards
@@ -1,11 +1,13 @@
 use crate::constants::MAX_REWARD_CONFIGS;
 use crate::errors::GaugeError;
-use crate::gauge::{checkpoint_global, checkpoint_user};
+use crate::gauge::{checkpoint_global, checkpoint_user, find_reward_config, release_unallocated};
 use crate::interface::UpgradeableContract;
 use crate::storage::{
-    get_global_reward_data, get_pool, get_reward_configs, get_reward_token, set_global_reward_data,
-    set_pool, set_reward_configs, set_reward_token, set_user_reward_data, GlobalRewardData,
-    RewardConfig,
+    get_global_reward_data, get_next_reward_config_id, get_pool, get_reward_configs,
+    get_reward_distributions, get_reward_token, get_unallocated_reward, set_global_reward_data,
+    set_next_reward_config_id, set_pool, set_reward_configs, set_reward_distributions,
+    set_reward_token, set_unallocated_reward, set_user_reward_data, GlobalRewardData, RewardConfig,
+    RewardDistribution,
 };
 use soroban_sdk::token::Client;
 use soroban_sdk::{contract, contractimpl, panic_with_error, Address, BytesN, Env, Symbol, Vec};
@@ -20,6 +22,11 @@ impl RewardsGauge {
         set_reward_token(&e, &reward_token);
     }
 
+    // Schedules a new reward config funded by the distributor.
+    //
+    // # Returns
+    //
+    // The id of the scheduled config.
     pub fn schedule_rewards_config(
         e: Env,
         pool: Address,
@@ -28,7 +35,7 @@ impl RewardsGauge {
         duration: u64,
         tps: u128,
         working_supply: u128,
-    ) {
+    ) -> u32 {
         pool.require_auth();
         distributor.require_auth();
 
@@ -51,6 +58,7 @@ impl RewardsGauge {
         // checkpoint the global data before setting the new config
         checkpoint_global(&e, working_supply);
         let mut current_configs = get_reward_configs(&e);
+        let mut current_distributions = get_reward_distributions(&e);
 
         let now = e.ledger().timestamp();
         let config_start_at = start_at.unwrap_or(now);
@@ -69,7 +77,155 @@ impl RewardsGauge {
         if current_configs.len() > MAX_REWARD_CONFIGS {
             panic_with_error!(&e, GaugeError::TooManyConfigs);
         }
+        let config_id = get_next_reward_config_id(&e);
+        current_distributions.push_back(Some(RewardDistribution {
+            config_id,
+            distributor,
+            unallocated: 0,
+        }));
         set_reward_configs(&e, current_configs);
+        set_reward_distributions(&e, current_distributions);
+        set_next_reward_config_id(&e, config_id + 1);
+        config_id
+    }
+
+    // Cancels the reward config scheduled by the distributor.
+    // Full amount is refunded if the config hasn't started yet, otherwise only the remaining part.
+    //
+    // # Returns
+    //
+    // The refunded amount.
+    pub fn cancel_scheduled_reward(
+        e: Env,
+        pool: Address,
+        distributor: Address,
+        config_id: u32,
+        working_supply: u128,
+    ) -> u128 {
+        pool.require_auth();
+        distributor.require_auth();
+
+        if get_pool(&e) != pool {
+            panic_with_error!(&e, GaugeError::Unauthorized);
+        }
+
+        // rewards generated so far stay with the users
+        checkpoint_global(&e, working_supply);
+        let (index, config, distribution) = find_reward_config(&e, config_id);
+        if distribution.distributor != distributor {
+            panic_with_error!(&e, GaugeError::Unauthorized);
+        }
+
+        let now = e.ledger().timestamp();
+        let refund_from = config.start_at.max(now);
+        let refund = (config.expired_at - refund_from) as u128 * config.tps;
+
+        let mut configs = get_reward_configs(&e);
+        let mut distributions = get_reward_distributions(&e);
+        configs.remove(index);
+        distributions.remove(index);
+        set_reward_configs(&e, configs);
+        set_reward_distributions(&e, distributions);
+        release_unallocated(&e, &distribution);
+
+        Client::new(&e, &get_reward_token(&e)).transfer(
+            &e.current_contract_address(),
+            &distributor,
+            &(refund as i128),
+        );
+        refund
+    }
+
+    // Extends the reward config scheduled by the distributor.
+    // `extra_tps` is applied from now (or from the config start if it's in the future)
+    // and `extra_duration` is distributed with the updated tps.
+    //
+    // # Returns
+    //
+    // The amount charged from the distributor.
+    pub fn extend_reward(
+        e: Env,
+        pool: Address,
+        distributor: Address,
+        config_id: u32,
+        extra_duration: u64,
+        extra_tps: u128,
+        working_supply: u128,
+    ) -> u128 {
+        pool.require_auth();
+        distributor.require_auth();
+
+        if get_pool(&e) != pool {
+            panic_with_error!(&e, GaugeError::Unauthorized);
+        }
+
+        if extra_duration == 0 && extra_tps == 0 {
+            panic_with_error!(&e, GaugeError::InvalidConfig);
+        }
+
+        // checkpoint the global data so the new tps isn't applied retroactively
+        checkpoint_global(&e, working_supply);
+        let (index, mut config, distribution) = find_reward_config(&e, config_id);
+        if distribution.distributor != distributor {
+            panic_with_error!(&e, GaugeError::Unauthorized);
+        }
+
+        let now = e.ledger().timestamp();
+        let extend_from = config.start_at.max(now);
+        let amount = extra_tps * (config.expired_at - extend_from) as u128
+            + (config.tps + extra_tps) * extra_duration as u128;
+
+        Client::new(&e, &get_reward_token(&e)).transfer(
+            &distributor,
+            e.current_contract_address(),
+            &(amount as i128),
+        );
+
+        config.expired_at += extra_duration;
+        config.tps += extra_tps;
+        let mut configs = get_reward_configs(&e);
+        configs.set(index, config);
+        set_reward_configs(&e, configs);
+        amount
+    }
+
+    // Returns rewards generated while the working supply was zero back to the distributor.
+    // The pool passes its current working supply, so configs that expired after the last
+    // pool checkpoint are rolled over here and their idle part becomes reclaimable too.
+    pub fn reclaim_unallocated(
+        e: Env,
+        pool: Address,
+        distributor: Address,
+        working_supply: u128,
+    ) -> u128 {
+        pool.require_auth();
+        distributor.require_auth();
+
+        if get_pool(&e) != pool {
+            panic_with_error!(&e, GaugeError::Unauthorized);
+        }
+
+        checkpoint_global(&e, working_supply);
+        let amount = get_unallocated_reward(&e, &distributor);
+        if amount == 0 {
+            return 0;
+        }
+
+        set_unallocated_reward(&e, &distributor, 0);
+        let mut global_data = get_global_reward_data(&e);
+        global_data.claimed += amount;
+        set_global_reward_data(&e, &global_data);
+
+        Client::new(&e, &get_reward_token(&e)).transfer(
+            &e.current_contract_address(),
+            &distributor,
+            &(amount as i128),
+        );
+        amount
+    }
+
+    pub fn get_unallocated_reward(e: Env, distributor: Address) -> u128 {
+        get_unallocated_reward(&e, &distributor)
     }
 
     pub fn checkpoint_user(
@@ -94,6 +250,19 @@ impl RewardsGauge {
         user: Address,
         working_balance: u128,
         working_supply: u128,
+    ) -> u128 {
+        Self::claim_to(e, pool, user.clone(), user, working_balance, working_supply)
+    }
+
+    // Claims the user's reward and transfers it to `recipient`.
+    // The pool is responsible for authorizing the claim.
+    pub fn claim_to(
+        e: Env,
+        pool: Address,
+        user: Address,
+        recipient: Address,
+        working_balance: u128,
+        working_supply: u128,
     ) -> u128 {
         pool.require_auth();
         if get_pool(&e) != pool {
@@ -120,7 +289,7 @@ impl RewardsGauge {
         let reward_token = get_reward_token(&e);
         Client::new(&e, &reward_token).transfer(
             &e.current_contract_address(),
-            &user,
+            &recipient,
             &(transfer_amount as i128),
         );
 
@@ -166,6 +335,8 @@ impl RewardsGauge {
 
         set_global_reward_data(&e, &global_data);
         set_reward_configs(&e, configs);
+        // imported configs have no known distributor
+        set_reward_distributions(&e, Vec::new(&e));
     }
 
     pub fn get_reward_state(e: Env) -> GlobalRewardData {
@@ -180,6 +351,15 @@ impl RewardsGauge {
         get_reward_token(&e)
     }
 
+    // Returns distribution data aligned with `get_reward_configs_raw`.
+    pub fn get_reward_distributions(e: Env) -> Vec<Option<RewardDistribution>> {
+        get_reward_distributions(&e)
+    }
+
+    pub fn get_scheduled_reward(e: Env, config_id: u32) -> RewardConfig {
+        find_reward_config(&e, config_id).1
+    }
+
     pub fn get_reward_configs(e: Env) -> Vec<RewardConfig> {
         let now = e.ledger().timestamp();
         let mut current_configs = Vec::new(&e);
@@ -12,4 +12,5 @@ pub enum GaugeError {
     StartTooEarly = 3003,
     TooManyConfigs = 3004,
     InvalidRewardState = 3005,
+    ConfigNotFound = 3006,
 }
@@ -1,24 +1,30 @@
 use crate::constants::REWARD_PRECISION;
+use crate::errors::GaugeError;
 use crate::storage::{
-    get_global_reward_data, get_reward_configs, get_user_reward_data, set_global_reward_data,
-    set_reward_configs, set_user_reward_data, GlobalRewardData, UserRewardData,
+    get_global_reward_data, get_reward_configs, get_reward_distributions, get_unallocated_reward,
+    get_user_reward_data, set_global_reward_data, set_reward_configs, set_reward_distributions,
+    set_unallocated_reward, set_user_reward_data, GlobalRewardData, RewardConfig,
+    RewardDistribution, UserRewardData,
 };
 use soroban_fixed_point_math::SorobanFixedPoint;
-use soroban_sdk::{Address, Env, Vec, U256};
+use soroban_sdk::{panic_with_error, Address, Env, Vec, U256};
 
 pub(crate) fn checkpoint_global(env: &Env, working_supply: u128) -> GlobalRewardData {
     let configs = get_reward_configs(env);
+    let distributions = get_reward_distributions(env);
     let mut configs_updated = Vec::new(env);
+    let mut distributions_updated = Vec::new(env);
     let start_data = get_global_reward_data(env);
     let now = env.ledger().timestamp();
 
     let mut new_data = start_data.clone();
     new_data.epoch = now;
 
-    for config in configs {
+    for (config, distribution) in configs.iter().zip(distributions.iter()) {
         if config.start_at > now {
             // Config not started yet, so no yield generated. skip
             configs_updated.push_back(config);
+            distributions_updated.push_back(distribution);
             continue;
         }
 
@@ -40,18 +46,64 @@ pub(crate) fn checkpoint_global(env: &Env, working_supply: u128) -> GlobalReward
             0
         };
 
+        // nobody is eligible for rewards generated with zero working supply,
+        // so they're kept aside for the distributor who funded the config
+        let mut distribution = distribution;
+        if working_supply == 0 {
+            if let Some(distribution) = distribution.as_mut() {
+                distribution.unallocated += generated_tokens;
+            }
+        }
+
         // store only active or future configs
         if config.expired_at > now {
             configs_updated.push_back(config);
+            distributions_updated.push_back(distribution);
+        } else if let Some(distribution) = distribution {
+            release_unallocated(env, &distribution);
         }
         new_data.inv = new_data.inv.add(&U256::from_u128(env, reward_per_share));
         new_data.accumulated += generated_tokens;
     }
     set_global_reward_data(env, &new_data);
     set_reward_configs(env, configs_updated);
+    set_reward_distributions(env, distributions_updated);
     new_data
 }
 
+// Makes unallocated part of the finished config reclaimable by its distributor.
+pub(crate) fn release_unallocated(env: &Env, distribution: &RewardDistribution) {
+    if distribution.unallocated > 0 {
+        let reclaimable = get_unallocated_reward(env, &distribution.distributor);
+        set_unallocated_reward(
+            env,
+            &distribution.distributor,
+            reclaimable + distribution.unallocated,
+        );
+    }
+}
+
+// Looks up active or future config by its id.
+//
+// # Returns
+//
+// Index of the config in storage, config itself and its distribution data.
+pub(crate) fn find_reward_config(
+    env: &Env,
+    config_id: u32,
+) -> (u32, RewardConfig, RewardDistribution) {
+    let configs = get_reward_configs(env);
+    for (index, distribution) in get_reward_distributions(env).iter().enumerate() {
+        if let Some(distribution) = distribution {
+            if distribution.config_id == config_id {
+                let index = index as u32;
+                return (index, configs.get(index).unwrap(), distribution);
+            }
+        }
+    }
+    panic_with_error!(env, GaugeError::ConfigNotFound);
+}
+
 pub(crate) fn checkpoint_user(
     env: &Env,
     global_data: &GlobalRewardData,
@@ -39,6 +39,16 @@ pub struct UserRewardData {
     pub to_claim: u128,
 }
 
+// Funding source of a reward config and the part of its rewards
+// generated while the working supply was zero.
+#[derive(Clone)]
+#[contracttype]
+pub struct RewardDistribution {
+    pub config_id: u32,
+    pub distributor: Address,
+    pub unallocated: u128,
+}
+
 #[derive(Clone)]
 #[contracttype]
 enum DataKey {
@@ -46,9 +56,15 @@ enum DataKey {
     RewardToken,
     RewardConfigs,
     GlobalRewardData,
+    // Distribution data aligned by index with RewardConfigs
+    RewardDistributions,
+    NextRewardConfigId,
 
     // User-level data
     UserRewardData(Address),
+
+    // Distributor-level data
+    UnallocatedReward(Address),
 }
 
 generate_instance_storage_getter_and_setter!(pool, DataKey::Pool, Address);
@@ -69,6 +85,59 @@ pub(crate) fn set_reward_configs(env: &Env, configs: Vec<RewardConfig>) {
         .set(&DataKey::RewardConfigs, &configs);
 }
 
+// Returns distribution data for each stored config. Configs scheduled before distributions
+// were tracked (or imported with set_reward_state) have no distribution.
+pub(crate) fn get_reward_distributions(env: &Env) -> Vec<Option<RewardDistribution>> {
+    bump_instance(env);
+    let configs_count = get_reward_configs(env).len();
+    let mut distributions: Vec<Option<RewardDistribution>> = env
+        .storage()
+        .instance()
+        .get(&DataKey::RewardDistributions)
+        .unwrap_or(Vec::new(env));
+    while distributions.len() < configs_count {
+        distributions.push_back(None);
+    }
+    distributions
+}
+
+pub(crate) fn set_reward_distributions(env: &Env, distributions: Vec<Option<RewardDistribution>>) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::RewardDistributions, &distributions);
+}
+
+pub(crate) fn get_next_reward_config_id(env: &Env) -> u32 {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .get(&DataKey::NextRewardConfigId)
+        .unwrap_or(0)
+}
+
+pub(crate) fn set_next_reward_config_id(env: &Env, config_id: u32) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextRewardConfigId, &config_id);
+}
+
+pub(crate) fn get_unallocated_reward(env: &Env, distributor: &Address) -> u128 {
+    let key = DataKey::UnallocatedReward(distributor.clone());
+    let amount = env.storage().persistent().get(&key);
+    if amount.is_some() {
+        bump_persistent(env, &key);
+    }
+    amount.unwrap_or(0)
+}
+
+pub(crate) fn set_unallocated_reward(env: &Env, distributor: &Address, amount: u128) {
+    let key = DataKey::UnallocatedReward(distributor.clone());
+    env.storage().persistent().set(&key, &amount);
+    bump_persistent(env, &key);
+}
+
 pub(crate) fn set_global_reward_data(env: &Env, data: &GlobalRewardData) {
     bump_instance(env);
     env.storage()
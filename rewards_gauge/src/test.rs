@@ -100,6 +100,465 @@ fn test_simple_reward() {
     assert_eq!(user2_claim, 383333333);
 }
 
+#[test]
+fn test_claim_to_recipient() {
+    let setup = Setup::with_mocked_pool();
+
+    let user = Address::generate(&setup.env);
+    let recipient = Address::generate(&setup.env);
+    let distributor = Address::generate(&setup.env);
+
+    let reward_token_sac = StellarAssetClient::new(&setup.env, &setup.reward_token.address);
+    reward_token_sac.mint(&distributor, &1_000_000_0000000);
+
+    let total_shares = 1000_0000000;
+    setup
+        .contract
+        .checkpoint_user(&setup.pool_address, &user, &0, &0);
+    setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor,
+        &None,
+        &100,
+        &1_0000000,
+        &total_shares,
+    );
+    setup
+        .contract
+        .checkpoint_user(&setup.pool_address, &user, &total_shares, &total_shares);
+    jump(&setup.env, 10);
+
+    let claimed = setup.contract.claim_to(
+        &setup.pool_address,
+        &user,
+        &recipient,
+        &total_shares,
+        &total_shares,
+    );
+    assert_eq!(claimed, 10_0000000);
+    assert_eq!(setup.reward_token.balance(&recipient), 10_0000000);
+    assert_eq!(setup.reward_token.balance(&user), 0);
+
+    // nothing left to claim for the user
+    assert_eq!(
+        setup
+            .contract
+            .claim(&setup.pool_address, &user, &total_shares, &total_shares),
+        0
+    );
+}
+
+#[test]
+fn test_reclaim_unallocated_reward() {
+    let setup = Setup::with_mocked_pool();
+
+    let user = Address::generate(&setup.env);
+    let distributor = Address::generate(&setup.env);
+
+    let reward_token_sac = StellarAssetClient::new(&setup.env, &setup.reward_token.address);
+    reward_token_sac.mint(&distributor, &100_0000000);
+
+    // rewards are scheduled while nobody is staked
+    setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor,
+        &None,
+        &100,
+        &1_0000000,
+        &0,
+    );
+    assert_eq!(setup.reward_token.balance(&distributor), 0);
+
+    // user deposits after 10 seconds
+    jump(&setup.env, 10);
+    setup
+        .contract
+        .checkpoint_user(&setup.pool_address, &user, &0, &0);
+    let total_shares = 1000_0000000;
+
+    // config is still active, nothing to reclaim yet
+    assert_eq!(
+        setup
+            .contract
+            .reclaim_unallocated(&setup.pool_address, &distributor, &total_shares),
+        0
+    );
+
+    jump(&setup.env, 100);
+    assert_eq!(
+        setup
+            .contract
+            .claim(&setup.pool_address, &user, &total_shares, &total_shares),
+        90_0000000
+    );
+
+    // first 10 seconds were emitted with no recipients
+    assert_eq!(
+        setup.contract.get_unallocated_reward(&distributor),
+        10_0000000
+    );
+    assert_eq!(
+        setup
+            .contract
+            .reclaim_unallocated(&setup.pool_address, &distributor, &total_shares),
+        10_0000000
+    );
+    assert_eq!(setup.reward_token.balance(&distributor), 10_0000000);
+    assert_eq!(setup.reward_token.balance(&setup.contract.address), 0);
+    assert_eq!(setup.contract.get_unallocated_reward(&distributor), 0);
+    assert_eq!(
+        setup
+            .contract
+            .reclaim_unallocated(&setup.pool_address, &distributor, &total_shares),
+        0
+    );
+
+    let global_data = setup.contract.get_reward_state();
+    assert_eq!(global_data.accumulated, 100_0000000);
+    assert_eq!(global_data.claimed, 100_0000000);
+}
+
+#[test]
+fn test_reclaim_unallocated_multiple_distributors() {
+    let setup = Setup::with_mocked_pool();
+
+    let user = Address::generate(&setup.env);
+    let distributor1 = Address::generate(&setup.env);
+    let distributor2 = Address::generate(&setup.env);
+
+    let reward_token_sac = StellarAssetClient::new(&setup.env, &setup.reward_token.address);
+    reward_token_sac.mint(&distributor1, &100_0000000);
+    reward_token_sac.mint(&distributor2, &100_0000000);
+
+    setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor1,
+        &None,
+        &50,
+        &2_0000000,
+        &0,
+    );
+    setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor2,
+        &None,
+        &100,
+        &1_0000000,
+        &0,
+    );
+
+    // user deposits after 20 seconds
+    jump(&setup.env, 20);
+    setup
+        .contract
+        .checkpoint_user(&setup.pool_address, &user, &0, &0);
+    let total_shares = 1000_0000000;
+
+    // first config expires, second is still running
+    jump(&setup.env, 40);
+    setup
+        .contract
+        .checkpoint_user(&setup.pool_address, &user, &total_shares, &total_shares);
+    assert_eq!(
+        setup.contract.get_unallocated_reward(&distributor1),
+        40_0000000
+    );
+    assert_eq!(setup.contract.get_unallocated_reward(&distributor2), 0);
+    assert_eq!(
+        setup
+            .contract
+            .reclaim_unallocated(&setup.pool_address, &distributor1, &total_shares),
+        40_0000000
+    );
+    assert_eq!(
+        setup
+            .contract
+            .reclaim_unallocated(&setup.pool_address, &distributor2, &total_shares),
+        0
+    );
+
+    jump(&setup.env, 40);
+    assert_eq!(
+        setup
+            .contract
+            .claim(&setup.pool_address, &user, &total_shares, &total_shares),
+        140_0000000
+    );
+    assert_eq!(
+        setup
+            .contract
+            .reclaim_unallocated(&setup.pool_address, &distributor2, &total_shares),
+        20_0000000
+    );
+    assert_eq!(setup.reward_token.balance(&distributor1), 40_0000000);
+    assert_eq!(setup.reward_token.balance(&distributor2), 20_0000000);
+    assert_eq!(setup.reward_token.balance(&setup.contract.address), 0);
+}
+
+#[test]
+fn test_reclaim_unallocated_without_pool_activity() {
+    let setup = Setup::with_mocked_pool();
+
+    let user = Address::generate(&setup.env);
+    let distributor1 = Address::generate(&setup.env);
+    let distributor2 = Address::generate(&setup.env);
+
+    let reward_token_sac = StellarAssetClient::new(&setup.env, &setup.reward_token.address);
+    reward_token_sac.mint(&distributor1, &100_0000000);
+    reward_token_sac.mint(&distributor2, &100_0000000);
+
+    setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor1,
+        &None,
+        &100,
+        &1_0000000,
+        &0,
+    );
+    // nobody is staked during the whole first config
+    jump(&setup.env, 150);
+    assert_eq!(setup.contract.get_unallocated_reward(&distributor1), 0);
+    assert_eq!(
+        setup
+            .contract
+            .reclaim_unallocated(&setup.pool_address, &distributor1, &0),
+        100_0000000
+    );
+    assert_eq!(setup.reward_token.balance(&distributor1), 100_0000000);
+
+    setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor2,
+        &None,
+        &100,
+        &1_0000000,
+        &0,
+    );
+    // user deposits after 10 seconds and nobody touches the pool after the config expiry
+    jump(&setup.env, 10);
+    setup
+        .contract
+        .checkpoint_user(&setup.pool_address, &user, &0, &0);
+    let total_shares = 1000_0000000;
+    jump(&setup.env, 200);
+
+    // only the idle part before the deposit is reclaimable
+    assert_eq!(
+        setup
+            .contract
+            .reclaim_unallocated(&setup.pool_address, &distributor2, &total_shares),
+        10_0000000
+    );
+    assert_eq!(
+        setup
+            .contract
+            .claim(&setup.pool_address, &user, &total_shares, &total_shares),
+        90_0000000
+    );
+    assert_eq!(setup.reward_token.balance(&distributor2), 10_0000000);
+    assert_eq!(setup.reward_token.balance(&setup.contract.address), 0);
+}
+
+#[test]
+fn test_cancel_scheduled_reward() {
+    let setup = Setup::with_mocked_pool();
+
+    let user = Address::generate(&setup.env);
+    let distributor = Address::generate(&setup.env);
+
+    let reward_token_sac = StellarAssetClient::new(&setup.env, &setup.reward_token.address);
+    reward_token_sac.mint(&distributor, &200_0000000);
+
+    let total_shares = 1000_0000000;
+    setup
+        .contract
+        .checkpoint_user(&setup.pool_address, &user, &0, &0);
+    let now = setup.env.ledger().timestamp();
+
+    // first config starts in the future, second one right away
+    let future_config_id = setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor,
+        &Some(now + 1000),
+        &100,
+        &1_0000000,
+        &total_shares,
+    );
+    let active_config_id = setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor,
+        &None,
+        &100,
+        &1_0000000,
+        &total_shares,
+    );
+    assert_ne!(future_config_id, active_config_id);
+    assert_eq!(setup.reward_token.balance(&distributor), 0);
+
+    // not started config is fully refunded
+    assert_eq!(
+        setup.contract.cancel_scheduled_reward(
+            &setup.pool_address,
+            &distributor,
+            &future_config_id,
+            &total_shares
+        ),
+        100_0000000
+    );
+
+    // active config is refunded pro-rata
+    jump(&setup.env, 30);
+    assert_eq!(
+        setup.contract.cancel_scheduled_reward(
+            &setup.pool_address,
+            &distributor,
+            &active_config_id,
+            &total_shares
+        ),
+        70_0000000
+    );
+    assert_eq!(setup.reward_token.balance(&distributor), 170_0000000);
+    assert_eq!(setup.contract.get_reward_configs_raw().len(), 0);
+    assert_eq!(setup.contract.get_reward_distributions().len(), 0);
+
+    // generated rewards stay with the user
+    jump(&setup.env, 100);
+    assert_eq!(
+        setup
+            .contract
+            .claim(&setup.pool_address, &user, &total_shares, &total_shares),
+        30_0000000
+    );
+    assert_eq!(setup.reward_token.balance(&setup.contract.address), 0);
+
+    // cancelled config can't be cancelled again
+    assert_eq!(
+        setup
+            .contract
+            .try_cancel_scheduled_reward(
+                &setup.pool_address,
+                &distributor,
+                &active_config_id,
+                &total_shares,
+            )
+            .unwrap_err()
+            .unwrap(),
+        soroban_sdk::Error::from_contract_error(3006)
+    );
+}
+
+#[test]
+#[should_panic(expected = "#102")]
+fn test_cancel_scheduled_reward_other_distributor() {
+    let setup = Setup::with_mocked_pool();
+    let distributor = Address::generate(&setup.env);
+    StellarAssetClient::new(&setup.env, &setup.reward_token.address)
+        .mint(&distributor, &100_0000000);
+    let config_id = setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor,
+        &None,
+        &100,
+        &1_0000000,
+        &0,
+    );
+    setup.contract.cancel_scheduled_reward(
+        &setup.pool_address,
+        &Address::generate(&setup.env),
+        &config_id,
+        &0,
+    );
+}
+
+#[test]
+fn test_extend_reward() {
+    let setup = Setup::with_mocked_pool();
+
+    let user = Address::generate(&setup.env);
+    let distributor = Address::generate(&setup.env);
+
+    let reward_token_sac = StellarAssetClient::new(&setup.env, &setup.reward_token.address);
+    reward_token_sac.mint(&distributor, &1000_0000000);
+
+    let total_shares = 1000_0000000;
+    setup
+        .contract
+        .checkpoint_user(&setup.pool_address, &user, &0, &0);
+    let now = setup.env.ledger().timestamp();
+    let config_id = setup.contract.schedule_rewards_config(
+        &setup.pool_address,
+        &distributor,
+        &None,
+        &100,
+        &1_0000000,
+        &total_shares,
+    );
+    assert_eq!(setup.reward_token.balance(&distributor), 900_0000000);
+
+    // extend duration only
+    jump(&setup.env, 20);
+    assert_eq!(
+        setup.contract.extend_reward(
+            &setup.pool_address,
+            &distributor,
+            &config_id,
+            &50,
+            &0,
+            &total_shares
+        ),
+        50_0000000
+    );
+    assert_eq!(
+        setup.contract.get_scheduled_reward(&config_id).expired_at,
+        now + 150
+    );
+
+    // increase tps for the remaining 100 seconds and extend by 10 more seconds
+    jump(&setup.env, 30);
+    assert_eq!(
+        setup.contract.extend_reward(
+            &setup.pool_address,
+            &distributor,
+            &config_id,
+            &10,
+            &1_0000000,
+            &total_shares
+        ),
+        100_0000000 + 20_0000000
+    );
+    let config = setup.contract.get_scheduled_reward(&config_id);
+    assert_eq!(config.tps, 2_0000000);
+    assert_eq!(config.expired_at, now + 160);
+    assert_eq!(setup.reward_token.balance(&distributor), 730_0000000);
+
+    // tps change isn't applied retroactively
+    jump(&setup.env, 200);
+    assert_eq!(
+        setup
+            .contract
+            .claim(&setup.pool_address, &user, &total_shares, &total_shares),
+        50_0000000 + 220_0000000
+    );
+    assert_eq!(setup.reward_token.balance(&setup.contract.address), 0);
+
+    // expired config can't be extended
+    assert_eq!(
+        setup
+            .contract
+            .try_extend_reward(
+                &setup.pool_address,
+                &distributor,
+                &config_id,
+                &10,
+                &0,
+                &total_shares
+            )
+            .unwrap_err()
+            .unwrap(),
+        soroban_sdk::Error::from_contract_error(3006)
+    );
+}
+
 #[test]
 fn test_retroactive_reward() {
     let setup = Setup::default();
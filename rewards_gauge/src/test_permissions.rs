@@ -112,3 +112,51 @@ fn test_upgrade_pool() {
         .try_upgrade(&setup.pool_address, &install_dummy_wasm(&setup.env))
         .is_ok());
 }
+
+#[test]
+fn test_reclaim_unallocated_distributor() {
+    let setup = Setup::with_mocked_pool();
+    let distributor = Address::generate(&setup.env);
+    setup
+        .contract
+        .reclaim_unallocated(&setup.pool_address, &distributor, &0);
+    assert_eq!(setup.env.auths()[0].0, setup.pool_address);
+    assert_eq!(setup.env.auths()[1].0, distributor);
+}
+
+#[test]
+#[should_panic(expected = "#102")]
+fn test_reclaim_unallocated_third_party_user() {
+    let setup = Setup::with_mocked_pool();
+    setup.contract.reclaim_unallocated(
+        &Address::generate(&setup.env), // random address, not pool
+        &Address::generate(&setup.env),
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "#102")]
+fn test_cancel_scheduled_reward_third_party_user() {
+    let setup = Setup::with_mocked_pool();
+    setup.contract.cancel_scheduled_reward(
+        &Address::generate(&setup.env), // random address, not pool
+        &Address::generate(&setup.env),
+        &0,
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "#102")]
+fn test_extend_reward_third_party_user() {
+    let setup = Setup::with_mocked_pool();
+    setup.contract.extend_reward(
+        &Address::generate(&setup.env), // random address, not pool
+        &Address::generate(&setup.env),
+        &0,
+        &10,
+        &0,
+        &0,
+    );
+}